@@ -6,12 +6,16 @@ use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::array::PrimitiveArray;
 use vortex::encoding::ids;
 use vortex::iter::{Accessor, AccessorRef};
-use vortex::stats::ArrayStatisticsCompute;
+use vortex::stats::{
+    compute_canonical_statistics, ArrayStatistics, ArrayStatisticsCompute, Stat, StatsOptions,
+    StatsSet,
+};
 use vortex::validity::{ArrayValidity, LogicalValidity, Validity};
 use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
 use vortex::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoCanonical};
 use vortex_dtype::{DType, PType};
 use vortex_error::{vortex_bail, vortex_panic, VortexExpect as _, VortexResult};
+use vortex_scalar::Scalar;
 
 use crate::alp::{alp_encode, decompress, Exponents};
 use crate::ALPFloat;
@@ -282,4 +286,98 @@ impl AcceptArrayVisitor for ALPArray {
     }
 }
 
-impl ArrayStatisticsCompute for ALPArray {}
+impl ArrayStatisticsCompute for ALPArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        // Without patches every value is decoded from its integer by a positive scaling, which
+        // preserves their order. Patched positions hold placeholder integers instead.
+        let unpatched = self.patches().map_or(true, |patches| {
+            patches.statistics().compute_null_count() == Some(patches.len())
+        });
+        let value = match stat {
+            Stat::NullCount => self.encoded().statistics().compute(stat),
+            Stat::Min | Stat::Max if unpatched => self
+                .encoded()
+                .statistics()
+                .compute(stat)
+                .map(|bound| self.decode_bound(&bound))
+                .transpose()?,
+            Stat::IsSorted | Stat::IsStrictSorted | Stat::IsConstant | Stat::RunCount
+                if unpatched =>
+            {
+                self.encoded().statistics().compute(stat)
+            }
+            _ => {
+                return compute_canonical_statistics(self.as_ref(), stat, options);
+            }
+        };
+        Ok(value
+            .map(|value| StatsSet::of(stat, value))
+            .unwrap_or_default())
+    }
+}
+
+impl ALPArray {
+    /// Decode a minimum or maximum of the encoded integers.
+    fn decode_bound(&self, encoded: &Scalar) -> VortexResult<Scalar> {
+        if encoded.is_null() {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+        Ok(match self.ptype() {
+            PType::F32 => f32::decode_single(i32::try_from(encoded)?, self.exponents()).into(),
+            PType::F64 => f64::decode_single(i64::try_from(encoded)?, self.exponents()).into(),
+            ptype => vortex_panic!("Unsupported ALP ptype {}", ptype),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex::array::PrimitiveArray;
+    use vortex::compute::slice;
+    use vortex::stats::{ArrayStatistics, Stat};
+    use vortex::IntoArray;
+    use vortex_scalar::Scalar;
+
+    use crate::alp_encode;
+
+    #[test]
+    fn stats() {
+        let array = PrimitiveArray::from(vec![2.5f64, 0.125, 7.75, 7.75, 1000.5]);
+        let encoded = alp_encode(&array).unwrap();
+        assert!(encoded.patches().is_none());
+        let stats = encoded.statistics();
+        assert_eq!(stats.compute(Stat::Min), Some(Scalar::from(0.125f64)));
+        assert_eq!(stats.compute(Stat::Max), Some(Scalar::from(1000.5f64)));
+        assert_eq!(stats.compute_is_sorted(), Some(false));
+        assert_eq!(stats.compute_run_count(), Some(4));
+    }
+
+    #[test]
+    fn patched_stats() {
+        let array = PrimitiveArray::from(vec![1.5f64, 2.5, f64::MAX, -0.5]);
+        let encoded = alp_encode(&array).unwrap().into_array();
+        assert_eq!(
+            encoded.statistics().compute(Stat::Max),
+            Some(Scalar::from(f64::MAX))
+        );
+        assert_eq!(
+            encoded.statistics().compute(Stat::Min),
+            Some(Scalar::from(-0.5f64))
+        );
+
+        // Slicing away the patched value leaves an empty set of patches.
+        let sliced = slice(&encoded, 0, 2).unwrap();
+        assert_eq!(
+            sliced.statistics().compute(Stat::Max),
+            Some(Scalar::from(2.5f64))
+        );
+    }
+}
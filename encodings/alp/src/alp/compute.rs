@@ -1,21 +1,29 @@
-use vortex::array::{BoolArray, ConstantArray};
-use vortex::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
+use num_traits::{NumCast, PrimInt};
+use vortex::array::{BoolArray, ConstantArray, PrimitiveArray};
+use vortex::compute::unary::{scalar_at, scalar_at_unchecked, unary_math, MathOp, ScalarAtFn};
 use vortex::compute::{
-    compare, filter, slice, take, ArrayCompute, FilterFn, MaybeCompareFn, Operator, SliceFn, TakeFn,
+    compare, filter, search_sorted, slice, take, ArrayCompute, FilterFn, FilterMask,
+    MaybeCompareFn, Operator, SearchResult, SearchSorted, SearchSortedFn, SearchSortedSide,
+    SliceFn, TakeFn,
 };
 use vortex::stats::{ArrayStatistics, Stat};
 use vortex::validity::Validity;
-use vortex::{Array, ArrayDType, IntoArray};
-use vortex_error::{VortexExpect, VortexResult};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{NativePType, PType};
+use vortex_error::{vortex_bail, VortexExpect, VortexResult};
 use vortex_scalar::{PValue, Scalar};
 
-use crate::{match_each_alp_float_ptype, ALPArray, ALPFloat};
+use crate::{match_each_alp_float_ptype, ALPArray, ALPFloat, Exponents};
 
 impl ArrayCompute for ALPArray {
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
@@ -31,6 +39,73 @@ impl ArrayCompute for ALPArray {
     fn filter(&self) -> Option<&dyn FilterFn> {
         Some(self)
     }
+
+    fn unary_math(&self, op: MathOp) -> Option<VortexResult<Array>> {
+        match op {
+            MathOp::Abs | MathOp::Negate | MathOp::Round | MathOp::Floor | MathOp::Ceil => {
+                Some(alp_math(self, op))
+            }
+            MathOp::Sqrt | MathOp::Log => None,
+        }
+    }
+}
+
+/// Apply a sign or rounding function to the encoded integers, without decoding.
+///
+/// Decoding is sign-symmetric, so `abs` and `negate` apply directly to the encoded values. The
+/// encoded values are decimals scaled by `10^(e - f)`, so rounding divides out that scale with
+/// integer arithmetic and re-encodes the resulting integers with zero exponents. Patches hold
+/// the exceptional values and have the same function applied to them.
+fn alp_math(array: &ALPArray, op: MathOp) -> VortexResult<Array> {
+    let exponents = array.exponents();
+    let scale = exponents.e.saturating_sub(exponents.f);
+    let (encoded, exponents) = match op {
+        MathOp::Abs | MathOp::Negate => (unary_math(array.encoded(), op)?, exponents),
+        // The encoded values are already whole numbers.
+        _ if scale == 0 => (array.encoded(), exponents),
+        _ => {
+            let encoded = array.encoded().into_primitive()?;
+            let divisor = 10i128.pow(scale.into());
+            let rounded = match encoded.ptype() {
+                PType::I32 => round_encoded::<i32>(&encoded, divisor, op),
+                PType::I64 => round_encoded::<i64>(&encoded, divisor, op),
+                p => vortex_bail!(MismatchedTypes: "int32 or int64", p),
+            };
+            (rounded, Exponents { e: 0, f: 0 })
+        }
+    };
+
+    ALPArray::try_new(
+        encoded,
+        exponents,
+        array.patches().map(|p| unary_math(p, op)).transpose()?,
+    )
+    .map(IntoArray::into_array)
+}
+
+fn round_encoded<I: NativePType + PrimInt>(
+    encoded: &PrimitiveArray,
+    divisor: i128,
+    op: MathOp,
+) -> Array {
+    let values = encoded
+        .maybe_null_slice::<I>()
+        .iter()
+        .map(|&n| {
+            let n = n
+                .to_i128()
+                .vortex_expect("ALP encoded integers fit in i128");
+            let rounded = match op {
+                MathOp::Floor => n.div_euclid(divisor),
+                MathOp::Ceil => -(-n).div_euclid(divisor),
+                // Halves round away from zero, as with `f64::round`.
+                _ => n.signum() * ((n.abs() + divisor / 2) / divisor),
+            };
+            // Rounding never increases the magnitude of a scaled down value past the original.
+            <I as NumCast>::from(rounded).vortex_expect("Rounded value must fit the encoded type")
+        })
+        .collect::<Vec<_>>();
+    PrimitiveArray::from_vec(values, encoded.validity()).into_array()
 }
 
 impl ScalarAtFn for ALPArray {
@@ -81,12 +156,55 @@ impl SliceFn for ALPArray {
     }
 }
 
+impl SearchSortedFn for ALPArray {
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        match_each_alp_float_ptype!(self.ptype(), |$T| {
+            let value: $T = value.cast(self.dtype())?.as_ref().try_into()?;
+            alp_search_sorted(self, value, side)
+        })
+    }
+}
+
+fn alp_search_sorted<F: ALPFloat + Into<Scalar>>(
+    alp: &ALPArray,
+    value: F,
+    side: SearchSortedSide,
+) -> VortexResult<SearchResult>
+where
+    F::ALPInt: Into<Scalar>,
+{
+    // The encoded slots of patched values hold fill values, so the encoded child is only sorted
+    // when there are no patches.
+    if alp.patches().is_some() {
+        return Ok(alp.as_ref().search_sorted(&value.into(), side));
+    }
+
+    // ALP encoding and decoding are both monotonic, so a sorted array has sorted encoded values.
+    // SAFETY: we check below whether the encoded value decodes back to the needle.
+    let encoded = unsafe { F::encode_single_unchecked(value, alp.exponents()) };
+    let decoded = F::decode_single(encoded, alp.exponents());
+    if decoded == value {
+        return search_sorted(&alp.encoded(), encoded, side);
+    }
+
+    // The needle is not representable with these exponents, so it can't be in the array. Its
+    // insertion point is on the far side of every value that encodes to the rounded needle.
+    let insertion_side = if decoded < value {
+        SearchSortedSide::Right
+    } else {
+        SearchSortedSide::Left
+    };
+    Ok(SearchResult::NotFound(
+        search_sorted(&alp.encoded(), encoded, insertion_side)?.to_index(),
+    ))
+}
+
 impl FilterFn for ALPArray {
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
         Ok(Self::try_new(
-            filter(self.encoded(), predicate)?,
+            filter(self.encoded(), mask)?,
             self.exponents(),
-            self.patches().map(|p| filter(&p, predicate)).transpose()?,
+            self.patches().map(|p| filter(&p, mask)).transpose()?,
         )?
         .into_array())
     }
@@ -155,6 +273,79 @@ mod tests {
     use super::*;
     use crate::alp_encode;
 
+    #[test]
+    fn search_sorted_encoded() {
+        let array = PrimitiveArray::from(vec![0.5f64, 1.25, 1.25, 1.25, 2.5, 3.75, 8.0]);
+        let encoded = alp_encode(&array).unwrap();
+        assert!(encoded.patches().is_none());
+        let encoded = encoded.into_array();
+
+        assert_eq!(
+            search_sorted(&encoded, 1.25f64, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(1)
+        );
+        assert_eq!(
+            search_sorted(&encoded, 1.25f64, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(4)
+        );
+        assert_eq!(
+            search_sorted(&encoded, 8.0f64, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(7)
+        );
+        assert_eq!(
+            search_sorted(&encoded, 3.0f64, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(5)
+        );
+        assert_eq!(
+            search_sorted(&encoded, 0.1f64, SearchSortedSide::Right).unwrap(),
+            SearchResult::NotFound(0)
+        );
+    }
+
+    #[test]
+    fn search_sorted_unrepresentable() {
+        // 1.255 cannot be represented with the 2 decimal digits chosen for this array, and rounds
+        // to an encoded value that is present in the array.
+        let array = PrimitiveArray::from(vec![1.24f32, 1.25, 1.25, 1.26, 1.27]);
+        let encoded = alp_encode(&array).unwrap();
+        assert!(encoded.patches().is_none());
+        let encoded = encoded.into_array();
+
+        assert_eq!(
+            search_sorted(&encoded, 1.2549f32, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(3)
+        );
+        assert_eq!(
+            search_sorted(&encoded, 1.2549f32, SearchSortedSide::Right).unwrap(),
+            SearchResult::NotFound(3)
+        );
+        assert_eq!(
+            search_sorted(&encoded, 1.2551f32, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(3)
+        );
+    }
+
+    #[test]
+    fn search_sorted_patched() {
+        let array = PrimitiveArray::from(vec![0.5f64, 1.0, std::f64::consts::PI, 4.0, 5.0]);
+        let encoded = alp_encode(&array).unwrap();
+        assert!(encoded.patches().is_some());
+        let encoded = encoded.into_array();
+
+        assert_eq!(
+            search_sorted(&encoded, std::f64::consts::PI, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(2)
+        );
+        assert_eq!(
+            search_sorted(&encoded, 4.0f64, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(4)
+        );
+        assert_eq!(
+            search_sorted(&encoded, 2.0f64, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(2)
+        );
+    }
+
     #[test]
     fn basic_comparison_test() {
         let array = PrimitiveArray::from(vec![1.234f32; 1025]);
@@ -221,4 +412,30 @@ mod tests {
             assert!(!v);
         }
     }
+
+    #[test]
+    fn rounding_encoded() {
+        let values = vec![0.5f64, -1.25, 2.5, -0.75, 12.0, std::f64::consts::PI, -3.5];
+        let array = PrimitiveArray::from(values.clone());
+        let encoded = alp_encode(&array).unwrap();
+        assert!(encoded.patches().is_some());
+        let encoded = encoded.into_array();
+
+        for op in [
+            MathOp::Abs,
+            MathOp::Negate,
+            MathOp::Round,
+            MathOp::Floor,
+            MathOp::Ceil,
+        ] {
+            let result = unary_math(&encoded, op).unwrap();
+            assert!(ALPArray::try_from(&result).is_ok(), "{op}");
+            let expected = values.iter().map(|&v| op.apply(v)).collect::<Vec<_>>();
+            assert_eq!(
+                result.into_primitive().unwrap().maybe_null_slice::<f64>(),
+                expected,
+                "{op}"
+            );
+        }
+    }
 }
@@ -1,21 +1,21 @@
-use vortex::compute::{filter, FilterFn};
+use vortex::compute::{filter, FilterFn, FilterMask};
 use vortex::{Array, ArrayDType, IntoArray};
 use vortex_error::VortexResult;
 
 use crate::ALPRDArray;
 
 impl FilterFn for ALPRDArray {
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
         let left_parts_exceptions = self
             .left_parts_exceptions()
-            .map(|array| filter(&array, predicate))
+            .map(|array| filter(&array, mask))
             .transpose()?;
 
         Ok(ALPRDArray::try_new(
             self.dtype().clone(),
-            filter(self.left_parts(), predicate)?,
+            filter(self.left_parts(), mask)?,
             self.left_parts_dict(),
-            filter(self.right_parts(), predicate)?,
+            filter(self.right_parts(), mask)?,
             self.right_bit_width(),
             left_parts_exceptions,
         )?
@@ -27,8 +27,8 @@ impl FilterFn for ALPRDArray {
 mod test {
     use rstest::rstest;
     use vortex::array::{BoolArray, PrimitiveArray};
-    use vortex::compute::filter;
-    use vortex::IntoArrayVariant;
+    use vortex::compute::{filter, FilterMask};
+    use vortex::{IntoArray, IntoArrayVariant};
 
     use crate::{ALPRDFloat, RDEncoder};
 
@@ -43,10 +43,13 @@ mod test {
         assert!(encoded.left_parts_exceptions().is_some());
 
         // The first two values need no patching
-        let filtered = filter(encoded.as_ref(), BoolArray::from(vec![true, false, true]))
-            .unwrap()
-            .into_primitive()
-            .unwrap();
+        let filtered = filter(
+            encoded.as_ref(),
+            &FilterMask::try_from(BoolArray::from(vec![true, false, true]).into_array()).unwrap(),
+        )
+        .unwrap()
+        .into_primitive()
+        .unwrap();
         assert_eq!(filtered.maybe_null_slice::<T>(), &[a, outlier]);
     }
 }
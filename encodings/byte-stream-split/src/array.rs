@@ -0,0 +1,181 @@
+use std::fmt::{Debug, Display};
+
+use serde::{Deserialize, Serialize};
+use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use vortex::array::PrimitiveArray;
+use vortex::encoding::ids;
+use vortex::stats::{
+    compute_canonical_statistics, ArrayStatisticsCompute, Stat, StatsOptions, StatsSet,
+};
+use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
+use vortex::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoCanonical};
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexExpect as _, VortexResult, VortexUnwrap as _};
+
+use crate::{byte_stream_split_decode, byte_stream_split_encode};
+
+impl_encoding!(
+    "vortex.bytestreamsplit",
+    ids::BYTE_STREAM_SPLIT,
+    ByteStreamSplit
+);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ByteStreamSplitMetadata {
+    validity: ValidityMetadata,
+}
+
+impl Display for ByteStreamSplitMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// An array of floats whose bytes are split into one `u8` stream per byte position.
+///
+/// The sign, exponent and high mantissa bytes of scientific data tend to take few distinct
+/// values even when the low mantissa bytes are noise, so splitting them apart lets each stream be
+/// compressed on its own, e.g. with dictionary, run-end or bit-packing encodings.
+///
+/// # Details
+///
+/// Stream `k` holds the `k`-th little-endian byte of every value, as in the `BYTE_STREAM_SPLIT`
+/// encoding of Parquet and Arrow. The bytes of null values are zero.
+impl ByteStreamSplitArray {
+    pub fn encode(array: &PrimitiveArray) -> VortexResult<Self> {
+        let streams = byte_stream_split_encode(array)?;
+        Self::try_new(
+            array.dtype().clone(),
+            streams.into_iter().map(IntoArray::into_array).collect(),
+            array.validity(),
+        )
+    }
+
+    pub fn try_new(dtype: DType, streams: Vec<Array>, validity: Validity) -> VortexResult<Self> {
+        let DType::Primitive(ptype @ (PType::F32 | PType::F64), _) = dtype else {
+            vortex_bail!(MismatchedTypes: "f32 or f64", dtype);
+        };
+        if dtype.nullability() != validity.nullability() {
+            vortex_bail!(
+                "ByteStreamSplitArray of type {} does not match the nullability of its validity",
+                dtype
+            );
+        }
+        if streams.len() != ptype.byte_width() {
+            vortex_bail!(
+                "Expected {} byte streams for {}, got {}",
+                ptype.byte_width(),
+                ptype,
+                streams.len()
+            );
+        }
+
+        let len = streams[0].len();
+        for stream in &streams {
+            if !matches!(
+                stream.dtype(),
+                DType::Primitive(PType::U8, Nullability::NonNullable)
+            ) {
+                vortex_bail!(MismatchedTypes: "u8", stream.dtype());
+            }
+            if stream.len() != len {
+                vortex_bail!(
+                    "Byte streams must have the same length, got {} and {}",
+                    len,
+                    stream.len()
+                );
+            }
+        }
+
+        let metadata = ByteStreamSplitMetadata {
+            validity: validity.to_metadata(len)?,
+        };
+
+        let mut children = streams;
+        if let Some(varray) = validity.into_array() {
+            children.push(varray)
+        }
+
+        Self::try_from_parts(dtype, len, metadata, children.into(), StatsSet::new())
+    }
+
+    /// The stream of the `k`-th little-endian byte of the values.
+    pub fn stream(&self, k: usize) -> Array {
+        self.as_ref()
+            .child(
+                k,
+                &DType::Primitive(PType::U8, Nullability::NonNullable),
+                self.len(),
+            )
+            .vortex_expect("ByteStreamSplitArray is missing a byte stream child array")
+    }
+
+    pub fn streams(&self) -> Vec<Array> {
+        (0..self.ptype().byte_width())
+            .map(|k| self.stream(k))
+            .collect()
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata().validity.to_validity(|| {
+            self.as_ref()
+                .child(self.ptype().byte_width(), &Validity::DTYPE, self.len())
+                .vortex_expect("ByteStreamSplitArray: validity child")
+        })
+    }
+
+    pub fn ptype(&self) -> PType {
+        PType::try_from(self.dtype()).vortex_unwrap()
+    }
+}
+
+impl ArrayTrait for ByteStreamSplitArray {}
+
+impl ArrayVariants for ByteStreamSplitArray {
+    fn as_primitive_array(&self) -> Option<&dyn PrimitiveArrayTrait> {
+        Some(self)
+    }
+}
+
+impl PrimitiveArrayTrait for ByteStreamSplitArray {}
+
+impl IntoCanonical for ByteStreamSplitArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        byte_stream_split_decode(self).map(Canonical::Primitive)
+    }
+}
+
+impl ArrayValidity for ByteStreamSplitArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for ByteStreamSplitArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        for (k, stream) in self.streams().iter().enumerate() {
+            visitor.visit_child(&format!("stream_{k}"), stream)?;
+        }
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for ByteStreamSplitArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        // The order of floats depends on all of their bytes at once, so decode to compute.
+        compute_canonical_statistics(self.as_ref(), stat, options)
+    }
+}
@@ -0,0 +1,157 @@
+use vortex::array::PrimitiveArray;
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::IntoArrayVariant;
+use vortex_dtype::PType;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::ByteStreamSplitArray;
+
+/// Split the little-endian bytes of the values of a float array into one stream per byte
+/// position, such that stream `k` holds byte `k` of every value.
+///
+/// This is the layout of Parquet's `BYTE_STREAM_SPLIT` encoding, with the streams kept as separate
+/// arrays rather than concatenated. The bytes of null values are zeroed.
+pub fn byte_stream_split_encode(array: &PrimitiveArray) -> VortexResult<Vec<PrimitiveArray>> {
+    let validity = array.logical_validity().to_null_buffer()?;
+    let is_valid = |i: usize| validity.as_ref().map_or(true, |v| v.is_valid(i));
+    let streams = match array.ptype() {
+        PType::F32 => split(array.maybe_null_slice::<f32>(), f32::to_le_bytes, is_valid),
+        PType::F64 => split(array.maybe_null_slice::<f64>(), f64::to_le_bytes, is_valid),
+        ptype => vortex_bail!(
+            "Byte stream split can only encode f32 and f64, got {}",
+            ptype
+        ),
+    };
+    Ok(streams
+        .into_iter()
+        .map(|stream| PrimitiveArray::from_vec(stream, Validity::NonNullable))
+        .collect())
+}
+
+fn split<T: Copy, const N: usize>(
+    values: &[T],
+    to_le_bytes: impl Fn(T) -> [u8; N],
+    is_valid: impl Fn(usize) -> bool,
+) -> Vec<Vec<u8>> {
+    let mut streams = vec![Vec::with_capacity(values.len()); N];
+    for (i, &value) in values.iter().enumerate() {
+        let bytes = if is_valid(i) {
+            to_le_bytes(value)
+        } else {
+            [0; N]
+        };
+        for (stream, byte) in streams.iter_mut().zip(bytes) {
+            stream.push(byte);
+        }
+    }
+    streams
+}
+
+pub fn byte_stream_split_decode(array: ByteStreamSplitArray) -> VortexResult<PrimitiveArray> {
+    let streams = array
+        .streams()
+        .into_iter()
+        .map(|stream| stream.into_primitive())
+        .collect::<VortexResult<Vec<_>>>()?;
+    let streams = streams
+        .iter()
+        .map(|stream| stream.maybe_null_slice::<u8>())
+        .collect::<Vec<_>>();
+
+    let decoded = match array.ptype() {
+        PType::F32 => PrimitiveArray::from_vec(
+            join(&streams, array.len(), f32::from_le_bytes),
+            array.validity(),
+        ),
+        PType::F64 => PrimitiveArray::from_vec(
+            join(&streams, array.len(), f64::from_le_bytes),
+            array.validity(),
+        ),
+        ptype => vortex_bail!(
+            "Byte stream split can only decode f32 and f64, got {}",
+            ptype
+        ),
+    };
+    Ok(decoded)
+}
+
+fn join<T, const N: usize>(
+    streams: &[&[u8]],
+    len: usize,
+    from_le_bytes: impl Fn([u8; N]) -> T,
+) -> Vec<T> {
+    (0..len)
+        .map(|i| from_le_bytes(std::array::from_fn(|k| streams[k][i])))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+    use vortex::validity::Validity;
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::{byte_stream_split_decode, byte_stream_split_encode, ByteStreamSplitArray};
+
+    #[test]
+    fn parquet_layout() {
+        // The example of the Parquet specification, with values given as their little-endian bytes.
+        let values = [
+            f32::from_le_bytes([0xAA, 0xBB, 0xCC, 0xDD]),
+            f32::from_le_bytes([0x00, 0x11, 0x22, 0x33]),
+            f32::from_le_bytes([0xA3, 0xB4, 0xC5, 0xD6]),
+        ];
+        let streams = byte_stream_split_encode(&PrimitiveArray::from(values.to_vec())).unwrap();
+        let concatenated = streams
+            .iter()
+            .flat_map(|s| s.maybe_null_slice::<u8>().to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            concatenated,
+            [0xAA, 0x00, 0xA3, 0xBB, 0x11, 0xB4, 0xCC, 0x22, 0xC5, 0xDD, 0x33, 0xD6]
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let values = (0..1000)
+            .map(|i| (i % 9 != 0).then_some((i as f64).sqrt() * 1e-3))
+            .chain([Some(f64::NAN), Some(-0.0), Some(f64::MAX)])
+            .collect::<Vec<_>>();
+        let array =
+            ByteStreamSplitArray::encode(&PrimitiveArray::from_nullable_vec(values.clone()))
+                .unwrap();
+        assert_eq!(array.streams().len(), 8);
+
+        let decoded = byte_stream_split_decode(array).unwrap();
+        let decoded_values = decoded.maybe_null_slice::<f64>();
+        for (i, value) in values.iter().enumerate() {
+            match value {
+                Some(v) => assert_eq!(decoded_values[i].to_bits(), v.to_bits()),
+                None => assert!(!decoded.validity().is_valid(i)),
+            }
+        }
+        assert_eq!(
+            byte_stream_split_decode(
+                ByteStreamSplitArray::encode(&PrimitiveArray::from(vec![1.5f32, -2.25])).unwrap()
+            )
+            .unwrap()
+            .into_primitive()
+            .unwrap()
+            .maybe_null_slice::<f32>(),
+            &[1.5, -2.25]
+        );
+    }
+
+    #[test]
+    fn nullable_streams_rejected() {
+        let stream = PrimitiveArray::from_nullable_vec(vec![Some(0u8), None]).into_array();
+        assert!(ByteStreamSplitArray::try_new(
+            DType::Primitive(PType::F32, Nullability::Nullable),
+            vec![stream; 4],
+            Validity::AllValid,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,194 @@
+use vortex::array::PrimitiveArray;
+use vortex::compute::unary::{scalar_at, ScalarAtFn};
+use vortex::compute::{filter, slice, take, ArrayCompute, FilterFn, FilterMask, SliceFn, TakeFn};
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{match_each_integer_ptype, PType};
+use vortex_error::{vortex_bail, VortexResult, VortexUnwrap as _};
+use vortex_scalar::Scalar;
+
+use crate::ByteStreamSplitArray;
+
+impl ArrayCompute for ByteStreamSplitArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for ByteStreamSplitArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+
+        let bytes = self
+            .streams()
+            .iter()
+            .map(|stream| u8::try_from(&scalar_at(stream, index)?))
+            .collect::<VortexResult<Vec<_>>>()?;
+        let nullability = self.dtype().nullability();
+        Ok(match (self.ptype(), bytes.as_slice()) {
+            (PType::F32, &[b0, b1, b2, b3]) => {
+                Scalar::primitive(f32::from_le_bytes([b0, b1, b2, b3]), nullability)
+            }
+            (PType::F64, &[b0, b1, b2, b3, b4, b5, b6, b7]) => Scalar::primitive(
+                f64::from_le_bytes([b0, b1, b2, b3, b4, b5, b6, b7]),
+                nullability,
+            ),
+            (ptype, _) => vortex_bail!("Invalid {} bytes for {}", bytes.len(), ptype),
+        })
+    }
+
+    fn scalar_at_unchecked(&self, index: usize) -> Scalar {
+        ScalarAtFn::scalar_at(self, index).vortex_unwrap()
+    }
+}
+
+impl SliceFn for ByteStreamSplitArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        Self::try_new(
+            self.dtype().clone(),
+            self.streams()
+                .iter()
+                .map(|stream| slice(stream, start, stop))
+                .collect::<VortexResult<Vec<_>>>()?,
+            self.validity().slice(start, stop)?,
+        )
+        .map(IntoArray::into_array)
+    }
+}
+
+impl TakeFn for ByteStreamSplitArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        // The byte streams are never nullable, so nulls in the indices only go to the validity.
+        let (stream_indices, indices_validity) = split_indices_validity(indices)?;
+        let validity = self
+            .validity()
+            .take(&stream_indices)?
+            .and(indices_validity)?;
+        Self::try_new(
+            self.dtype().with_nullability(validity.nullability()),
+            self.streams()
+                .iter()
+                .map(|stream| take(stream, &stream_indices))
+                .collect::<VortexResult<Vec<_>>>()?,
+            validity,
+        )
+        .map(IntoArray::into_array)
+    }
+}
+
+/// Split the indices into non-nullable indices, with nulls replaced by index zero, and their
+/// validity.
+fn split_indices_validity(indices: &Array) -> VortexResult<(Array, Validity)> {
+    if !indices.dtype().is_nullable() {
+        return Ok((indices.clone(), Validity::NonNullable));
+    }
+
+    let indices = indices.clone().into_primitive()?;
+    let validity = indices.validity();
+    let non_nullable = match_each_integer_ptype!(indices.ptype(), |$I| {
+        PrimitiveArray::from(
+            indices
+                .maybe_null_slice::<$I>()
+                .iter()
+                .enumerate()
+                .map(|(i, &idx)| if validity.is_valid(i) { idx } else { 0 })
+                .collect::<Vec<_>>(),
+        )
+    });
+    Ok((non_nullable.into_array(), validity))
+}
+
+impl FilterFn for ByteStreamSplitArray {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        Self::try_new(
+            self.dtype().clone(),
+            self.streams()
+                .iter()
+                .map(|stream| filter(stream, mask))
+                .collect::<VortexResult<Vec<_>>>()?,
+            self.validity().filter(mask)?,
+        )
+        .map(IntoArray::into_array)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+    use vortex::compute::unary::scalar_at;
+    use vortex::compute::{filter, slice, take, FilterMask, TakeFn};
+    use vortex::{ArrayDType, IntoArrayVariant};
+    use vortex_scalar::Scalar;
+
+    use crate::ByteStreamSplitArray;
+
+    #[test]
+    fn compute() {
+        let values = (0..100)
+            .map(|i| (i % 10 != 0).then_some(i as f32 / 7.0))
+            .collect::<Vec<_>>();
+        let array =
+            ByteStreamSplitArray::encode(&PrimitiveArray::from_nullable_vec(values.clone()))
+                .unwrap();
+
+        assert_eq!(
+            scalar_at(&array, 0).unwrap(),
+            Scalar::null(array.dtype().clone())
+        );
+        assert_eq!(
+            f32::try_from(&scalar_at(&array, 15).unwrap()).unwrap(),
+            values[15].unwrap()
+        );
+
+        let sliced = slice(&array, 15, 25).unwrap();
+        assert_eq!(
+            f32::try_from(&scalar_at(&sliced, 2).unwrap()).unwrap(),
+            values[17].unwrap()
+        );
+        assert!(scalar_at(&sliced, 5).unwrap().is_null());
+
+        let taken = take(&array, PrimitiveArray::from(vec![99u32, 3, 40]).as_ref())
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(
+            taken.maybe_null_slice::<f32>()[..2],
+            [99.0 / 7.0, 3.0 / 7.0]
+        );
+        assert!(!taken.validity().is_valid(2));
+
+        // Taking with nullable indices directly leaves the byte streams non-nullable.
+        let taken = TakeFn::take(
+            &array,
+            PrimitiveArray::from_nullable_vec(vec![Some(99u32), None, Some(3)]).as_ref(),
+        )
+        .unwrap();
+        assert_eq!(
+            scalar_at(&taken, 0).unwrap(),
+            scalar_at(&array, 99).unwrap()
+        );
+        assert!(scalar_at(&taken, 1).unwrap().is_null());
+        assert_eq!(scalar_at(&taken, 2).unwrap(), scalar_at(&array, 3).unwrap());
+
+        let filtered = filter(&array, &FilterMask::from_indices(100, [1, 50, 51])).unwrap();
+        assert_eq!(filtered.len(), 3);
+        assert_eq!(
+            f32::try_from(&scalar_at(&filtered, 2).unwrap()).unwrap(),
+            values[51].unwrap()
+        );
+    }
+}
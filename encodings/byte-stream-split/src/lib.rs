@@ -0,0 +1,6 @@
+pub use array::*;
+pub use compress::*;
+
+mod array;
+mod compress;
+mod compute;
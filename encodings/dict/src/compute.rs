@@ -1,16 +1,50 @@
-use vortex::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{filter, slice, take, ArrayCompute, FilterFn, SliceFn, TakeFn};
-use vortex::{Array, IntoArray};
+use std::cmp::Ordering;
+
+use vortex::array::{ConstantArray, PrimitiveArray};
+use vortex::compute::unary::{scalar_at, scalar_at_unchecked, unary_math, MathOp, ScalarAtFn};
+use vortex::compute::{
+    between, bitwise, filter, is_in, slice, take, top_k, ArrayCompute, BetweenFn, BitwiseOperator,
+    FilterFn, FilterMask, Inclusivity, IndexOrd, IsInFn, Len, SearchResult, SearchSorted,
+    SearchSortedFn, SearchSortedSide, SliceFn, TakeFn, TopKFn,
+};
+use vortex::{Array, IntoArray, IntoArrayVariant};
+use vortex_dtype::match_each_integer_ptype;
 use vortex_error::{VortexExpect, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::DictArray;
 
 impl ArrayCompute for DictArray {
+    fn between(&self) -> Option<&dyn BetweenFn> {
+        Some(self)
+    }
+
+    fn bitwise(&self, other: &Array, operator: BitwiseOperator) -> Option<VortexResult<Array>> {
+        // Against a constant, the operator only needs to be applied to the dictionary values.
+        let constant = ConstantArray::try_from(other).ok()?;
+        Some(
+            bitwise(
+                self.values(),
+                ConstantArray::new(constant.owned_scalar(), self.values().len()),
+                operator,
+            )
+            .and_then(|values| Self::try_new(self.codes(), values))
+            .map(IntoArray::into_array),
+        )
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
@@ -19,9 +53,22 @@ impl ArrayCompute for DictArray {
         Some(self)
     }
 
+    fn top_k(&self) -> Option<&dyn TopKFn> {
+        Some(self)
+    }
+
     fn filter(&self) -> Option<&dyn FilterFn> {
         Some(self)
     }
+
+    fn unary_math(&self, op: MathOp) -> Option<VortexResult<Array>> {
+        // The function only needs to be evaluated once per distinct value.
+        Some(
+            unary_math(self.values(), op)
+                .and_then(|values| Self::try_new(self.codes(), values))
+                .map(IntoArray::into_array),
+        )
+    }
 }
 
 impl ScalarAtFn for DictArray {
@@ -40,6 +87,86 @@ impl ScalarAtFn for DictArray {
     }
 }
 
+impl BetweenFn for DictArray {
+    fn between(
+        &self,
+        lower: &Scalar,
+        upper: &Scalar,
+        inclusivity: Inclusivity,
+    ) -> VortexResult<Array> {
+        // Evaluate the range once per distinct value, then expand through the codes.
+        let matches = between(self.values(), lower, upper, inclusivity)?;
+        take(matches, self.codes())
+    }
+}
+
+impl TopKFn for DictArray {
+    fn top_k(&self, k: usize, descending: bool) -> VortexResult<Array> {
+        let codes = self.codes().into_primitive()?;
+        let codes = match_each_integer_ptype!(codes.ptype(), |$C| {
+            codes
+                .maybe_null_slice::<$C>()
+                .iter()
+                .map(|&code| code as usize)
+                .collect::<Vec<_>>()
+        });
+
+        // A sliced dictionary may hold values that none of its rows refer to, so only rank the
+        // referenced ones.
+        let values = self.values();
+        let mut referenced = vec![false; values.len()];
+        for &code in &codes {
+            referenced[code] = true;
+        }
+        let referenced_codes = (0..values.len() as u64)
+            .filter(|&code| referenced[code as usize])
+            .collect::<Vec<_>>();
+        let candidates = if referenced_codes.len() == values.len() {
+            values
+        } else {
+            take(
+                &values,
+                PrimitiveArray::from(referenced_codes.clone()).as_ref(),
+            )?
+        };
+
+        // The first k rows can take at most k distinct values, so rank the referenced values
+        // first and only consider the rows that hold one of the best values.
+        let best = top_k(&candidates, k, descending)?.into_primitive()?;
+        let mut ranks = vec![usize::MAX; referenced.len()];
+        for (rank, &candidate) in best.maybe_null_slice::<u64>().iter().enumerate() {
+            ranks[referenced_codes[candidate as usize] as usize] = rank;
+        }
+
+        let mut rows = codes
+            .iter()
+            .enumerate()
+            .filter_map(|(row, &code)| {
+                let rank = ranks[code];
+                (rank != usize::MAX).then_some((rank, row as u64))
+            })
+            .collect::<Vec<_>>();
+        if rows.len() > k {
+            rows.select_nth_unstable(k);
+            rows.truncate(k);
+        }
+        rows.sort_unstable();
+
+        Ok(
+            PrimitiveArray::from(rows.into_iter().map(|(_, row)| row).collect::<Vec<_>>())
+                .into_array(),
+        )
+    }
+}
+
+impl IsInFn for DictArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        // Evaluate membership once per distinct value, then expand through the codes.
+        let matches = is_in(self.values(), values)?;
+        take(matches, self.codes())
+    }
+}
+
 impl TakeFn for DictArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         // Dict
@@ -51,8 +178,8 @@ impl TakeFn for DictArray {
 }
 
 impl FilterFn for DictArray {
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
-        let codes = filter(self.codes(), predicate)?;
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        let codes = filter(self.codes(), mask)?;
         Self::try_new(codes, self.values()).map(|a| a.into_array())
     }
 }
@@ -64,10 +191,55 @@ impl SliceFn for DictArray {
     }
 }
 
+impl SearchSortedFn for DictArray {
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        // The dictionary values are not required to be sorted, so we compare each of them against
+        // the needle once and then binary search over the codes using those orderings.
+        let values = self.values();
+        let value_orderings = (0..values.len())
+            .map(|i| Ok(scalar_at(&values, i)?.partial_cmp(value)))
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        Ok(DictSearch {
+            codes: self.codes(),
+            value_orderings,
+        }
+        .search_sorted(value, side))
+    }
+}
+
+/// Orders the codes of a [`DictArray`] against a needle whose ordering relative to each
+/// dictionary value has already been computed.
+struct DictSearch {
+    codes: Array,
+    value_orderings: Vec<Option<Ordering>>,
+}
+
+impl IndexOrd<Scalar> for DictSearch {
+    fn index_cmp(&self, idx: usize, _elem: &Scalar) -> Option<Ordering> {
+        let code: usize = scalar_at_unchecked(&self.codes, idx)
+            .as_ref()
+            .try_into()
+            .vortex_expect("Invalid dict index");
+        self.value_orderings[code]
+    }
+}
+
+impl Len for DictSearch {
+    fn len(&self) -> usize {
+        self.codes.len()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use vortex::accessor::ArrayAccessor;
-    use vortex::array::{PrimitiveArray, VarBinViewArray};
+    use vortex::array::{ConstantArray, PrimitiveArray, VarBinViewArray};
+    use vortex::compute::unary::{negate, scalar_at};
+    use vortex::compute::{
+        between, bitwise, is_in, search_sorted, slice, top_k, BitwiseOperator, Inclusivity,
+        SearchResult, SearchSortedSide,
+    };
     use vortex::{IntoArray, IntoArrayVariant, ToArray};
     use vortex_dtype::{DType, Nullability};
 
@@ -112,4 +284,182 @@ mod test {
                 .unwrap(),
         );
     }
+
+    #[test]
+    fn search_sorted_unsorted_dictionary() {
+        // Codes are sorted by value, but the dictionary itself is not.
+        let dict = DictArray::try_new(
+            PrimitiveArray::from(vec![2u8, 2, 0, 0, 0, 3, 1, 1]).into_array(),
+            PrimitiveArray::from(vec![20, 50, 10, 30]).into_array(),
+        )
+        .unwrap()
+        .into_array();
+
+        assert_eq!(
+            search_sorted(&dict, 20, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(2)
+        );
+        assert_eq!(
+            search_sorted(&dict, 20, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(5)
+        );
+        assert_eq!(
+            search_sorted(&dict, 40, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(6)
+        );
+        assert_eq!(
+            search_sorted(&dict, 60, SearchSortedSide::Right).unwrap(),
+            SearchResult::NotFound(8)
+        );
+        assert_eq!(
+            search_sorted(&dict, 5, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(0)
+        );
+
+        let sliced = slice(&dict, 3, 7).unwrap();
+        assert_eq!(
+            search_sorted(&sliced, 30, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(2)
+        );
+    }
+
+    #[test]
+    fn search_sorted_strings() {
+        let reference = VarBinViewArray::from_iter_str(["apple", "banana", "banana", "cherry"]);
+        let (codes, values) = dict_encode_varbinview(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array())
+            .unwrap()
+            .into_array();
+
+        assert_eq!(
+            search_sorted(&dict, "banana", SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(1)
+        );
+        assert_eq!(
+            search_sorted(&dict, "banana", SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(3)
+        );
+        assert_eq!(
+            search_sorted(&dict, "blueberry", SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(3)
+        );
+    }
+
+    #[test]
+    fn is_in_nullable_strings() {
+        let reference = VarBinViewArray::from_iter_nullable_str([
+            Some("US"),
+            None,
+            Some("CA"),
+            Some("FR"),
+            Some("US"),
+        ]);
+        let (codes, values) = dict_encode_varbinview(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let needles = VarBinViewArray::from_iter_str(["US", "MX", "CA"]);
+        let matches = is_in(dict.as_ref(), needles.as_ref()).unwrap();
+        let matches = (0..matches.len())
+            .map(|i| scalar_at(&matches, i).unwrap().value().as_bool().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            matches,
+            vec![Some(true), None, Some(true), Some(false), Some(true)]
+        );
+    }
+
+    #[test]
+    fn bitwise_constant() {
+        let reference = PrimitiveArray::from_nullable_vec(vec![Some(6u8), None, Some(12), Some(6)]);
+        let (codes, values) = dict_encode_typed_primitive::<u8>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let result = bitwise(
+            dict.as_ref(),
+            ConstantArray::new(3u8, 4),
+            BitwiseOperator::ShiftRight,
+        )
+        .unwrap();
+        assert!(DictArray::try_from(&result).is_ok());
+        let result = result.into_primitive().unwrap();
+        assert_eq!(result.maybe_null_slice::<u8>()[..1], [0]);
+        assert_eq!(result.maybe_null_slice::<u8>()[2..], [1, 0]);
+        assert!(result.validity().is_null(1));
+    }
+
+    #[test]
+    fn negate_values() {
+        let reference =
+            PrimitiveArray::from_nullable_vec(vec![Some(3i64), None, Some(-7), Some(3)]);
+        let (codes, values) = dict_encode_typed_primitive::<i64>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+
+        let negated = negate(dict.as_ref()).unwrap();
+        let negated_dict = DictArray::try_from(&negated).unwrap();
+        assert_eq!(negated_dict.values().len(), dict.values().len());
+        assert_eq!(
+            (0..negated.len())
+                .map(|i| {
+                    let scalar = scalar_at(&negated, i).unwrap();
+                    (!scalar.is_null()).then(|| i64::try_from(&scalar).unwrap())
+                })
+                .collect::<Vec<_>>(),
+            vec![Some(-3), None, Some(7), Some(-3)]
+        );
+    }
+
+    #[test]
+    fn between_strings() {
+        let reference = VarBinViewArray::from_iter_nullable_str([
+            Some("US"),
+            None,
+            Some("CA"),
+            Some("FR"),
+            Some("US"),
+        ]);
+        let (codes, values) = dict_encode_varbinview(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let matches = between(
+            dict.as_ref(),
+            &"CA".into(),
+            &"GB".into(),
+            Inclusivity::Upper,
+        )
+        .unwrap();
+        let matches = (0..matches.len())
+            .map(|i| scalar_at(&matches, i).unwrap().value().as_bool().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            matches,
+            vec![Some(false), None, Some(false), Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn top_k_values() {
+        let reference = PrimitiveArray::from_nullable_vec(vec![
+            Some(3i32),
+            Some(9),
+            None,
+            Some(3),
+            Some(9),
+            Some(1),
+        ]);
+        let (codes, values) = dict_encode_typed_primitive::<i32>(&reference);
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+
+        let largest = top_k(dict.as_ref(), 3, true)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(largest.maybe_null_slice::<u64>(), &[1, 4, 0]);
+        let smallest = top_k(dict.as_ref(), 6, false)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(smallest.maybe_null_slice::<u64>(), &[5, 0, 3, 1, 4, 2]);
+
+        // The slice keeps the whole dictionary, including the 1 it no longer refers to.
+        let sliced = slice(dict.as_ref(), 0, 2).unwrap();
+        let smallest = top_k(&sliced, 1, false).unwrap().into_primitive().unwrap();
+        assert_eq!(smallest.maybe_null_slice::<u64>(), &[0]);
+    }
 }
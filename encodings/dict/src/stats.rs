@@ -1,24 +1,26 @@
 use std::collections::HashMap;
 
-use vortex::stats::{ArrayStatistics, ArrayStatisticsCompute, Stat, StatsSet};
+use vortex::stats::{ArrayStatistics, ArrayStatisticsCompute, Stat, StatsOptions, StatsSet};
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::DictArray;
 
 impl ArrayStatisticsCompute for DictArray {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        _stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
         let mut stats: HashMap<Stat, Scalar> = HashMap::new();
 
         if let Some(rc) = self.codes().statistics().compute(Stat::RunCount) {
             stats.insert(Stat::RunCount, rc);
         }
-        if let Some(min) = self.values().statistics().compute(Stat::Min) {
-            stats.insert(Stat::Min, min);
-        }
-        if let Some(max) = self.values().statistics().compute(Stat::Max) {
-            stats.insert(Stat::Max, max);
-        }
         if let Some(is_constant) = self.codes().statistics().compute(Stat::IsConstant) {
             stats.insert(Stat::IsConstant, is_constant);
         }
@@ -26,11 +28,12 @@ impl ArrayStatisticsCompute for DictArray {
             stats.insert(Stat::NullCount, null_count);
         }
 
-        // if dictionary is sorted
+        // if dictionary is sorted, which computes the values' bounds along with it
         if self
             .values()
             .statistics()
-            .compute_is_sorted()
+            .compute_with_options(Stat::IsSorted, options)
+            .and_then(|is_sorted| bool::try_from(&is_sorted).ok())
             .unwrap_or(false)
         {
             if let Some(codes_are_sorted) = self.codes().statistics().compute(Stat::IsSorted) {
@@ -44,6 +47,52 @@ impl ArrayStatisticsCompute for DictArray {
             }
         }
 
-        Ok(StatsSet::from(stats))
+        let mut stats = StatsSet::from(stats);
+        // Values that no code refers to, e.g. after slicing, still take part in these, so they
+        // are only bounds.
+        for stat in [Stat::Min, Stat::Max] {
+            if let Some(bound) = self
+                .values()
+                .statistics()
+                .compute_with_options(stat, options)
+            {
+                stats.set_bound(stat, bound);
+            }
+        }
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::VarBinArray;
+    use vortex::stats::{ArrayStatistics, Stat, StatsOptions};
+    use vortex::IntoArray;
+    use vortex_dtype::{DType, Nullability};
+    use vortex_scalar::Scalar;
+
+    use crate::{dict_encode_varbin, DictArray};
+
+    #[test]
+    fn bound_length_option() {
+        let (codes, values) = dict_encode_varbin(&VarBinArray::from_vec(
+            vec!["abcdef", "abcxyz", "abcdef"],
+            DType::Utf8(Nullability::NonNullable),
+        ));
+        let dict = DictArray::try_new(codes.into_array(), values.into_array()).unwrap();
+        let options = StatsOptions {
+            max_string_bound_length: 4,
+            ..StatsOptions::default()
+        };
+        let stats = dict.statistics();
+        assert_eq!(
+            stats.compute_with_options(Stat::Min, &options),
+            Some(Scalar::from("abcd"))
+        );
+        assert_eq!(
+            stats.compute_with_options(Stat::Max, &options),
+            Some(Scalar::from("abcy"))
+        );
+        assert!(!stats.is_exact(Stat::Max));
     }
 }
@@ -0,0 +1,165 @@
+use arrow_buffer::BooleanBufferBuilder;
+use fastlanes::BitPacking;
+use num_traits::AsPrimitive;
+use vortex::array::{BoolArray, SparseArray};
+use vortex::compute::{between, integer_bounds, BetweenFn, Inclusivity};
+use vortex::{Array, IntoArray, IntoArrayVariant};
+use vortex_dtype::{match_each_unsigned_integer_ptype, NativePType};
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::BitPackedArray;
+
+impl BetweenFn for BitPackedArray {
+    fn between(
+        &self,
+        lower: &Scalar,
+        upper: &Scalar,
+        inclusivity: Inclusivity,
+    ) -> VortexResult<Array> {
+        if self.ptype().is_signed_int() {
+            return between(self.clone().into_primitive()?, lower, upper, inclusivity);
+        }
+
+        let range = integer_bounds(lower, upper, inclusivity)?;
+        match_each_unsigned_integer_ptype!(self.ptype(), |$P| {
+            // Clamp the bounds to the domain of the type, leaving an empty range when none of it
+            // can match.
+            let max = i128::from($P::MAX);
+            let (lower, upper) = if *range.start() > max || *range.end() < 0 {
+                (1, 0)
+            } else {
+                ((*range.start()).max(0) as $P, (*range.end()).min(max) as $P)
+            };
+            between_packed::<$P>(self, lower, upper)
+        })
+    }
+}
+
+/// Evaluate the inclusive range over the values of each block as it is unpacked.
+fn between_packed<T>(array: &BitPackedArray, lower: T, upper: T) -> VortexResult<Array>
+where
+    T: NativePType + BitPacking + AsPrimitive<u64>,
+{
+    let in_range = |v: T| lower <= v && v <= upper;
+    let offset = array.offset() as usize;
+    let len = array.len();
+    let bit_width = array.bit_width() as usize;
+    let packed = array.packed_slice::<T>();
+    let elems_per_chunk = 128 * bit_width / size_of::<T>();
+    let num_chunks = (offset + len + 1023) / 1024;
+
+    let mut matches = BooleanBufferBuilder::new(len);
+    let mut decoded = [T::zero(); 1024];
+    for chunk in 0..num_chunks {
+        if bit_width > 0 {
+            // SAFETY: each chunk holds exactly one block of 1024 values packed into `bit_width`
+            // bits, and the output has room for all of them.
+            unsafe {
+                BitPacking::unchecked_unpack(
+                    bit_width,
+                    &packed[chunk * elems_per_chunk..][..elems_per_chunk],
+                    &mut decoded,
+                )
+            };
+        }
+        let start = if chunk == 0 { offset } else { 0 };
+        let end = (offset + len - chunk * 1024).min(1024);
+        for &v in &decoded[start..end] {
+            matches.append(in_range(v));
+        }
+    }
+
+    if let Some(patches) = array.patches() {
+        let patches = SparseArray::try_from(patches)?;
+        let values = patches.values().into_primitive()?;
+        for (idx, &v) in patches
+            .resolved_indices()
+            .into_iter()
+            .zip(values.maybe_null_slice::<T>())
+        {
+            matches.set_bit(idx, in_range(v));
+        }
+    }
+
+    BoolArray::try_new(matches.finish(), array.validity()).map(IntoArray::into_array)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+    use vortex::compute::{between, slice, Inclusivity};
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_scalar::Scalar;
+
+    use crate::BitPackedArray;
+
+    #[test]
+    fn between_sliced_blocks() {
+        let values = (0u32..3000).map(|i| i % 61).collect::<Vec<_>>();
+        let packed = BitPackedArray::encode(PrimitiveArray::from(values.clone()).as_ref(), 6)
+            .unwrap()
+            .into_array();
+        let sliced = slice(&packed, 700, 2900).unwrap();
+
+        let result = between(
+            &sliced,
+            &Scalar::from(10u32),
+            &Scalar::from(20u32),
+            Inclusivity::Lower,
+        )
+        .unwrap();
+        assert!(result.with_dyn(|a| a.as_bool_array().is_some()));
+        assert_eq!(
+            result
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            values[700..2900]
+                .iter()
+                .map(|&v| (10..20).contains(&v))
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn between_patched() {
+        let packed =
+            BitPackedArray::encode(PrimitiveArray::from(vec![1u32, 2, 300, 4, 7]).as_ref(), 3)
+                .unwrap();
+        let result = between(
+            packed.as_ref(),
+            &Scalar::from(4u32),
+            &Scalar::from(1000u32),
+            Inclusivity::Both,
+        )
+        .unwrap();
+        assert_eq!(
+            result
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![false, false, true, true, true]
+        );
+
+        let none = between(
+            packed.as_ref(),
+            &Scalar::from(5u32),
+            &Scalar::from(5u32),
+            Inclusivity::Neither,
+        )
+        .unwrap();
+        assert_eq!(
+            none.into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![false; 5]
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use arrow_buffer::ArrowNativeType;
+use fastlanes::BitPacking;
+use num_traits::{AsPrimitive, PrimInt, WrappingShl, WrappingShr};
+use vortex::array::ConstantArray;
+use vortex::compute::BitwiseOperator;
+use vortex::{Array, IntoArray};
+use vortex_buffer::Buffer;
+use vortex_dtype::{match_each_unsigned_integer_ptype, NativePType};
+use vortex_error::{VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::BitPackedArray;
+
+/// Apply a bitwise operator against a constant directly on the packed words.
+///
+/// Packing only permutes the bits within each block of 1024 values, so `&`, `|` and `^` against
+/// a constant that fits in the bit width can be applied word-by-word against the constant packed
+/// into the same layout.
+pub(crate) fn bitwise_packed(
+    array: &BitPackedArray,
+    other: &Array,
+    operator: BitwiseOperator,
+) -> Option<VortexResult<Array>> {
+    if !operator.is_commutative() || array.patches().is_some() {
+        return None;
+    }
+    let constant = ConstantArray::try_from(other).ok()?.owned_scalar();
+    if constant.is_null() {
+        return None;
+    }
+
+    match_each_unsigned_integer_ptype!(array.ptype(), |$P| {
+        bitwise_typed::<$P>(array, &constant, operator)
+    })
+}
+
+fn bitwise_typed<T>(
+    array: &BitPackedArray,
+    constant: &Scalar,
+    operator: BitwiseOperator,
+) -> Option<VortexResult<Array>>
+where
+    T: NativePType
+        + BitPacking
+        + ArrowNativeType
+        + PrimInt
+        + WrappingShl
+        + WrappingShr
+        + AsPrimitive<u32>
+        + for<'a> TryFrom<&'a Scalar, Error = VortexError>,
+{
+    let value = T::try_from(constant).ok()?;
+    let bit_width = array.bit_width() as usize;
+    if bit_width < T::T && value >> bit_width != T::zero() {
+        return None;
+    }
+
+    let mut packed_constant = vec![T::zero(); 128 * bit_width / size_of::<T>()];
+    if bit_width > 0 {
+        // SAFETY: the output holds exactly one block of 1024 values packed into `bit_width` bits.
+        unsafe { BitPacking::unchecked_pack(bit_width, &[value; 1024], &mut packed_constant) };
+    }
+
+    let packed = array
+        .packed_slice::<T>()
+        .iter()
+        .zip(packed_constant.iter().cycle())
+        .map(|(&word, &c)| operator.apply(word, c))
+        .collect::<Vec<_>>();
+
+    let validity = if constant.dtype().is_nullable() {
+        array.validity().into_nullable()
+    } else {
+        array.validity()
+    };
+
+    Some(
+        BitPackedArray::try_new_from_offset(
+            Buffer::from(packed),
+            array.ptype(),
+            validity,
+            None,
+            array.bit_width(),
+            array.len(),
+            array.offset(),
+        )
+        .map(IntoArray::into_array),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{ConstantArray, PrimitiveArray};
+    use vortex::compute::{bitwise, slice, BitwiseOperator};
+    use vortex::{IntoArray, IntoArrayVariant};
+
+    use crate::BitPackedArray;
+
+    #[test]
+    fn bitwise_packed_constant() {
+        let values = (0u32..3000).map(|i| i % 61).collect::<Vec<_>>();
+        let packed = BitPackedArray::encode(PrimitiveArray::from(values.clone()).as_ref(), 6)
+            .unwrap()
+            .into_array();
+        let sliced = slice(&packed, 700, 2900).unwrap();
+
+        for (op, f) in [
+            (BitwiseOperator::And, (|a, b| a & b) as fn(u32, u32) -> u32),
+            (BitwiseOperator::Or, |a, b| a | b),
+            (BitwiseOperator::Xor, |a, b| a ^ b),
+        ] {
+            let result = bitwise(&sliced, ConstantArray::new(0b101010u32, 2200), op).unwrap();
+            assert!(BitPackedArray::try_from(&result).is_ok());
+            assert_eq!(
+                result.into_primitive().unwrap().maybe_null_slice::<u32>(),
+                values[700..2900]
+                    .iter()
+                    .map(|&v| f(v, 0b101010))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn bitwise_wide_constant() {
+        let packed =
+            BitPackedArray::encode(PrimitiveArray::from(vec![1u32, 2, 3]).as_ref(), 2).unwrap();
+        let result = bitwise(
+            packed.as_ref(),
+            ConstantArray::new(4u32, 3),
+            BitwiseOperator::Or,
+        )
+        .unwrap();
+        assert!(BitPackedArray::try_from(&result).is_err());
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<u32>(),
+            &[5, 6, 7]
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashSet;
+
+use arrow_buffer::BooleanBuffer;
+use num_traits::AsPrimitive;
+use vortex::array::{BoolArray, PrimitiveArray, SparseArray};
+use vortex::compute::{is_in, IsInFn};
+use vortex::validity::Validity;
+use vortex::{Array, IntoArray, IntoArrayVariant};
+use vortex_dtype::{match_each_unsigned_integer_ptype, NativePType};
+use vortex_error::VortexResult;
+
+use crate::BitPackedArray;
+
+impl IsInFn for BitPackedArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        let values = values.clone().into_primitive()?;
+        match_each_unsigned_integer_ptype!(self.ptype(), |$P| {
+            is_in_typed::<$P>(self, &values)
+        })
+    }
+}
+
+fn is_in_typed<T: NativePType + AsPrimitive<u64>>(
+    array: &BitPackedArray,
+    values: &PrimitiveArray,
+) -> VortexResult<Array> {
+    // Packed values fit in `bit_width` bits, so wider needles can only match one of the patches.
+    let max_packed = array.max_packed_value() as u64;
+    let patch_values = array
+        .patches()
+        .map(|patches| -> VortexResult<HashSet<u64>> {
+            let values = SparseArray::try_from(patches)?.values().into_primitive()?;
+            Ok(values
+                .maybe_null_slice::<T>()
+                .iter()
+                .map(|v| v.as_())
+                .collect())
+        })
+        .transpose()?
+        .unwrap_or_default();
+
+    let validity = values.validity();
+    let needles = values
+        .maybe_null_slice::<T>()
+        .iter()
+        .enumerate()
+        .filter(|&(i, v)| {
+            validity.is_valid(i) && (v.as_() <= max_packed || patch_values.contains(&v.as_()))
+        })
+        .map(|(_, &v)| v)
+        .collect::<Vec<_>>();
+
+    // Only decode the array when some of its values can match.
+    if needles.is_empty() {
+        return BoolArray::try_new(BooleanBuffer::new_unset(array.len()), array.validity())
+            .map(IntoArray::into_array);
+    }
+
+    is_in(
+        array.clone().into_primitive()?,
+        PrimitiveArray::from_vec(needles, Validity::NonNullable),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+    use vortex::compute::is_in;
+    use vortex::IntoArrayVariant;
+
+    use crate::BitPackedArray;
+
+    #[test]
+    fn is_in_out_of_range() {
+        let packed =
+            BitPackedArray::encode(PrimitiveArray::from(vec![1u32, 2, 3, 4, 5]).as_ref(), 3)
+                .unwrap();
+        let matches = is_in(
+            packed.as_ref(),
+            PrimitiveArray::from(vec![4u32, 8, 100]).as_ref(),
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, false, false, true, false]
+        );
+
+        let none = is_in(
+            packed.as_ref(),
+            PrimitiveArray::from(vec![8u32, 100]).as_ref(),
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(none.boolean_buffer().count_set_bits(), 0);
+    }
+
+    #[test]
+    fn is_in_patched() {
+        let packed =
+            BitPackedArray::encode(PrimitiveArray::from(vec![1u32, 2, 300, 4]).as_ref(), 3)
+                .unwrap();
+        let matches = is_in(
+            packed.as_ref(),
+            PrimitiveArray::from(vec![300u32, 2]).as_ref(),
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, true, false]
+        );
+
+        // Too wide to be packed, and not one of the patches.
+        let none = is_in(
+            packed.as_ref(),
+            PrimitiveArray::from(vec![299u32, 1000]).as_ref(),
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(none.boolean_buffer().count_set_bits(), 0);
+    }
+}
@@ -1,14 +1,33 @@
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{ArrayCompute, SearchSortedFn, SliceFn, TakeFn};
+use vortex::compute::{
+    ArrayCompute, BetweenFn, BitwiseOperator, IsInFn, SearchSortedFn, SliceFn, TakeFn,
+};
+use vortex::Array;
+use vortex_error::VortexResult;
 
 use crate::BitPackedArray;
 
+mod between;
+mod bitwise;
+mod is_in;
 mod scalar_at;
 mod search_sorted;
 mod slice;
 mod take;
 
 impl ArrayCompute for BitPackedArray {
+    fn between(&self) -> Option<&dyn BetweenFn> {
+        Some(self)
+    }
+
+    fn bitwise(&self, other: &Array, operator: BitwiseOperator) -> Option<VortexResult<Array>> {
+        bitwise::bitwise_packed(self, other, operator)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -6,7 +6,10 @@ use fastlanes::BitPacking;
 use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::array::{PrimitiveArray, SparseArray};
 use vortex::encoding::ids;
-use vortex::stats::{ArrayStatisticsCompute, StatsSet};
+use vortex::stats::{
+    compute_canonical_statistics, ArrayStatistics, ArrayStatisticsCompute, Stat, StatsOptions,
+    StatsSet,
+};
 use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
 use vortex::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoCanonical, TypedArray};
@@ -238,7 +241,34 @@ impl AcceptArrayVisitor for BitPackedArray {
     }
 }
 
-impl ArrayStatisticsCompute for BitPackedArray {}
+impl ArrayStatisticsCompute for BitPackedArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        // Only the validity is stored unpacked, everything else needs the values.
+        if stat == Stat::NullCount {
+            let null_count = match self.logical_validity() {
+                LogicalValidity::AllValid(_) => Some(0),
+                LogicalValidity::AllInvalid(len) => Some(len),
+                LogicalValidity::Array(validity) => validity
+                    .statistics()
+                    .compute_true_count()
+                    .map(|true_count| self.len() - true_count),
+            };
+            return Ok(null_count
+                .map(|n| StatsSet::of(stat, n.into()))
+                .unwrap_or_default());
+        }
+
+        compute_canonical_statistics(self.as_ref(), stat, options)
+    }
+}
 
 impl ArrayTrait for BitPackedArray {
     fn nbytes(&self) -> usize {
@@ -259,6 +289,7 @@ impl PrimitiveArrayTrait for BitPackedArray {}
 #[cfg(test)]
 mod test {
     use vortex::array::PrimitiveArray;
+    use vortex::stats::ArrayStatistics;
     use vortex::{IntoArray, IntoArrayVariant};
 
     use crate::BitPackedArray;
@@ -278,6 +309,20 @@ mod test {
         assert_eq!(results, expected);
     }
 
+    #[test]
+    fn test_stats() {
+        let values = vec![Some(3u32), None, Some(1), Some(2_000), None];
+        let uncompressed = PrimitiveArray::from_nullable_vec(values);
+        let packed = BitPackedArray::encode(uncompressed.as_ref(), 2)
+            .unwrap()
+            .into_array();
+        let stats = packed.statistics();
+        assert_eq!(stats.compute_null_count(), Some(2));
+        assert_eq!(stats.compute_min::<u32>(), Some(1));
+        assert_eq!(stats.compute_max::<u32>(), Some(2_000));
+        assert_eq!(stats.compute_is_sorted(), Some(false));
+    }
+
     #[test]
     fn test_encode_too_wide() {
         let values = vec![Some(1u8), None, Some(1), None, Some(1), None];
@@ -1,13 +1,13 @@
 use arrayref::{array_mut_ref, array_ref};
-use fastlanes::{Delta, Transpose};
+use arrow_buffer::NullBuffer;
+use fastlanes::{Delta, Transpose, FL_ORDER};
 use num_traits::{WrappingAdd, WrappingSub};
 use vortex::array::PrimitiveArray;
 use vortex::compute::unary::fill_forward;
-use vortex::compute::SliceFn;
 use vortex::validity::Validity;
 use vortex::IntoArrayVariant;
 use vortex_dtype::{match_each_unsigned_integer_ptype, NativePType, Nullability};
-use vortex_error::VortexResult;
+use vortex_error::{VortexExpect as _, VortexResult};
 
 use crate::DeltaArray;
 
@@ -50,28 +50,11 @@ where
     // Loop over all the 1024-element chunks.
     if num_chunks > 0 {
         let mut transposed: [T; 1024] = [T::default(); 1024];
-        let mut base = [T::default(); T::LANES];
 
         for i in 0..num_chunks {
             let start_elem = i * 1024;
             let chunk: &[T; 1024] = array_ref![array, start_elem, 1024];
-            Transpose::transpose(chunk, &mut transposed);
-
-            // Initialize and store the base vector for each chunk
-            // TODO(ngates): avoid copying the base vector
-            base.copy_from_slice(&transposed[0..T::LANES]);
-            bases.extend(base);
-
-            deltas.reserve(1024);
-            let delta_len = deltas.len();
-            unsafe {
-                deltas.set_len(delta_len + 1024);
-                Delta::delta(
-                    &transposed,
-                    &base,
-                    array_mut_ref![deltas[delta_len..], 0, 1024],
-                );
-            }
+            compress_chunk(chunk, &mut transposed, &mut bases, &mut deltas);
         }
     }
 
@@ -97,19 +80,95 @@ where
     (bases, deltas)
 }
 
+/// Delta-encode the running sum of `array`, one chunk at a time.
+///
+/// Each full chunk is summed into a scratch buffer and encoded from there, so the sums of the
+/// whole array are never materialized. The scalar-encoded remainder needs no sums at all: its base
+/// is the sum before the remainder and its deltas are the values themselves. Null values do not
+/// contribute to the sum.
+pub(crate) fn cumsum_compress<T: NativePType + Delta + Transpose + WrappingAdd>(
+    array: &[T],
+    nulls: Option<&NullBuffer>,
+) -> (Vec<T>, Vec<T>)
+where
+    [(); T::LANES]:,
+{
+    let num_chunks = array.len() / 1024;
+    let masked = |i: usize| {
+        if nulls.map_or(true, |n| n.is_valid(i)) {
+            array[i]
+        } else {
+            T::default()
+        }
+    };
+
+    let mut bases = Vec::with_capacity(num_chunks * T::LANES + 1);
+    let mut deltas = Vec::with_capacity(array.len());
+    let mut sum = T::default();
+
+    if num_chunks > 0 {
+        let mut sums: [T; 1024] = [T::default(); 1024];
+        let mut transposed: [T; 1024] = [T::default(); 1024];
+
+        for i in 0..num_chunks {
+            for (j, value) in sums.iter_mut().enumerate() {
+                sum = sum.wrapping_add(&masked(i * 1024 + j));
+                *value = sum;
+            }
+            compress_chunk(&sums, &mut transposed, &mut bases, &mut deltas);
+        }
+    }
+
+    if array.len() % 1024 > 0 {
+        bases.push(sum);
+        deltas.extend((num_chunks * 1024..array.len()).map(masked));
+    }
+
+    (bases, deltas)
+}
+
+/// Transpose and delta-encode a single full chunk, appending its bases and deltas.
+fn compress_chunk<T: NativePType + Delta + Transpose>(
+    chunk: &[T; 1024],
+    transposed: &mut [T; 1024],
+    bases: &mut Vec<T>,
+    deltas: &mut Vec<T>,
+) where
+    [(); T::LANES]:,
+{
+    Transpose::transpose(chunk, transposed);
+
+    // The base vector of each chunk is the first row of the transposed chunk
+    let base: &[T; T::LANES] = transposed[..T::LANES]
+        .try_into()
+        .ok()
+        .vortex_expect("a chunk has a value for every lane");
+    bases.extend_from_slice(base);
+
+    deltas.reserve(1024);
+    let delta_len = deltas.len();
+    unsafe {
+        deltas.set_len(delta_len + 1024);
+        Delta::delta(
+            transposed,
+            base,
+            array_mut_ref![deltas[delta_len..], 0, 1024],
+        );
+    }
+}
+
 pub fn delta_decompress(array: DeltaArray) -> VortexResult<PrimitiveArray> {
     let bases = array.bases().into_primitive()?;
     let deltas = array.deltas().into_primitive()?;
-    let decoded = match_each_unsigned_integer_ptype!(deltas.ptype(), |$T| {
-        PrimitiveArray::from_vec(
-            decompress_primitive::<$T>(bases.maybe_null_slice(), deltas.maybe_null_slice()),
-            array.validity()
-        )
-    });
-
-    decoded
-        .slice(array.offset(), array.offset() + array.len())?
-        .into_primitive()
+    let (offset, len) = (array.offset(), array.len());
+    // The validity is logical, so it is only attached once the values have been sliced to match.
+    Ok(match_each_unsigned_integer_ptype!(deltas.ptype(), |$T| {
+        let mut values =
+            decompress_primitive::<$T>(bases.maybe_null_slice(), deltas.maybe_null_slice());
+        values.drain(..offset);
+        values.truncate(len);
+        PrimitiveArray::from_vec(values, array.validity())
+    }))
 }
 
 fn decompress_primitive<T: NativePType + Delta + Transpose + WrappingAdd>(
@@ -118,6 +177,20 @@ fn decompress_primitive<T: NativePType + Delta + Transpose + WrappingAdd>(
 ) -> Vec<T>
 where
     [(); T::LANES]:,
+{
+    let mut output = Vec::with_capacity(deltas.len());
+    for_each_chunk(bases, deltas, |values| output.extend_from_slice(values));
+    output
+}
+
+/// Decode each chunk in turn into a reused buffer and pass its values to `f`, so that a single
+/// pass over the array never holds more than one decoded chunk.
+pub(crate) fn for_each_chunk<T: NativePType + Delta + Transpose + WrappingAdd>(
+    bases: &[T],
+    deltas: &[T],
+    mut f: impl FnMut(&[T]),
+) where
+    [(); T::LANES]:,
 {
     // How many fastlanes vectors we will process.
     let num_chunks = deltas.len() / 1024;
@@ -125,29 +198,23 @@ where
     // How long each base vector will be.
     let lanes = T::LANES;
 
-    // Allocate a result array.
-    let mut output = Vec::with_capacity(deltas.len());
-
     // Loop over all the chunks
     if num_chunks > 0 {
         let mut transposed: [T; 1024] = [T::default(); 1024];
-        let mut base = [T::default(); T::LANES];
+        let mut values: [T; 1024] = [T::default(); 1024];
 
         for i in 0..num_chunks {
             let start_elem = i * 1024;
             let chunk: &[T; 1024] = array_ref![deltas, start_elem, 1024];
-
-            // Initialize the base vector for this chunk
-            // TODO(ngates): avoid copying the bases
-            base.copy_from_slice(&bases[i * lanes..(i + 1) * lanes]);
-            Delta::undelta(chunk, &base, &mut transposed);
-
-            let output_len = output.len();
-            unsafe { output.set_len(output_len + 1024) }
-            Transpose::untranspose(&transposed, array_mut_ref![output[output_len..], 0, 1024]);
+            let base: &[T; T::LANES] = bases[i * lanes..(i + 1) * lanes]
+                .try_into()
+                .ok()
+                .vortex_expect("a chunk has a base for every lane");
+            Delta::undelta(chunk, base, &mut transposed);
+            Transpose::untranspose(&transposed, &mut values);
+            f(&values);
         }
     }
-    assert_eq!(output.len() % 1024, 0);
 
     // The remainder was encoded with scalar logic, so we need to scalar decode it.
     let remainder_size = deltas.len() % 1024;
@@ -155,18 +222,110 @@ where
         let chunk = &deltas[num_chunks * 1024..];
         assert_eq!(bases.len(), num_chunks * lanes + 1);
         let mut base_scalar = bases[num_chunks * lanes];
-        for next_diff in chunk {
-            let next = next_diff.wrapping_add(&base_scalar);
-            output.push(next);
-            base_scalar = next;
+        let values = chunk
+            .iter()
+            .map(|next_diff| {
+                base_scalar = next_diff.wrapping_add(&base_scalar);
+                base_scalar
+            })
+            .collect::<Vec<_>>();
+        f(&values);
+    }
+}
+
+/// Differences between adjacent values, `values[i + 1] - values[i]`, over every physical value.
+///
+/// In a full chunk, a value and the one before it are adjacent rows of the same lane unless the
+/// value's index is a multiple of eight, so seven in every eight differences are read directly from
+/// the deltas. Only the rest are taken from the undeltaed chunk, which is left transposed. The
+/// scalar-encoded remainder stores the differences as its deltas.
+pub(crate) fn diff_primitive<T: NativePType + Delta + WrappingAdd + WrappingSub>(
+    bases: &[T],
+    deltas: &[T],
+) -> Vec<T>
+where
+    [(); T::LANES]:,
+{
+    let num_chunks = deltas.len() / 1024;
+    let lanes = T::LANES;
+
+    let mut output = Vec::with_capacity(deltas.len().saturating_sub(1));
+    // The last value of the previous chunk.
+    let mut previous: Option<T> = None;
+
+    if num_chunks > 0 {
+        let mut transposed: [T; 1024] = [T::default(); 1024];
+
+        for i in 0..num_chunks {
+            let chunk: &[T; 1024] = array_ref![deltas, i * 1024, 1024];
+            let base: &[T; T::LANES] = bases[i * lanes..(i + 1) * lanes]
+                .try_into()
+                .ok()
+                .vortex_expect("a chunk has a base for every lane");
+            Delta::undelta(chunk, base, &mut transposed);
+
+            // Transposition keeps the first value of the chunk in place.
+            if let Some(previous) = previous {
+                output.push(transposed[0].wrapping_sub(&previous));
+            }
+            output.extend((1..1024).map(|j| {
+                if j % 8 == 0 {
+                    transposed[transposed_position(j)]
+                        .wrapping_sub(&transposed[transposed_position(j - 1)])
+                } else {
+                    chunk[transposed_position(j)]
+                }
+            }));
+            previous = Some(transposed[transposed_position(1023)]);
         }
     }
 
+    let remainder_size = deltas.len() % 1024;
+    if remainder_size > 0 {
+        let chunk = &deltas[num_chunks * 1024..];
+        if let Some(previous) = previous {
+            let first = bases[num_chunks * lanes].wrapping_add(&chunk[0]);
+            output.push(first.wrapping_sub(&previous));
+        }
+        output.extend_from_slice(&chunk[1..]);
+    }
+
     output
 }
 
+/// The position of the value at `index` of a chunk once transposed, i.e. the inverse of
+/// `transpose`.
+fn transposed_position(index: usize) -> usize {
+    (index % 8) * 128 + FL_ORDER[(index / 8) % 8] * 16 + index / 64
+}
+
+/// Decode the value at `index` of a single chunk, given the bases and deltas of just that chunk.
+///
+/// A full chunk is only summed along the lane holding the value, which reads one delta per row of
+/// the transposed chunk rather than decoding all 1,024 values.
+pub(crate) fn decompress_single<T: NativePType + Delta + WrappingAdd>(
+    bases: &[T],
+    deltas: &[T],
+    index: usize,
+) -> T {
+    if deltas.len() < 1024 {
+        return deltas[..=index]
+            .iter()
+            .fold(bases[0], |value, delta| value.wrapping_add(delta));
+    }
+
+    let position = transposed_position(index);
+    let lane = (position % 128) % T::LANES;
+    let row = FL_ORDER[(position % 128 - lane) / 16] * 8 + position / 128;
+    (0..=row)
+        .map(|r| FL_ORDER[r / 8] * 16 + (r % 8) * 128 + lane)
+        .fold(bases[lane], |value, i| value.wrapping_add(&deltas[i]))
+}
+
 #[cfg(test)]
 mod test {
+    use std::cmp::min;
+
     use super::*;
 
     #[test]
@@ -183,6 +342,29 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_decompress_single() {
+        fn check<T: NativePType + Delta + Transpose + WrappingAdd + WrappingSub>(values: Vec<T>)
+        where
+            [(); T::LANES]:,
+        {
+            let (bases, deltas) = compress_primitive(&values);
+            for (chunk, expected) in values.chunks(1024).enumerate() {
+                let chunk_bases =
+                    &bases[chunk * T::LANES..min((chunk + 1) * T::LANES, bases.len())];
+                let chunk_deltas = &deltas[chunk * 1024..min((chunk + 1) * 1024, deltas.len())];
+                for (i, value) in expected.iter().enumerate() {
+                    assert_eq!(decompress_single(chunk_bases, chunk_deltas, i), *value);
+                }
+            }
+        }
+
+        check((0..1500).map(|i| (i * 7 % 251) as u8).collect());
+        check((0..1500).map(|i| (i * i) as u16).collect());
+        check((0..2100u32).map(|i| i * 3).collect());
+        check((0..1100u64).map(|i| i << 40).collect());
+    }
+
     fn do_roundtrip_test<T: NativePType>(input: Vec<T>) {
         let delta = DeltaArray::try_from_vec(input.clone()).unwrap();
         assert_eq!(delta.len(), input.len());
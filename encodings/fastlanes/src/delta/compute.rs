@@ -1,35 +1,154 @@
-use std::cmp::min;
+use std::cmp::{min, Ordering};
 
+use num_traits::ToPrimitive;
+use vortex::array::PrimitiveArray;
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{slice, ArrayCompute, SliceFn};
-use vortex::{Array, IntoArray, IntoArrayVariant};
-use vortex_error::{VortexExpect, VortexResult};
+use vortex::compute::{
+    slice, ArrayCompute, CumsumFn, DiffFn, FilterFn, FilterMask, IndexOrd, Len, SearchResult,
+    SearchSorted, SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
+};
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{
+    match_each_integer_ptype, match_each_unsigned_integer_ptype, NativePType, PType,
+};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexExpect, VortexResult};
 use vortex_scalar::Scalar;
 
+use super::compress::{decompress_single, diff_primitive, for_each_chunk};
 use crate::DeltaArray;
 
 impl ArrayCompute for DeltaArray {
+    fn cumsum(&self) -> Option<&dyn CumsumFn> {
+        Some(self)
+    }
+
+    fn diff(&self) -> Option<&dyn DiffFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl CumsumFn for DeltaArray {
+    fn cumsum(&self) -> VortexResult<Array> {
+        let ptype = PType::try_from(self.dtype())?;
+        let bases = self.bases().into_primitive()?;
+        let deltas = self.deltas().into_primitive()?;
+        let nulls = self.logical_validity().to_null_buffer()?;
+        let (offset, len) = (self.offset(), self.len());
+
+        let sums = match_each_unsigned_integer_ptype!(ptype, |$T| {
+            let mut sums = Vec::with_capacity(len);
+            let mut sum = <$T>::default();
+            let mut position = 0;
+            for_each_chunk(
+                bases.maybe_null_slice::<$T>(),
+                deltas.maybe_null_slice::<$T>(),
+                |values| {
+                    for &value in values {
+                        if (offset..offset + len).contains(&position) {
+                            if nulls.as_ref().map_or(true, |n| n.is_valid(position - offset)) {
+                                sum = sum.wrapping_add(value);
+                            }
+                            sums.push(sum);
+                        }
+                        position += 1;
+                    }
+                },
+            );
+            PrimitiveArray::from_vec(sums, self.validity())
+        });
+        Ok(sums.into_array())
+    }
+}
+
+impl DiffFn for DeltaArray {
+    fn diff(&self) -> VortexResult<Array> {
+        if self.is_empty() {
+            return Ok(self.clone().into_array());
+        }
+
+        let ptype = PType::try_from(self.dtype())?;
+        let (offset, len) = (self.offset(), self.len());
+        let validity = match self.logical_validity().to_null_buffer()? {
+            None => self.validity().slice(1, len)?,
+            Some(nulls) => {
+                let n = len - 1;
+                Validity::from(&nulls.inner().slice(0, n) & &nulls.inner().slice(1, n))
+            }
+        };
+
+        let bases = self.bases().into_primitive()?;
+        let deltas = self.deltas().into_primitive()?;
+        let diffs = match_each_unsigned_integer_ptype!(ptype, |$T| {
+            let mut diffs =
+                diff_primitive(bases.maybe_null_slice::<$T>(), deltas.maybe_null_slice::<$T>());
+            diffs.drain(..offset);
+            diffs.truncate(len - 1);
+            PrimitiveArray::from_vec(diffs, validity)
+        });
+        Ok(diffs.into_array())
+    }
 }
 
 impl ScalarAtFn for DeltaArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
-        let decompressed = slice(self, index, index + 1)?.into_primitive()?;
-        ScalarAtFn::scalar_at(&decompressed, 0)
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+
+        // Only the bases and deltas of the chunk holding the value are read.
+        let position = index + self.offset();
+        let chunk = position / 1024;
+        let lanes = self.lanes();
+        let bases = slice(
+            self.bases(),
+            chunk * lanes,
+            min((chunk + 1) * lanes, self.bases_len()),
+        )?
+        .into_primitive()?;
+        let deltas = slice(
+            self.deltas(),
+            chunk * 1024,
+            min((chunk + 1) * 1024, self.deltas_len()),
+        )?
+        .into_primitive()?;
+
+        let nullability = self.dtype().nullability();
+        let ptype = PType::try_from(self.dtype())?;
+        Ok(match_each_unsigned_integer_ptype!(ptype, |$T| {
+            Scalar::primitive(
+                decompress_single::<$T>(
+                    bases.maybe_null_slice(),
+                    deltas.maybe_null_slice(),
+                    position % 1024,
+                ),
+                nullability,
+            )
+        }))
     }
 
     fn scalar_at_unchecked(&self, index: usize) -> Scalar {
-        let decompressed = slice(self, index, index + 1)
-            .vortex_expect("DeltaArray slice for one value should work")
-            .into_primitive()
-            .vortex_expect("Converting slice into primitive should work");
-        ScalarAtFn::scalar_at_unchecked(&decompressed, 0)
+        ScalarAtFn::scalar_at(self, index).vortex_expect("DeltaArray scalar_at")
     }
 }
 
@@ -74,15 +193,279 @@ impl SliceFn for DeltaArray {
     }
 }
 
+impl SearchSortedFn for DeltaArray {
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        let ptype = PType::try_from(self.dtype())?;
+        match_each_unsigned_integer_ptype!(ptype, |$T| {
+            search_sorted_typed::<$T>(self, value, side)
+        })
+    }
+}
+
+/// The first value of every 1024-element chunk of a [`DeltaArray`] after the first, read directly
+/// from `bases`.
+///
+/// FastLanes transposition keeps the first element of a chunk in place, so it is always the first
+/// base of that chunk, both for full chunks and for the scalar-encoded remainder. The first chunk
+/// is skipped as it may start part-way through due to slicing.
+struct ChunkFirsts<'a, T> {
+    bases: &'a [T],
+    lanes: usize,
+    num_chunks: usize,
+}
+
+impl<T: NativePType> ChunkFirsts<'_, T> {
+    fn chunk_first(&self, chunk: usize) -> T {
+        self.bases[chunk * self.lanes]
+    }
+}
+
+impl<T: NativePType> IndexOrd<T> for ChunkFirsts<'_, T> {
+    fn index_cmp(&self, idx: usize, elem: &T) -> Option<Ordering> {
+        self.chunk_first(idx + 1).partial_cmp(elem)
+    }
+}
+
+impl<T> Len for ChunkFirsts<'_, T> {
+    fn len(&self) -> usize {
+        self.num_chunks - 1
+    }
+}
+
+/// Search a sorted [`DeltaArray`] by first locating the chunk that may contain the value using
+/// the chunk bases, and then decoding and searching only that chunk.
+fn search_sorted_typed<T>(
+    array: &DeltaArray,
+    value: &Scalar,
+    side: SearchSortedSide,
+) -> VortexResult<SearchResult>
+where
+    T: NativePType + for<'a> TryFrom<&'a Scalar, Error = VortexError>,
+{
+    let value: T = value.cast(array.dtype())?.as_ref().try_into()?;
+    let offset = array.offset();
+    let len = array.len();
+    if len == 0 {
+        return Ok(SearchResult::NotFound(0));
+    }
+
+    let num_chunks = (offset + len).div_ceil(1024);
+    let bases = array.bases().into_primitive()?;
+    let firsts = ChunkFirsts {
+        bases: bases.maybe_null_slice::<T>(),
+        lanes: array.lanes(),
+        num_chunks,
+    };
+
+    // The value can only be in the last chunk whose first value precedes it (on the requested
+    // side), or exactly at the start of the chunk after that.
+    let next_chunk = 1 + firsts.search_sorted(&value, side).to_index();
+    let chunk = next_chunk - 1;
+
+    let chunk_start = (chunk * 1024).saturating_sub(offset);
+    let chunk_stop = min((chunk + 1) * 1024 - offset, len);
+    let decoded = slice(array, chunk_start, chunk_stop)?.into_primitive()?;
+    let result = decoded.maybe_null_slice::<T>().search_sorted(&value, side);
+
+    Ok(match result {
+        SearchResult::Found(i) => SearchResult::Found(chunk_start + i),
+        // The value sorts after the whole chunk, so it lands on the start of the next chunk.
+        SearchResult::NotFound(i) if chunk_start + i == chunk_stop && next_chunk < num_chunks => {
+            if firsts.chunk_first(next_chunk) == value {
+                SearchResult::Found(chunk_stop)
+            } else {
+                SearchResult::NotFound(chunk_stop)
+            }
+        }
+        SearchResult::NotFound(i) => SearchResult::NotFound(chunk_start + i),
+    })
+}
+
+impl TakeFn for DeltaArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let ptype = PType::try_from(self.dtype())?;
+        let primitive_indices = indices.clone().into_primitive()?;
+        let len = self.len();
+        let positions = match_each_integer_ptype!(primitive_indices.ptype(), |$I| {
+            primitive_indices
+                .maybe_null_slice::<$I>()
+                .iter()
+                .map(|&idx| {
+                    idx.to_usize()
+                        .filter(|&idx| idx < len)
+                        .ok_or_else(|| vortex_err!(OutOfBounds: idx as usize, 0, len))
+                })
+                .collect::<VortexResult<Vec<_>>>()?
+        });
+
+        let taken_validity = self.validity().take(indices)?;
+        let taken = match_each_unsigned_integer_ptype!(ptype, |$T| {
+            PrimitiveArray::from_vec(
+                take_primitive::<$T>(self, positions.into_iter())?,
+                taken_validity,
+            )
+        });
+        Ok(taken.into_array())
+    }
+}
+
+impl FilterFn for DeltaArray {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        let ptype = PType::try_from(self.dtype())?;
+        let filtered_validity = self.validity().filter(mask)?;
+        let filtered = match_each_unsigned_integer_ptype!(ptype, |$T| {
+            PrimitiveArray::from_vec(
+                take_primitive::<$T>(self, mask.indices().iter().copied())?,
+                filtered_validity,
+            )
+        });
+        Ok(filtered.into_array())
+    }
+}
+
+/// Take values from a [`DeltaArray`], decoding only the 1024-element chunks that are referenced
+/// by `indices`.
+///
+/// Each chunk is decoded against its own slice of `bases`, and the most recently decoded chunk is
+/// kept around so that runs of indices into the same chunk are served without re-decoding.
+fn take_primitive<T: NativePType>(
+    array: &DeltaArray,
+    indices: impl ExactSizeIterator<Item = usize>,
+) -> VortexResult<Vec<T>> {
+    let offset = array.offset();
+    let len = array.len();
+
+    let mut output = Vec::with_capacity(indices.len());
+    // The logical start of the currently decoded chunk, along with its decoded values.
+    let mut decoded: Option<(usize, PrimitiveArray)> = None;
+
+    for idx in indices {
+        if idx >= len {
+            vortex_bail!(OutOfBounds: idx, 0, len);
+        }
+
+        let chunk = (idx + offset) / 1024;
+        let chunk_start = (chunk * 1024).saturating_sub(offset);
+        if decoded
+            .as_ref()
+            .map_or(true, |(start, _)| *start != chunk_start)
+        {
+            let chunk_stop = min((chunk + 1) * 1024 - offset, len);
+            let values = slice(array, chunk_start, chunk_stop)?.into_primitive()?;
+            decoded = Some((chunk_start, values));
+        }
+
+        let (start, values) = decoded
+            .as_ref()
+            .vortex_expect("chunk containing index must have been decoded");
+        output.push(values.maybe_null_slice::<T>()[idx - *start]);
+    }
+
+    Ok(output)
+}
+
 #[cfg(test)]
 mod test {
-    use vortex::compute::slice;
+    use vortex::accessor::ArrayAccessor;
+    use vortex::array::BoolArray;
     use vortex::compute::unary::{scalar_at, scalar_at_unchecked};
+    use vortex::compute::{cumsum, diff, filter, search_sorted, slice, take};
+    use vortex::validity::Validity;
     use vortex::IntoArrayVariant;
+    use vortex_dtype::Nullability;
     use vortex_error::VortexError;
 
     use super::*;
 
+    #[test]
+    fn test_diff_remainder() {
+        let delta = DeltaArray::try_from_vec(vec![3u32, 5, 9, 10, 20]).unwrap();
+        let sliced = slice(delta.as_ref(), 1, 5).unwrap();
+        let diffs = diff(&sliced).unwrap().into_primitive().unwrap();
+        assert_eq!(diffs.maybe_null_slice::<u32>(), &[4, 1, 10]);
+    }
+
+    #[test]
+    fn test_diff_chunked() {
+        let values = (0u32..3000).map(|i| i * i).collect::<Vec<_>>();
+        let delta = DeltaArray::try_from_vec(values.clone()).unwrap();
+        let diffs = diff(delta.as_ref()).unwrap().into_primitive().unwrap();
+        assert_eq!(
+            diffs.maybe_null_slice::<u32>(),
+            values.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_diff_cumsum_sliced_nullable() {
+        let values = (0u32..3000)
+            .map(|i| (i % 13 != 0).then_some(i * 7 % 1000))
+            .collect::<Vec<_>>();
+        let delta = DeltaArray::try_from_primitive_array(&PrimitiveArray::from_nullable_vec(
+            values.clone(),
+        ))
+        .unwrap();
+        let sliced = slice(delta.as_ref(), 1000, 2900).unwrap();
+        assert!(DeltaArray::try_from(&sliced).is_ok());
+        let decoded = sliced.clone().into_primitive().unwrap();
+
+        for (encoded, canonical) in [
+            (diff(&sliced).unwrap(), diff(decoded.as_ref()).unwrap()),
+            (cumsum(&sliced).unwrap(), cumsum(decoded.as_ref()).unwrap()),
+        ] {
+            assert_eq!(
+                ArrayAccessor::<u32>::with_iterator(&encoded.into_primitive().unwrap(), |iter| {
+                    iter.map(|v| v.copied()).collect::<Vec<_>>()
+                })
+                .unwrap(),
+                ArrayAccessor::<u32>::with_iterator(&canonical.into_primitive().unwrap(), |iter| {
+                    iter.map(|v| v.copied()).collect::<Vec<_>>()
+                })
+                .unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_cumsum_roundtrip() {
+        for len in [10, 2500] {
+            let values = PrimitiveArray::from((0..len).map(|i| (i % 7) as u16).collect::<Vec<_>>());
+            let delta = DeltaArray::try_from_cumsum(&values).unwrap();
+            assert_eq!(
+                delta.into_primitive().unwrap().maybe_null_slice::<u16>(),
+                cumsum(values.as_ref())
+                    .unwrap()
+                    .into_primitive()
+                    .unwrap()
+                    .maybe_null_slice::<u16>()
+            );
+        }
+
+        let values = PrimitiveArray::from_nullable_vec(
+            (0..2500u32).map(|i| (i % 5 != 0).then_some(i)).collect(),
+        );
+        let delta = DeltaArray::try_from_cumsum(&values).unwrap();
+        assert_eq!(
+            delta.into_primitive().unwrap().maybe_null_slice::<u32>(),
+            cumsum(values.as_ref())
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u32>()
+        );
+
+        // A validity bitmap with no nulls is summed like any other.
+        let values = PrimitiveArray::from_vec(
+            (0..10u32).collect(),
+            Validity::Array(BoolArray::from(vec![true; 10]).into_array()),
+        );
+        let delta = DeltaArray::try_from_cumsum(&values).unwrap();
+        assert_eq!(
+            delta.into_primitive().unwrap().maybe_null_slice::<u32>(),
+            [0, 1, 3, 6, 10, 15, 21, 28, 36, 45]
+        );
+    }
+
     #[test]
     fn test_scalar_at_non_jagged_array() {
         let delta = DeltaArray::try_from_vec((0u32..2048).collect())
@@ -402,4 +785,175 @@ mod test {
             (1015..1030).collect::<Vec<_>>(),
         );
     }
+
+    #[test]
+    fn test_take_indices() {
+        let delta = DeltaArray::try_from_vec((0u32..4096).map(|i| i * 3).collect()).unwrap();
+        let indices = PrimitiveArray::from(vec![0, 125, 2047, 2049, 2151, 4095]).into_array();
+
+        let taken = TakeFn::take(&delta, &indices)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(
+            taken.maybe_null_slice::<u32>(),
+            &[0, 375, 6141, 6147, 6453, 12285]
+        );
+    }
+
+    #[test]
+    fn test_take_unordered_jagged() {
+        let delta = DeltaArray::try_from_vec((0u32..2000).collect()).unwrap();
+        let indices = PrimitiveArray::from(vec![1999u16, 3, 1024, 1023, 1500, 0]).into_array();
+
+        let taken = TakeFn::take(&delta, &indices)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(
+            taken.maybe_null_slice::<u32>(),
+            &[1999, 3, 1024, 1023, 1500, 0]
+        );
+    }
+
+    #[test]
+    fn test_take_sliced_indices() {
+        let delta = DeltaArray::try_from_vec((0u32..4096).collect()).unwrap();
+        let sliced = SliceFn::slice(&delta, 128, 2050).unwrap();
+        let indices = PrimitiveArray::from(vec![1919, 1921, 0]).into_array();
+
+        let taken = take(&sliced, &indices).unwrap().into_primitive().unwrap();
+        assert_eq!(taken.maybe_null_slice::<u32>(), &[2047, 2049, 128]);
+    }
+
+    #[test]
+    fn test_take_out_of_bounds() {
+        let delta = DeltaArray::try_from_vec((0u32..100).collect()).unwrap();
+        let indices = PrimitiveArray::from(vec![100]).into_array();
+
+        assert!(matches!(
+            TakeFn::take(&delta, &indices),
+            Err(VortexError::OutOfBounds(100, 0, 100, _))
+        ));
+        assert!(take(delta.as_ref(), PrimitiveArray::from(vec![-1i32]).as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_filter() {
+        let delta = DeltaArray::try_from_vec((0u64..3000).map(|i| i * 5).collect()).unwrap();
+        let sliced = SliceFn::slice(&delta, 1000, 2500).unwrap();
+        let mask = FilterMask::from_indices(1500, [0, 23, 24, 1048, 1499]);
+
+        let filtered = filter(&sliced, &mask).unwrap().into_primitive().unwrap();
+        assert_eq!(
+            filtered.maybe_null_slice::<u64>(),
+            &[5000, 5115, 5120, 10240, 12495]
+        );
+    }
+
+    #[test]
+    fn test_nullable_scalar_at_and_filter() {
+        let values = (0u32..1500)
+            .map(|i| (i % 3 != 0).then_some(i))
+            .collect::<Vec<_>>();
+        let delta =
+            DeltaArray::try_from_primitive_array(&PrimitiveArray::from_nullable_vec(values))
+                .unwrap();
+
+        assert_eq!(
+            scalar_at(&delta, 0).unwrap(),
+            Scalar::null(delta.dtype().clone())
+        );
+        assert_eq!(
+            scalar_at(&delta, 1028).unwrap(),
+            Scalar::primitive(1028u32, Nullability::Nullable)
+        );
+
+        let filtered = filter(&delta, &FilterMask::from_indices(1500, [1, 3, 1499])).unwrap();
+        assert!(!filtered.with_dyn(|a| a.is_valid(1)));
+        assert_eq!(
+            scalar_at(&filtered, 2).unwrap(),
+            Scalar::primitive(1499u32, Nullability::Nullable)
+        );
+    }
+
+    #[test]
+    fn test_search_sorted() {
+        // Each value is repeated twice, so duplicates straddle the chunk boundary at 1024.
+        let delta = DeltaArray::try_from_vec((0u32..3000).map(|i| i / 2).collect())
+            .unwrap()
+            .into_array();
+
+        assert_eq!(
+            search_sorted(&delta, 10u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(20)
+        );
+        assert_eq!(
+            search_sorted(&delta, 10u32, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(22)
+        );
+        assert_eq!(
+            search_sorted(&delta, 512u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(1024)
+        );
+        assert_eq!(
+            search_sorted(&delta, 511u32, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(1024)
+        );
+        assert_eq!(
+            search_sorted(&delta, 1499u32, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(3000)
+        );
+        assert_eq!(
+            search_sorted(&delta, 1500u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(3000)
+        );
+    }
+
+    #[test]
+    fn test_search_sorted_not_found() {
+        let delta = DeltaArray::try_from_vec((0u32..4096).map(|i| i * 2 + 10).collect())
+            .unwrap()
+            .into_array();
+
+        assert_eq!(
+            search_sorted(&delta, 5u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(0)
+        );
+        assert_eq!(
+            search_sorted(&delta, 2057u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(1024)
+        );
+        assert_eq!(
+            search_sorted(&delta, 2057u32, SearchSortedSide::Right).unwrap(),
+            SearchResult::NotFound(1024)
+        );
+    }
+
+    #[test]
+    fn test_search_sorted_sliced() {
+        let delta = DeltaArray::try_from_vec((0u32..4096).collect()).unwrap();
+        let sliced = SliceFn::slice(&delta, 1000, 2100).unwrap();
+
+        assert_eq!(
+            search_sorted(&sliced, 1000u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(0)
+        );
+        assert_eq!(
+            search_sorted(&sliced, 1024u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(24)
+        );
+        assert_eq!(
+            search_sorted(&sliced, 2099u32, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(1100)
+        );
+        assert_eq!(
+            search_sorted(&sliced, 2100u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(1100)
+        );
+        assert_eq!(
+            search_sorted(&sliced, 10u32, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(0)
+        );
+    }
 }
@@ -5,11 +5,13 @@ use serde::{Deserialize, Serialize};
 use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::array::PrimitiveArray;
 use vortex::encoding::ids;
-use vortex::stats::{ArrayStatisticsCompute, StatsSet};
+use vortex::stats::{
+    compute_canonical_statistics, ArrayStatisticsCompute, Stat, StatsOptions, StatsSet,
+};
 use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
 use vortex::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoCanonical};
-use vortex_dtype::{match_each_unsigned_integer_ptype, NativePType};
+use vortex_dtype::{match_each_unsigned_integer_ptype, NativePType, Nullability};
 use vortex_error::{vortex_bail, vortex_panic, VortexExpect as _, VortexResult};
 
 mod compress;
@@ -73,7 +75,39 @@ impl DeltaArray {
         Self::try_from_delta_compress_parts(
             bases.into_array(),
             deltas.into_array(),
-            Validity::NonNullable,
+            array.validity(),
+        )
+    }
+
+    /// Encode the running sum of `array` as a `DeltaArray`.
+    ///
+    /// The sums are computed and encoded one chunk at a time, see [`cumsum_compress`].
+    pub fn try_from_cumsum(array: &PrimitiveArray) -> VortexResult<Self> {
+        if !array.ptype().is_unsigned_int() {
+            vortex_bail!(
+                "DeltaArray requires unsigned integers, got {}",
+                array.ptype()
+            );
+        }
+
+        // The bases and deltas are always valid, whatever the validity of the values.
+        let parts_validity = match array.dtype().nullability() {
+            Nullability::NonNullable => Validity::NonNullable,
+            Nullability::Nullable => Validity::AllValid,
+        };
+        let nulls = array.logical_validity().to_null_buffer()?;
+        let (bases, deltas) = match_each_unsigned_integer_ptype!(array.ptype(), |$T| {
+            let (bases, deltas) =
+                cumsum_compress(array.maybe_null_slice::<$T>(), nulls.as_ref());
+            (
+                PrimitiveArray::from_vec(bases, parts_validity.clone()),
+                PrimitiveArray::from_vec(deltas, parts_validity),
+            )
+        });
+        Self::try_from_delta_compress_parts(
+            bases.into_array(),
+            deltas.into_array(),
+            array.validity(),
         )
     }
 
@@ -238,8 +272,48 @@ impl ArrayValidity for DeltaArray {
 impl AcceptArrayVisitor for DeltaArray {
     fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
         visitor.visit_child("bases", &self.bases())?;
-        visitor.visit_child("deltas", &self.deltas())
+        visitor.visit_child("deltas", &self.deltas())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for DeltaArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        // The values are running sums of the deltas, so no statistic can be derived from the
+        // children. Decode once and keep everything computed along the way.
+        compute_canonical_statistics(self.as_ref(), stat, options)
     }
 }
 
-impl ArrayStatisticsCompute for DeltaArray {}
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+    use vortex::stats::{ArrayStatistics, Stat};
+    use vortex::IntoArray;
+
+    use crate::DeltaArray;
+
+    #[test]
+    fn statistics() {
+        let delta = DeltaArray::try_from_primitive_array(&PrimitiveArray::from_nullable_vec(
+            (0u32..2000).map(|i| (i != 5).then_some(i * 2)).collect(),
+        ))
+        .unwrap()
+        .into_array();
+
+        assert_eq!(delta.statistics().compute_as::<u32>(Stat::Max), Some(3998));
+        assert_eq!(
+            delta.statistics().compute_as::<u64>(Stat::NullCount),
+            Some(1)
+        );
+        assert_eq!(delta.statistics().compute_is_sorted(), Some(true));
+    }
+}
@@ -1,19 +1,32 @@
 use std::ops::{AddAssign, Shl, Shr};
 
 use num_traits::{WrappingAdd, WrappingSub};
+use vortex::array::PrimitiveArray;
 use vortex::compute::unary::{scalar_at_unchecked, ScalarAtFn};
 use vortex::compute::{
-    filter, search_sorted, slice, take, ArrayCompute, FilterFn, SearchResult, SearchSortedFn,
-    SearchSortedSide, SliceFn, TakeFn,
+    between, filter, integer_bounds, is_in, search_sorted, slice, take, ArrayCompute, BetweenFn,
+    FilterFn, FilterMask, Inclusivity, IsInFn, SearchResult, SearchSortedFn, SearchSortedSide,
+    SliceFn, TakeFn,
+};
+use vortex::validity::Validity;
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{
+    match_each_integer_ptype, match_each_unsigned_integer_ptype, NativePType, PType,
 };
-use vortex::{Array, ArrayDType, IntoArray};
-use vortex_dtype::{match_each_integer_ptype, NativePType};
 use vortex_error::{VortexError, VortexExpect as _, VortexResult, VortexUnwrap as _};
 use vortex_scalar::{PValue, Scalar};
 
 use crate::FoRArray;
 
 impl ArrayCompute for FoRArray {
+    fn between(&self) -> Option<&dyn BetweenFn> {
+        Some(self)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -47,9 +60,9 @@ impl TakeFn for FoRArray {
 }
 
 impl FilterFn for FoRArray {
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
         Self::try_new(
-            filter(self.encoded(), predicate)?,
+            filter(self.encoded(), mask)?,
             self.owned_reference_scalar(),
             self.shift(),
         )
@@ -57,6 +70,99 @@ impl FilterFn for FoRArray {
     }
 }
 
+impl BetweenFn for FoRArray {
+    fn between(
+        &self,
+        lower: &Scalar,
+        upper: &Scalar,
+        inclusivity: Inclusivity,
+    ) -> VortexResult<Array> {
+        // Translate the bounds into offsets from the reference, and then into the shifted encoded
+        // space, rounding inwards so that only representable values are selected.
+        let range = integer_bounds(lower, upper, inclusivity)?;
+        let reference = match_each_integer_ptype!(self.ptype(), |$P| {
+            i128::from($P::try_from(&self.owned_reference_scalar())?)
+        });
+        let shift = self.shift();
+        let encoded_lower = ((range.start() - reference).max(0) + (1 << shift) - 1) >> shift;
+        let encoded_upper = (range.end() - reference) >> shift;
+
+        let encoded = self.encoded();
+        let encoded_ptype = PType::try_from(encoded.dtype())?;
+        let max = i128::from(encoded_ptype.max_value());
+        let (encoded_lower, encoded_upper) = if encoded_lower > encoded_upper || encoded_lower > max
+        {
+            (1, 0)
+        } else {
+            (encoded_lower, encoded_upper.min(max))
+        };
+        let (lower, upper) = match_each_unsigned_integer_ptype!(encoded_ptype, |$E| {
+            (
+                Scalar::from(encoded_lower as $E),
+                Scalar::from(encoded_upper as $E),
+            )
+        });
+        between(encoded, &lower, &upper, Inclusivity::Both)
+    }
+}
+
+impl IsInFn for FoRArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        let values = values.clone().into_primitive()?;
+        let deltas = match_each_integer_ptype!(self.ptype(), |$P| {
+            reference_deltas::<$P>(self, &values)?
+        })
+        .reinterpret_cast(self.ptype().to_unsigned());
+        let needles = match_each_unsigned_integer_ptype!(deltas.ptype(), |$P| {
+            shifted_deltas::<$P>(&deltas, self.shift())
+        });
+        is_in(self.encoded(), needles)
+    }
+}
+
+/// Translate the valid needles into offsets from the reference, dropping any outside the range of
+/// the array.
+fn reference_deltas<T>(array: &FoRArray, values: &PrimitiveArray) -> VortexResult<PrimitiveArray>
+where
+    T: NativePType + TryFrom<PValue, Error = VortexError> + WrappingSub,
+{
+    let min: T = array
+        .reference()
+        .as_pvalue()?
+        .vortex_expect("Reference value cannot be null")
+        .as_primitive::<T>()?;
+    let max = array
+        .known_max()?
+        .and_then(|max| max.value().as_pvalue().transpose())
+        .map(|max| max?.as_primitive::<T>())
+        .transpose()?;
+    let validity = values.validity();
+    let deltas = values
+        .maybe_null_slice::<T>()
+        .iter()
+        .enumerate()
+        .filter(|&(i, &v)| validity.is_valid(i) && v >= min && max.map_or(true, |max| v <= max))
+        .map(|(_, v)| v.wrapping_sub(&min))
+        .collect::<Vec<_>>();
+    Ok(PrimitiveArray::from_vec(deltas, Validity::NonNullable))
+}
+
+/// Shift the deltas into the encoded space, dropping any that are not representable.
+fn shifted_deltas<T>(deltas: &PrimitiveArray, shift: u8) -> PrimitiveArray
+where
+    T: NativePType + Shr<u8, Output = T> + Shl<u8, Output = T>,
+{
+    let encoded = deltas
+        .maybe_null_slice::<T>()
+        .iter()
+        .map(|&d| d >> shift)
+        .zip(deltas.maybe_null_slice::<T>())
+        .filter(|&(e, &d)| e << shift == d)
+        .map(|(e, _)| e)
+        .collect::<Vec<_>>();
+    PrimitiveArray::from_vec(encoded, Validity::NonNullable)
+}
+
 impl ScalarAtFn for FoRArray {
     fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
         Ok(self.scalar_at_unchecked(index))
@@ -166,10 +272,71 @@ where
 mod test {
     use vortex::array::PrimitiveArray;
     use vortex::compute::unary::scalar_at;
-    use vortex::compute::{search_sorted, SearchResult, SearchSortedSide};
+    use vortex::compute::{
+        between, is_in, search_sorted, Inclusivity, SearchResult, SearchSortedSide,
+    };
+    use vortex::stats::ArrayStatistics;
+    use vortex::IntoArrayVariant;
 
     use crate::{for_compress, FoRArray};
 
+    #[test]
+    fn for_is_in() {
+        let for_arr = for_compress(&PrimitiveArray::from(vec![-100, 1100, 1500, 1900])).unwrap();
+        let matches = is_in(
+            &for_arr,
+            PrimitiveArray::from(vec![-200, 1500, 1501, -100]).as_ref(),
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, true, false]
+        );
+    }
+
+    #[test]
+    fn for_is_in_shifted() {
+        let for_arr = FoRArray::try_new(
+            PrimitiveArray::from(vec![0u32, 1, 2]).into(),
+            10u32.into(),
+            2,
+        )
+        .unwrap();
+        let matches = is_in(
+            for_arr.as_ref(),
+            PrimitiveArray::from(vec![14u32, 15]).as_ref(),
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, false]
+        );
+    }
+
+    #[test]
+    fn for_is_in_known_max() {
+        let encoded = PrimitiveArray::from(vec![0u32, 1, 2]);
+        encoded.statistics().compute_max::<u32>();
+        let for_arr = FoRArray::try_new(encoded.into(), 10u32.into(), 0).unwrap();
+        assert_eq!(for_arr.known_max().unwrap(), Some(12u32.into()));
+
+        let matches = is_in(
+            for_arr.as_ref(),
+            PrimitiveArray::from(vec![12u32, 13, 1000]).as_ref(),
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, false, true]
+        );
+    }
+
     #[test]
     fn for_scalar_at() {
         let for_arr = for_compress(&PrimitiveArray::from(vec![-100, 1100, 1500, 1900])).unwrap();
@@ -277,4 +444,58 @@ mod test {
             SearchResult::NotFound(4)
         );
     }
+
+    #[test]
+    fn for_between() {
+        let values = vec![-100i32, 1100, 1500, 1900, 1501];
+        let for_arr = for_compress(&PrimitiveArray::from(values.clone())).unwrap();
+        for (lower, upper, inclusivity) in [
+            (1100, 1501, Inclusivity::Both),
+            (1100, 1501, Inclusivity::Neither),
+            (-500, 0, Inclusivity::Lower),
+            (2000, 3000, Inclusivity::Both),
+            (i32::MIN, i32::MAX, Inclusivity::Neither),
+        ] {
+            let result = between(&for_arr, &lower.into(), &upper.into(), inclusivity)
+                .unwrap()
+                .into_bool()
+                .unwrap();
+            let expected = values
+                .iter()
+                .map(|&v| {
+                    (v > lower || (inclusivity.includes_lower() && v == lower))
+                        && (v < upper || (inclusivity.includes_upper() && v == upper))
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                result.boolean_buffer().iter().collect::<Vec<_>>(),
+                expected,
+                "{lower} {inclusivity} {upper}"
+            );
+        }
+    }
+
+    #[test]
+    fn for_between_shifted() {
+        let for_arr = FoRArray::try_new(
+            PrimitiveArray::from(vec![0u32, 1, 2, 3]).into(),
+            10u32.into(),
+            2,
+        )
+        .unwrap();
+        // The array holds 10, 14, 18 and 22.
+        let result = between(
+            for_arr.as_ref(),
+            &11u32.into(),
+            &18u32.into(),
+            Inclusivity::Lower,
+        )
+        .unwrap()
+        .into_bool()
+        .unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, false, false]
+        );
+    }
 }
@@ -4,11 +4,14 @@ pub use compress::*;
 use serde::{Deserialize, Serialize};
 use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::encoding::ids;
-use vortex::stats::{ArrayStatisticsCompute, StatsSet};
+use vortex::stats::{
+    compute_canonical_statistics, ArrayStatistics, ArrayStatisticsCompute, Stat, StatsOptions,
+    StatsSet,
+};
 use vortex::validity::{ArrayValidity, LogicalValidity};
 use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
 use vortex::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoCanonical};
-use vortex_dtype::{DType, PType};
+use vortex_dtype::{match_each_integer_ptype, DType, PType};
 use vortex_error::{vortex_bail, vortex_panic, VortexExpect as _, VortexResult};
 use vortex_scalar::{Scalar, ScalarValue};
 
@@ -114,7 +117,68 @@ impl AcceptArrayVisitor for FoRArray {
     }
 }
 
-impl ArrayStatisticsCompute for FoRArray {}
+impl ArrayStatisticsCompute for FoRArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        // Decoding shifts the encoded values and adds the reference, which preserves their order.
+        let encoded = self.encoded();
+        let value = match stat {
+            Stat::Min | Stat::Max => encoded
+                .statistics()
+                .compute(stat)
+                .map(|bound| self.decode_bound(&bound))
+                .transpose()?,
+            Stat::IsSorted
+            | Stat::IsStrictSorted
+            | Stat::IsConstant
+            | Stat::RunCount
+            | Stat::NullCount => encoded.statistics().compute(stat),
+            _ => {
+                return compute_canonical_statistics(self.as_ref(), stat, options);
+            }
+        };
+        Ok(value
+            .map(|value| StatsSet::of(stat, value))
+            .unwrap_or_default())
+    }
+}
+
+impl FoRArray {
+    /// An upper bound of the values, if one is known without decoding them.
+    fn known_max(&self) -> VortexResult<Option<Scalar>> {
+        if let Some(max) = self.statistics().get(Stat::Max) {
+            return Ok(Some(max));
+        }
+        self.encoded()
+            .statistics()
+            .get(Stat::Max)
+            .map(|max| self.decode_bound(&max))
+            .transpose()
+    }
+
+    /// Decode a minimum or maximum of the encoded values.
+    fn decode_bound(&self, encoded: &Scalar) -> VortexResult<Scalar> {
+        let Some(encoded) = encoded.value().as_pvalue()? else {
+            return Ok(Scalar::null(self.dtype().clone()));
+        };
+        let reference = self
+            .reference()
+            .as_pvalue()?
+            .vortex_expect("Reference value cannot be null");
+        match_each_integer_ptype!(self.ptype(), |$P| {
+            let value = (encoded.reinterpret_cast(self.ptype()).as_primitive::<$P>()? << self.shift())
+                .wrapping_add(reference.as_primitive::<$P>()?);
+            Ok(Scalar::from(value))
+        })
+    }
+}
 
 impl ArrayTrait for FoRArray {
     fn nbytes(&self) -> usize {
@@ -129,3 +193,31 @@ impl ArrayVariants for FoRArray {
 }
 
 impl PrimitiveArrayTrait for FoRArray {}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+    use vortex::stats::{ArrayStatistics, Stat};
+    use vortex::IntoArray;
+    use vortex_scalar::Scalar;
+
+    use crate::{for_compress, FoRArray};
+
+    #[test]
+    fn stats_from_encoded() {
+        let array = PrimitiveArray::from(vec![-96i32, -64, -64, 32, 160]);
+        let compressed = FoRArray::try_from(for_compress(&array).unwrap()).unwrap();
+        assert_eq!(compressed.shift(), 5);
+
+        let stats = compressed.statistics();
+        assert_eq!(stats.compute(Stat::Min), Some(Scalar::from(-96i32)));
+        assert_eq!(stats.compute(Stat::Max), Some(Scalar::from(160i32)));
+        assert_eq!(stats.compute_is_sorted(), Some(true));
+        assert_eq!(stats.compute_is_strict_sorted(), Some(false));
+        assert_eq!(stats.compute_run_count(), Some(4));
+        assert_eq!(
+            stats.compute_trailing_zero_freq(),
+            array.into_array().statistics().compute_trailing_zero_freq()
+        );
+    }
+}
@@ -6,7 +6,9 @@
 pub use bitpacking::*;
 pub use delta::*;
 pub use r#for::*;
+pub use rle::*;
 
 mod bitpacking;
 mod delta;
 mod r#for;
+mod rle;
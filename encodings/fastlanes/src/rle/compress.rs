@@ -0,0 +1,177 @@
+use arrayref::array_ref;
+use vortex::array::PrimitiveArray;
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::{ArrayDType, IntoArrayVariant};
+use vortex_dtype::{match_each_native_ptype, NativePType, Nullability};
+use vortex_error::VortexResult;
+
+use crate::RLEArray;
+
+/// Split a primitive array into the run values, block-local run indices and per-block offsets into
+/// the run values of an [RLEArray].
+///
+/// Null values continue the run they fall in, and a run that starts with nulls takes the value of
+/// its first valid element, so nulls never break runs.
+pub fn rle_encode(
+    array: &PrimitiveArray,
+) -> VortexResult<(PrimitiveArray, PrimitiveArray, PrimitiveArray)> {
+    let validity = array.logical_validity().to_null_buffer()?;
+    let is_valid = |i: usize| validity.as_ref().map_or(true, |v| v.is_valid(i));
+    let values_validity = if array.dtype().nullability() == Nullability::NonNullable {
+        Validity::NonNullable
+    } else {
+        Validity::AllValid
+    };
+
+    let (values, indices, offsets) = match_each_native_ptype!(array.ptype(), |$P| {
+        let (values, indices, offsets) =
+            rle_encode_primitive(array.maybe_null_slice::<$P>(), is_valid);
+        (PrimitiveArray::from_vec(values, values_validity), indices, offsets)
+    });
+    Ok((
+        values,
+        PrimitiveArray::from_vec(indices, Validity::NonNullable),
+        PrimitiveArray::from_vec(offsets, Validity::NonNullable),
+    ))
+}
+
+fn rle_encode_primitive<T: NativePType>(
+    elements: &[T],
+    is_valid: impl Fn(usize) -> bool,
+) -> (Vec<T>, Vec<u16>, Vec<u64>) {
+    let mut values = Vec::new();
+    let mut indices = Vec::with_capacity(elements.len());
+    let mut offsets = Vec::with_capacity(elements.len().div_ceil(1024));
+
+    for (block_idx, block) in elements.chunks(1024).enumerate() {
+        let block_start = values.len();
+        offsets.push(block_start as u64);
+        let mut run_is_null = false;
+        for (i, &value) in block.iter().enumerate() {
+            let valid = is_valid(block_idx * 1024 + i);
+            let last = values.len().wrapping_sub(1);
+            if values.len() == block_start {
+                values.push(value);
+                run_is_null = !valid;
+            } else if valid && run_is_null {
+                values[last] = value;
+                run_is_null = false;
+            } else if valid && !values[last].is_eq(value) {
+                values.push(value);
+            }
+            indices.push((values.len() - block_start - 1) as u16);
+        }
+    }
+
+    (values, indices, offsets)
+}
+
+pub fn rle_decode(array: RLEArray) -> VortexResult<PrimitiveArray> {
+    let values = array.values().into_primitive()?;
+    let indices = array.indices().into_primitive()?;
+    let offsets = array.values_idx_offsets().into_primitive()?;
+    let decoded = match_each_native_ptype!(values.ptype(), |$P| {
+        PrimitiveArray::from_vec(
+            rle_decode_primitive::<$P>(
+                values.maybe_null_slice(),
+                indices.maybe_null_slice(),
+                offsets.maybe_null_slice(),
+                array.offset(),
+                array.len(),
+            ),
+            array.validity(),
+        )
+    });
+    Ok(decoded)
+}
+
+fn rle_decode_primitive<T: NativePType>(
+    values: &[T],
+    indices: &[u16],
+    offsets: &[u64],
+    offset: usize,
+    len: usize,
+) -> Vec<T> {
+    let mut output = Vec::with_capacity(indices.len());
+    let mut decoded = [T::default(); 1024];
+    for (block_idx, block) in indices.chunks(1024).enumerate() {
+        let start = offsets[block_idx] as usize;
+        let stop = offsets
+            .get(block_idx + 1)
+            .map_or(values.len(), |&o| o as usize);
+        let dict = &values[start..stop];
+        if block.len() == 1024 {
+            decode_block(array_ref![block, 0, 1024], dict, &mut decoded);
+            output.extend_from_slice(&decoded);
+        } else {
+            output.extend(block.iter().map(|&i| dict[i as usize]));
+        }
+    }
+    output.truncate(offset + len);
+    output.drain(..offset);
+    output
+}
+
+/// Look up every index of a full block in the block's run values.
+///
+/// The bounds of the whole block are checked up front so that the lookups compile to a vectorized
+/// gather.
+#[inline]
+fn decode_block<T: NativePType>(indices: &[u16; 1024], dict: &[T], output: &mut [T; 1024]) {
+    let max_index = indices.iter().copied().max().unwrap_or_default() as usize;
+    assert!(
+        max_index < dict.len(),
+        "RLE index {max_index} out of bounds for {} run values",
+        dict.len()
+    );
+    for (out, &index) in output.iter_mut().zip(indices.iter()) {
+        // SAFETY: every index was checked against the number of run values above.
+        *out = unsafe { *dict.get_unchecked(index as usize) };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+
+    use crate::{rle_decode, rle_encode, RLEArray};
+
+    #[test]
+    fn encode_blocks() {
+        let values = (0..2000u32).map(|i| i / 300).collect::<Vec<_>>();
+        let (run_values, indices, offsets) = rle_encode(&PrimitiveArray::from(values)).unwrap();
+        assert_eq!(
+            run_values.maybe_null_slice::<u32>(),
+            &[0, 1, 2, 3, 3, 4, 5, 6]
+        );
+        assert_eq!(offsets.maybe_null_slice::<u64>(), &[0, 4]);
+        let indices = indices.maybe_null_slice::<u16>();
+        assert_eq!(indices[1023], 3);
+        assert_eq!(indices[1024], 0);
+        assert_eq!(indices[1999], 3);
+    }
+
+    #[test]
+    fn nulls_do_not_break_runs() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![None, Some(5i64), None, Some(5), Some(7)]);
+        let (run_values, indices, _) = rle_encode(&array).unwrap();
+        assert_eq!(run_values.maybe_null_slice::<i64>(), &[5, 7]);
+        assert_eq!(indices.maybe_null_slice::<u16>(), &[0, 0, 0, 0, 1]);
+
+        let decoded = rle_decode(RLEArray::encode(&array).unwrap()).unwrap();
+        assert_eq!(&decoded.maybe_null_slice::<i64>()[1..], &[5, 5, 5, 7]);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let values = (0..5000)
+            .map(|i| ((i / 7) % 13) as f64 * 0.5)
+            .collect::<Vec<_>>();
+        let array = RLEArray::encode(&PrimitiveArray::from(values.clone())).unwrap();
+        assert_eq!(
+            rle_decode(array).unwrap().maybe_null_slice::<f64>(),
+            values.as_slice()
+        );
+    }
+}
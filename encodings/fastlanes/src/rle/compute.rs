@@ -0,0 +1,249 @@
+use std::cmp::min;
+
+use num_traits::ToPrimitive;
+use vortex::array::PrimitiveArray;
+use vortex::compute::unary::{scalar_at, ScalarAtFn};
+use vortex::compute::{slice, take, ArrayCompute, FilterFn, FilterMask, SliceFn, TakeFn};
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::{vortex_err, VortexResult, VortexUnwrap as _};
+use vortex_scalar::Scalar;
+
+use crate::RLEArray;
+
+impl ArrayCompute for RLEArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl RLEArray {
+    /// The position in the run values at which the runs of `block` start, or the number of run
+    /// values for the block past the end.
+    fn block_values_offset(&self, block: usize) -> VortexResult<usize> {
+        let offsets = self.values_idx_offsets();
+        if block == offsets.len() {
+            return Ok(self.values().len());
+        }
+        Ok(u64::try_from(&scalar_at(&offsets, block)?)? as usize)
+    }
+
+    /// Look up the values at the given physical positions, i.e. indices into [`Self::indices`].
+    fn gather(
+        &self,
+        positions: impl Iterator<Item = usize>,
+        validity: Validity,
+    ) -> VortexResult<Array> {
+        let positions = PrimitiveArray::from(positions.map(|p| p as u64).collect::<Vec<_>>());
+        let run_indices = take(self.indices(), positions.as_ref())?.into_primitive()?;
+        let offsets = self.values_idx_offsets().into_primitive()?;
+        let offsets = offsets.maybe_null_slice::<u64>();
+        let value_positions = positions
+            .maybe_null_slice::<u64>()
+            .iter()
+            .zip(run_indices.maybe_null_slice::<u16>())
+            .map(|(&p, &run)| offsets[p as usize / 1024] + run as u64)
+            .collect::<Vec<_>>();
+        let values = take(
+            self.values(),
+            PrimitiveArray::from(value_positions).as_ref(),
+        )?
+        .into_primitive()?;
+        Ok(PrimitiveArray::new(values.buffer().clone(), values.ptype(), validity).into_array())
+    }
+}
+
+impl ScalarAtFn for RLEArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+        let position = index + self.offset();
+        let run = u16::try_from(&scalar_at(self.indices(), position)?)?;
+        let values_offset = self.block_values_offset(position / 1024)?;
+        scalar_at(self.values(), values_offset + run as usize)
+    }
+
+    fn scalar_at_unchecked(&self, index: usize) -> Scalar {
+        ScalarAtFn::scalar_at(self, index).vortex_unwrap()
+    }
+}
+
+impl SliceFn for RLEArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        let physical_start = start + self.offset();
+        let physical_stop = stop + self.offset();
+
+        let indices_len = self.indices().len();
+        let num_blocks = self.values_idx_offsets().len();
+        let start_block = min(physical_start / 1024, num_blocks);
+        let stop_block = min(physical_stop.div_ceil(1024), num_blocks).max(start_block);
+
+        // Keep the run values of the kept blocks only, and rebase their offsets onto them.
+        let values_start = self.block_values_offset(start_block)?;
+        let values_stop = self.block_values_offset(stop_block)?;
+        let offsets =
+            slice(self.values_idx_offsets(), start_block, stop_block)?.into_primitive()?;
+        let offsets = offsets
+            .maybe_null_slice::<u64>()
+            .iter()
+            .map(|o| o - values_start as u64)
+            .collect::<Vec<_>>();
+
+        RLEArray::try_new(
+            slice(self.values(), values_start, values_stop)?,
+            slice(
+                self.indices(),
+                min(start_block * 1024, indices_len),
+                min(stop_block * 1024, indices_len),
+            )?,
+            PrimitiveArray::from(offsets).into_array(),
+            self.validity().slice(start, stop)?,
+            physical_start - start_block * 1024,
+            stop - start,
+        )
+        .map(IntoArray::into_array)
+    }
+}
+
+impl TakeFn for RLEArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let primitive_indices = indices.clone().into_primitive()?;
+        let len = self.len();
+        let offset = self.offset();
+        // Check every index first, as a sliced array can read runs past its end without error.
+        let positions = match_each_integer_ptype!(primitive_indices.ptype(), |$I| {
+            primitive_indices
+                .maybe_null_slice::<$I>()
+                .iter()
+                .map(|&idx| {
+                    idx.to_usize()
+                        .filter(|&idx| idx < len)
+                        .map(|idx| idx + offset)
+                        .ok_or_else(|| vortex_err!(OutOfBounds: idx as usize, 0, len))
+                })
+                .collect::<VortexResult<Vec<_>>>()?
+        });
+        self.gather(positions.into_iter(), self.validity().take(indices)?)
+    }
+}
+
+impl FilterFn for RLEArray {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        let offset = self.offset();
+        self.gather(
+            mask.indices().iter().map(|&i| i + offset),
+            self.validity().filter(mask)?,
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::accessor::ArrayAccessor;
+    use vortex::array::PrimitiveArray;
+    use vortex::compute::unary::scalar_at;
+    use vortex::compute::{filter, slice, take, FilterMask};
+    use vortex::{ArrayDType, IntoArray, IntoArrayVariant};
+    use vortex_scalar::Scalar;
+
+    use crate::RLEArray;
+
+    fn rle() -> (Vec<Option<i32>>, RLEArray) {
+        let values = (0..3000)
+            .map(|i| (i % 11 != 0).then_some(i / 100))
+            .collect::<Vec<_>>();
+        let array = RLEArray::encode(&PrimitiveArray::from_nullable_vec(values.clone())).unwrap();
+        (values, array)
+    }
+
+    #[test]
+    fn scalar_at_and_slice() {
+        let (values, array) = rle();
+        assert_eq!(
+            scalar_at(&array, 0).unwrap(),
+            Scalar::null(array.dtype().clone())
+        );
+        assert_eq!(
+            scalar_at(&array, 2999).unwrap(),
+            Scalar::from(29).cast(array.dtype()).unwrap()
+        );
+
+        let sliced = slice(&array, 1000, 2100).unwrap();
+        assert_eq!(
+            ArrayAccessor::<i32>::with_iterator(
+                &sliced.clone().into_primitive().unwrap(),
+                |iter| iter.map(|v| v.copied()).collect::<Vec<_>>()
+            )
+            .unwrap(),
+            values[1000..2100]
+        );
+        let resliced = slice(&sliced, 30, 1060).unwrap();
+        assert_eq!(
+            ArrayAccessor::<i32>::with_iterator(
+                &resliced.clone().into_primitive().unwrap(),
+                |iter| iter.map(|v| v.copied()).collect::<Vec<_>>()
+            )
+            .unwrap(),
+            values[1030..2060]
+        );
+        assert_eq!(
+            resliced.into_primitive().unwrap().maybe_null_slice::<i32>()[5],
+            values[1035].unwrap()
+        );
+        assert_eq!(slice(&array, 2048, 2048).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn take_and_filter() {
+        let (values, array) = rle();
+        let indices = [2999u32, 0, 1500, 1023, 1024];
+        let taken = take(&array, PrimitiveArray::from(indices.to_vec()).as_ref()).unwrap();
+        assert_eq!(
+            ArrayAccessor::<i32>::with_iterator(&taken.into_primitive().unwrap(), |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            indices
+                .iter()
+                .map(|&i| values[i as usize])
+                .collect::<Vec<_>>()
+        );
+
+        let sliced = slice(&array, 1000, 2100).unwrap();
+        assert!(take(&sliced, PrimitiveArray::from(vec![1100u32]).as_ref()).is_err());
+        assert!(take(&sliced, PrimitiveArray::from(vec![-1i32]).as_ref()).is_err());
+        assert!(take(&array, PrimitiveArray::from(vec![3000u32]).as_ref()).is_err());
+
+        let mask = FilterMask::from_indices(1100, [0, 23, 24, 1099]);
+        let filtered = filter(&sliced, &mask).unwrap();
+        assert_eq!(
+            ArrayAccessor::<i32>::with_iterator(&filtered.into_primitive().unwrap(), |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            [1000, 1023, 1024, 2099]
+                .iter()
+                .map(|&i| values[i])
+                .collect::<Vec<_>>()
+        );
+        assert!(
+            filter(array.into_array(), &FilterMask::from_indices(3000, []))
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,215 @@
+use std::fmt::{Debug, Display};
+
+pub use compress::*;
+use serde::{Deserialize, Serialize};
+use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use vortex::array::PrimitiveArray;
+use vortex::encoding::ids;
+use vortex::stats::{ArrayStatisticsCompute, StatsSet};
+use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
+use vortex::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoCanonical};
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexExpect as _, VortexResult};
+
+use crate::{bitpack_encode, find_min_patchless_bit_width};
+
+mod compress;
+mod compute;
+
+impl_encoding!("fastlanes.rle", ids::FL_RLE, RLE);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RLEMetadata {
+    validity: ValidityMetadata,
+    values_len: u64,
+    indices_len: u64,
+    offset: u16, // must be <1024
+}
+
+impl Display for RLEMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// A FastLanes-style run-length encoded array of primitive values.
+///
+/// The values are split into blocks of 1,024, and each block stores the values of its runs in
+/// order along with one index per element into those run values. Since a block holds at most
+/// 1,024 runs the indices are `u16`s that bit-pack to a handful of bits, and decoding a block is a
+/// branch-free gather rather than a walk over run ends.
+///
+/// # Examples
+///
+/// ```
+/// use vortex::array::PrimitiveArray;
+/// use vortex_fastlanes::RLEArray;
+/// let array = RLEArray::encode(&PrimitiveArray::from(vec![7u32, 7, 7, 3, 3])).unwrap();
+/// ```
+///
+/// # Details
+///
+/// The run values of every block are concatenated into `values`, and `values_idx_offsets` holds
+/// the position in `values` at which each block's run values start. Like
+/// [`DeltaArray`](crate::DeltaArray), slicing keeps whole blocks and records an `offset` into the
+/// first one, which must be strictly less than 1,024.
+impl RLEArray {
+    /// Run-length encode an array, bit-packing the run indices.
+    pub fn encode(array: &PrimitiveArray) -> VortexResult<Self> {
+        let (values, indices, values_idx_offsets) = rle_encode(array)?;
+        let bit_width = find_min_patchless_bit_width(&indices)?;
+        let indices = if bit_width > 0 {
+            bitpack_encode(indices, bit_width)?.into_array()
+        } else {
+            indices.into_array()
+        };
+        Self::try_new(
+            values.into_array(),
+            indices,
+            values_idx_offsets.into_array(),
+            array.validity(),
+            0,
+            array.len(),
+        )
+    }
+
+    pub fn try_new(
+        values: Array,
+        indices: Array,
+        values_idx_offsets: Array,
+        validity: Validity,
+        offset: usize,
+        length: usize,
+    ) -> VortexResult<Self> {
+        if offset >= 1024 {
+            vortex_bail!("offset must be less than 1024: {}", offset);
+        }
+        if offset + length > indices.len() {
+            vortex_bail!(
+                "offset + length, {} + {}, must be less than or equal to the number of indices: {}",
+                offset,
+                length,
+                indices.len()
+            );
+        }
+        if !matches!(indices.dtype(), DType::Primitive(PType::U16, _)) {
+            vortex_bail!(MismatchedTypes: "u16", indices.dtype());
+        }
+        if !matches!(values_idx_offsets.dtype(), DType::Primitive(PType::U64, _)) {
+            vortex_bail!(MismatchedTypes: "u64", values_idx_offsets.dtype());
+        }
+        if values_idx_offsets.len() != indices.len().div_ceil(1024) {
+            vortex_bail!(
+                "Expected one values offset per block of 1024 indices, got {} for {} indices",
+                values_idx_offsets.len(),
+                indices.len()
+            );
+        }
+
+        if values.dtype().nullability() != validity.nullability() {
+            vortex_bail!(
+                "RLEArray values of type {} do not match the nullability of its validity",
+                values.dtype()
+            );
+        }
+
+        let dtype = values.dtype().clone();
+        let metadata = RLEMetadata {
+            validity: validity.to_metadata(length)?,
+            values_len: values.len() as u64,
+            indices_len: indices.len() as u64,
+            offset: offset as u16,
+        };
+
+        let mut children = vec![values, indices, values_idx_offsets];
+        if let Some(varray) = validity.into_array() {
+            children.push(varray)
+        }
+
+        Self::try_from_parts(dtype, length, metadata, children.into(), StatsSet::new())
+    }
+
+    /// The values of the runs of every block.
+    #[inline]
+    pub fn values(&self) -> Array {
+        self.as_ref()
+            .child(0, self.dtype(), self.metadata().values_len as usize)
+            .vortex_expect("RLEArray is missing values child array")
+    }
+
+    /// The index of each element into the run values of its block.
+    #[inline]
+    pub fn indices(&self) -> Array {
+        self.as_ref()
+            .child(
+                1,
+                &DType::Primitive(PType::U16, Nullability::NonNullable),
+                self.metadata().indices_len as usize,
+            )
+            .vortex_expect("RLEArray is missing indices child array")
+    }
+
+    /// The position in [`Self::values`] of the first run value of each block.
+    #[inline]
+    pub fn values_idx_offsets(&self) -> Array {
+        self.as_ref()
+            .child(
+                2,
+                &DType::Primitive(PType::U64, Nullability::NonNullable),
+                (self.metadata().indices_len as usize).div_ceil(1024),
+            )
+            .vortex_expect("RLEArray is missing values offsets child array")
+    }
+
+    /// The logical offset into the first block of [`Self::indices`].
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.metadata().offset as usize
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata().validity.to_validity(|| {
+            self.as_ref()
+                .child(3, &Validity::DTYPE, self.len())
+                .vortex_expect("RLEArray: validity child")
+        })
+    }
+}
+
+impl ArrayTrait for RLEArray {}
+
+impl ArrayVariants for RLEArray {
+    fn as_primitive_array(&self) -> Option<&dyn PrimitiveArrayTrait> {
+        Some(self)
+    }
+}
+
+impl PrimitiveArrayTrait for RLEArray {}
+
+impl IntoCanonical for RLEArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        rle_decode(self).map(Canonical::Primitive)
+    }
+}
+
+impl ArrayValidity for RLEArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for RLEArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("values", &self.values())?;
+        visitor.visit_child("indices", &self.indices())?;
+        visitor.visit_child("values_idx_offsets", &self.values_idx_offsets())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for RLEArray {}
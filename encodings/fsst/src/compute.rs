@@ -1,12 +1,18 @@
-use fsst::Symbol;
-use vortex::array::{varbin_scalar, ConstantArray};
+use std::cmp::Ordering;
+use std::cmp::Ordering::Greater;
+
+use fsst::{Decompressor, Symbol};
+use vortex::array::{varbin_scalar, ConstantArray, VarBinArray};
 use vortex::compute::unary::{scalar_at_unchecked, ScalarAtFn};
 use vortex::compute::{
-    compare, filter, slice, take, ArrayCompute, FilterFn, MaybeCompareFn, Operator, SliceFn, TakeFn,
+    compare, filter, search_sorted_many, slice, take, ArrayCompute, FilterFn, FilterMask, IndexOrd,
+    Len, MaybeCompareFn, Operator, SearchResult, SearchSorted, SearchSortedFn, SearchSortedSide,
+    SliceFn, TakeFn,
 };
+use vortex::validity::Validity;
 use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
 use vortex_buffer::Buffer;
-use vortex_dtype::DType;
+use vortex_dtype::{match_each_integer_ptype, DType, NativePType};
 use vortex_error::{vortex_err, VortexResult, VortexUnwrap};
 use vortex_scalar::Scalar;
 
@@ -25,6 +31,10 @@ impl ArrayCompute for FSSTArray {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
@@ -144,15 +154,81 @@ impl ScalarAtFn for FSSTArray {
     }
 }
 
+impl SearchSortedFn for FSSTArray {
+    // The codes do not preserve the order of the strings, so each probe of the binary search
+    // decompresses a single element with the symbol table instead of the whole array.
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        let value = value.cast(self.dtype())?;
+        let needle = match self.dtype() {
+            DType::Utf8(_) => value.value().as_buffer_string()?.map(Buffer::from),
+            _ => value.value().as_buffer()?,
+        }
+        .ok_or_else(|| vortex_err!("Search sorted with null value is not supported"))?;
+
+        let codes = VarBinArray::try_from(self.codes())?;
+        let offsets = codes.offsets().into_primitive()?;
+        let bytes = codes.bytes().into_primitive()?;
+        self.with_decompressor(|decompressor| {
+            match_each_integer_ptype!(offsets.ptype(), |$O| {
+                Ok(SearchSortedFSST {
+                    offsets: offsets.maybe_null_slice::<$O>(),
+                    bytes: bytes.maybe_null_slice::<u8>(),
+                    validity: codes.validity(),
+                    decompressor: &decompressor,
+                }
+                .search_sorted(&needle.as_slice(), side))
+            })
+        })
+    }
+
+    // Searching for many values decompresses the whole array once and searches the canonical
+    // array, rather than decompressing a handful of elements per value.
+    fn search_sorted_many(
+        &self,
+        values: &[Scalar],
+        sides: &[SearchSortedSide],
+    ) -> VortexResult<Vec<SearchResult>> {
+        let canonical = self.clone().into_varbinview()?.into_array();
+        search_sorted_many(&canonical, values, sides)
+    }
+}
+
+/// Byte-wise ordering of the decompressed values of an [FSSTArray], with nulls sorted last.
+struct SearchSortedFSST<'a, O> {
+    offsets: &'a [O],
+    bytes: &'a [u8],
+    validity: Validity,
+    decompressor: &'a Decompressor<'a>,
+}
+
+impl<O: NativePType> IndexOrd<&[u8]> for SearchSortedFSST<'_, O> {
+    fn index_cmp(&self, idx: usize, elem: &&[u8]) -> Option<Ordering> {
+        if self.validity.is_null(idx) {
+            return Some(Greater);
+        }
+
+        let start = self.offsets[idx].to_usize()?;
+        let end = self.offsets[idx + 1].to_usize()?;
+        let value = self.decompressor.decompress(&self.bytes[start..end]);
+        Some(value.as_slice().cmp(elem))
+    }
+}
+
+impl<O> Len for SearchSortedFSST<'_, O> {
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+}
+
 impl FilterFn for FSSTArray {
     // Filtering an FSSTArray filters the codes array, leaving the symbols array untouched
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
         Ok(Self::try_new(
             self.dtype().clone(),
             self.symbols(),
             self.symbol_lengths(),
-            filter(self.codes(), predicate)?,
-            filter(self.uncompressed_lengths(), predicate)?,
+            filter(self.codes(), mask)?,
+            filter(self.uncompressed_lengths(), mask)?,
         )?
         .into_array())
     }
@@ -162,7 +238,10 @@ impl FilterFn for FSSTArray {
 mod tests {
     use vortex::array::{ConstantArray, VarBinArray};
     use vortex::compute::unary::scalar_at_unchecked;
-    use vortex::compute::{MaybeCompareFn, Operator};
+    use vortex::compute::{
+        search_sorted, search_sorted_many, slice, MaybeCompareFn, Operator, SearchResult,
+        SearchSortedSide,
+    };
     use vortex::{IntoArray, IntoArrayVariant};
     use vortex_dtype::{DType, Nullability};
     use vortex_scalar::Scalar;
@@ -228,4 +307,54 @@ mod tests {
             assert!(scalar_at_unchecked(&noteq_null, idx).is_null());
         }
     }
+
+    #[test]
+    fn test_search_sorted_fsst() {
+        let array = VarBinArray::from_iter(
+            [
+                Some("apple"),
+                Some("banana split"),
+                Some("banana split"),
+                Some("cherry pie"),
+                None,
+            ],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array();
+        let compressor = fsst_train_compressor(&array).unwrap();
+        let array = fsst_compress(&array, &compressor).unwrap().into_array();
+
+        assert_eq!(
+            search_sorted(&array, "banana split", SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(1)
+        );
+        assert_eq!(
+            search_sorted(&array, "banana split", SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(3)
+        );
+        assert_eq!(
+            search_sorted(&array, "banana", SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(1)
+        );
+        assert_eq!(
+            search_sorted(&array, "date", SearchSortedSide::Right).unwrap(),
+            SearchResult::NotFound(4)
+        );
+
+        let sliced = slice(&array, 2, 5).unwrap();
+        assert_eq!(
+            search_sorted(&sliced, "cherry pie", SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(1)
+        );
+
+        assert_eq!(
+            search_sorted_many(
+                &array,
+                &["apple", "cherry"],
+                &[SearchSortedSide::Right, SearchSortedSide::Left]
+            )
+            .unwrap(),
+            vec![SearchResult::Found(1), SearchResult::NotFound(3)]
+        );
+    }
 }
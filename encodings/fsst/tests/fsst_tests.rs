@@ -3,7 +3,7 @@
 use vortex::array::builder::VarBinBuilder;
 use vortex::array::{BoolArray, PrimitiveArray};
 use vortex::compute::unary::scalar_at;
-use vortex::compute::{filter, slice, take};
+use vortex::compute::{filter, slice, take, FilterMask};
 use vortex::validity::Validity;
 use vortex::{Array, ArrayDef, IntoArray, IntoCanonical};
 use vortex_dtype::{DType, Nullability};
@@ -88,7 +88,7 @@ fn test_fsst_array_ops() {
     let predicate =
         BoolArray::from_vec(vec![false, true, false], Validity::NonNullable).into_array();
 
-    let fsst_filtered = filter(&fsst_array, &predicate).unwrap();
+    let fsst_filtered = filter(&fsst_array, &FilterMask::try_from(predicate).unwrap()).unwrap();
     assert_eq!(fsst_filtered.encoding().id(), FSST::ENCODING.id());
     assert_eq!(fsst_filtered.len(), 1);
     assert_nth_scalar!(
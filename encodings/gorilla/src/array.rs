@@ -0,0 +1,206 @@
+use std::fmt::{Debug, Display};
+
+use serde::{Deserialize, Serialize};
+use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use vortex::array::PrimitiveArray;
+use vortex::encoding::ids;
+use vortex::stats::{
+    compute_canonical_statistics, ArrayStatisticsCompute, Stat, StatsOptions, StatsSet,
+};
+use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
+use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
+use vortex::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoCanonical};
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexExpect as _, VortexResult, VortexUnwrap as _};
+
+use crate::{gorilla_decode, gorilla_encode, BLOCK_SIZE};
+
+impl_encoding!("vortex.gorilla", ids::GORILLA, Gorilla);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GorillaMetadata {
+    validity: ValidityMetadata,
+    bits_len: u64,
+    offset: u16, // must be <1024
+}
+
+impl Display for GorillaMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// An array of floats compressed with the XOR scheme of Facebook's
+/// [Gorilla](https://www.vldb.org/pvldb/vol8/p1816-teller.pdf) time series database.
+///
+/// Each value is stored as the XOR of its bit pattern with that of the value before it, with the
+/// leading and trailing zeros of the XOR trimmed. Readings that drift slowly share most of their
+/// sign, exponent and high mantissa bits, so their XORs are short even when the values are not
+/// decimal-like enough for ALP.
+///
+/// # Details
+///
+/// The values are split into blocks of 1,024, and every block starts byte-aligned in `bits` with
+/// its first value stored verbatim. `block_offsets` holds the byte offset of each block, which
+/// serves as a checkpoint so that a single value is decoded from the start of its block rather
+/// than from the start of the array. Like `DeltaArray`, slicing keeps whole blocks and records an
+/// `offset` into the first one, which must be strictly less than 1,024.
+impl GorillaArray {
+    pub fn encode(array: &PrimitiveArray) -> VortexResult<Self> {
+        let (bits, block_offsets) = gorilla_encode(array)?;
+        Self::try_new(
+            array.dtype().clone(),
+            bits.into_array(),
+            block_offsets.into_array(),
+            array.validity(),
+            0,
+            array.len(),
+        )
+    }
+
+    pub fn try_new(
+        dtype: DType,
+        bits: Array,
+        block_offsets: Array,
+        validity: Validity,
+        offset: usize,
+        length: usize,
+    ) -> VortexResult<Self> {
+        if !matches!(dtype, DType::Primitive(PType::F32 | PType::F64, _)) {
+            vortex_bail!(MismatchedTypes: "f32 or f64", dtype);
+        }
+        if dtype.nullability() != validity.nullability() {
+            vortex_bail!(
+                "GorillaArray of type {} does not match the nullability of its validity",
+                dtype
+            );
+        }
+        if offset >= BLOCK_SIZE {
+            vortex_bail!("offset must be less than {}: {}", BLOCK_SIZE, offset);
+        }
+        if !matches!(bits.dtype(), DType::Primitive(PType::U8, _)) {
+            vortex_bail!(MismatchedTypes: "u8", bits.dtype());
+        }
+        if !matches!(block_offsets.dtype(), DType::Primitive(PType::U64, _)) {
+            vortex_bail!(MismatchedTypes: "u64", block_offsets.dtype());
+        }
+        if block_offsets.len() != (offset + length).div_ceil(BLOCK_SIZE) {
+            vortex_bail!(
+                "Expected one block offset per {} values, got {} for {} values at offset {}",
+                BLOCK_SIZE,
+                block_offsets.len(),
+                length,
+                offset
+            );
+        }
+
+        let metadata = GorillaMetadata {
+            validity: validity.to_metadata(length)?,
+            bits_len: bits.len() as u64,
+            offset: offset as u16,
+        };
+
+        let mut children = vec![bits, block_offsets];
+        if let Some(varray) = validity.into_array() {
+            children.push(varray)
+        }
+
+        Self::try_from_parts(dtype, length, metadata, children.into(), StatsSet::new())
+    }
+
+    /// The bit stream of all blocks.
+    #[inline]
+    pub fn bits(&self) -> Array {
+        self.as_ref()
+            .child(
+                0,
+                &DType::Primitive(PType::U8, Nullability::NonNullable),
+                self.metadata().bits_len as usize,
+            )
+            .vortex_expect("GorillaArray is missing bits child array")
+    }
+
+    /// The byte offset in [`Self::bits`] at which each block starts.
+    #[inline]
+    pub fn block_offsets(&self) -> Array {
+        self.as_ref()
+            .child(
+                1,
+                &DType::Primitive(PType::U64, Nullability::NonNullable),
+                (self.offset() + self.len()).div_ceil(BLOCK_SIZE),
+            )
+            .vortex_expect("GorillaArray is missing block offsets child array")
+    }
+
+    /// The logical offset into the first block.
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.metadata().offset as usize
+    }
+
+    pub fn validity(&self) -> Validity {
+        self.metadata().validity.to_validity(|| {
+            self.as_ref()
+                .child(2, &Validity::DTYPE, self.len())
+                .vortex_expect("GorillaArray: validity child")
+        })
+    }
+
+    pub fn ptype(&self) -> PType {
+        PType::try_from(self.dtype()).vortex_unwrap()
+    }
+
+    /// The width in bits of the values.
+    pub(crate) fn width(&self) -> u32 {
+        self.ptype().bit_width() as u32
+    }
+}
+
+impl ArrayTrait for GorillaArray {}
+
+impl ArrayVariants for GorillaArray {
+    fn as_primitive_array(&self) -> Option<&dyn PrimitiveArrayTrait> {
+        Some(self)
+    }
+}
+
+impl PrimitiveArrayTrait for GorillaArray {}
+
+impl IntoCanonical for GorillaArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        gorilla_decode(self).map(Canonical::Primitive)
+    }
+}
+
+impl ArrayValidity for GorillaArray {
+    fn is_valid(&self, index: usize) -> bool {
+        self.validity().is_valid(index)
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        self.validity().to_logical(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for GorillaArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        visitor.visit_child("bits", &self.bits())?;
+        visitor.visit_child("block_offsets", &self.block_offsets())?;
+        visitor.visit_validity(&self.validity())
+    }
+}
+
+impl ArrayStatisticsCompute for GorillaArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        // The bit stream says nothing about the values without decoding it.
+        compute_canonical_statistics(self.as_ref(), stat, options)
+    }
+}
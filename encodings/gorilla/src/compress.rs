@@ -0,0 +1,344 @@
+use vortex::array::PrimitiveArray;
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::IntoArrayVariant;
+use vortex_dtype::{NativePType, PType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::GorillaArray;
+
+/// The number of values between checkpoints in the bit stream.
+///
+/// Every block starts byte-aligned with its first value stored verbatim, so any value can be
+/// decoded by reading at most one block.
+pub const BLOCK_SIZE: usize = 1024;
+
+/// A float whose bit pattern is XOR compressed.
+pub trait GorillaFloat: NativePType {
+    /// The width of the bit pattern.
+    const WIDTH: u32;
+
+    fn to_bits_u64(self) -> u64;
+
+    fn from_bits_u64(bits: u64) -> Self;
+}
+
+impl GorillaFloat for f32 {
+    const WIDTH: u32 = 32;
+
+    fn to_bits_u64(self) -> u64 {
+        self.to_bits() as u64
+    }
+
+    fn from_bits_u64(bits: u64) -> Self {
+        f32::from_bits(bits as u32)
+    }
+}
+
+impl GorillaFloat for f64 {
+    const WIDTH: u32 = 64;
+
+    fn to_bits_u64(self) -> u64 {
+        self.to_bits()
+    }
+
+    fn from_bits_u64(bits: u64) -> Self {
+        f64::from_bits(bits)
+    }
+}
+
+#[macro_export]
+macro_rules! match_each_gorilla_float_ptype {
+    ($ptype:expr, | $_:tt $enc:ident | $($body:tt)*) => ({
+        macro_rules! __with__ {( $_ $enc:ident ) => ( $($body)* )}
+        use vortex_dtype::PType;
+        use vortex_error::vortex_panic;
+        let ptype = $ptype;
+        match ptype {
+            PType::F32 => __with__! { f32 },
+            PType::F64 => __with__! { f64 },
+            _ => vortex_panic!("Unsupported ptype {}", ptype),
+        }
+    })
+}
+
+/// XOR compress the values of a float array into a bit stream and the byte offset of each block
+/// in it.
+///
+/// Null values repeat the value before them, which costs a single bit each.
+pub fn gorilla_encode(array: &PrimitiveArray) -> VortexResult<(PrimitiveArray, PrimitiveArray)> {
+    if !matches!(array.ptype(), PType::F32 | PType::F64) {
+        vortex_bail!("Gorilla can only encode f32 and f64, got {}", array.ptype());
+    }
+
+    let validity = array.logical_validity().to_null_buffer()?;
+    let is_valid = |i: usize| validity.as_ref().map_or(true, |v| v.is_valid(i));
+    let (bits, block_offsets) = match_each_gorilla_float_ptype!(array.ptype(), |$T| {
+        encode_primitive(array.maybe_null_slice::<$T>(), is_valid)
+    });
+    Ok((
+        PrimitiveArray::from_vec(bits, Validity::NonNullable),
+        PrimitiveArray::from_vec(block_offsets, Validity::NonNullable),
+    ))
+}
+
+fn encode_primitive<T: GorillaFloat>(
+    values: &[T],
+    is_valid: impl Fn(usize) -> bool,
+) -> (Vec<u8>, Vec<u64>) {
+    let mut writer = BitWriter::default();
+    let mut block_offsets = Vec::with_capacity(values.len().div_ceil(BLOCK_SIZE));
+    for (block_idx, block) in values.chunks(BLOCK_SIZE).enumerate() {
+        block_offsets.push(writer.bytes.len() as u64);
+        let mut last = 0;
+        let block = block.iter().enumerate().map(|(i, v)| {
+            if is_valid(block_idx * BLOCK_SIZE + i) {
+                last = v.to_bits_u64();
+            }
+            last
+        });
+        encode_block(block, T::WIDTH, &mut writer);
+        writer.flush();
+    }
+    (writer.bytes, block_offsets)
+}
+
+/// Gorilla encode a block of bit patterns of the given width.
+///
+/// The first value is written verbatim and every other value as its XOR with the value before
+/// it: a `0` bit if they are equal, `10` followed by the meaningful bits if they fit within the
+/// previous window of meaningful bits, and otherwise `11` followed by a new window of 5 bits of
+/// leading zeros and 6 bits of length, and the meaningful bits.
+fn encode_block(mut values: impl Iterator<Item = u64>, width: u32, writer: &mut BitWriter) {
+    let Some(mut prev) = values.next() else {
+        return;
+    };
+    writer.write(prev, width);
+
+    let mut window: Option<(u32, u32)> = None;
+    for value in values {
+        let xor = value ^ prev;
+        prev = value;
+        if xor == 0 {
+            writer.write(0, 1);
+            continue;
+        }
+
+        let leading = (xor.leading_zeros() - (64 - width)).min(31);
+        let trailing = xor.trailing_zeros();
+        match window {
+            Some((window_leading, window_len))
+                if leading >= window_leading && trailing >= width - window_leading - window_len =>
+            {
+                writer.write(0b10, 2);
+                writer.write(xor >> (width - window_leading - window_len), window_len);
+            }
+            _ => {
+                let len = width - leading - trailing;
+                writer.write(0b11, 2);
+                writer.write(leading as u64, 5);
+                writer.write((len - 1) as u64, 6);
+                writer.write(xor >> trailing, len);
+                window = Some((leading, len));
+            }
+        }
+    }
+}
+
+/// Decode the first `count` bit patterns of a block.
+pub(crate) fn decode_block(bytes: &[u8], count: usize, width: u32) -> VortexResult<Vec<u64>> {
+    let mut values = Vec::with_capacity(count);
+    if count == 0 {
+        return Ok(values);
+    }
+
+    let mut reader = BitReader::new(bytes);
+    let mut prev = reader.read(width);
+    values.push(prev);
+
+    let (mut window_leading, mut window_len) = (0, 0);
+    for _ in 1..count {
+        if reader.read(1) == 0 {
+            values.push(prev);
+            continue;
+        }
+        if reader.read(1) == 1 {
+            window_leading = reader.read(5) as u32;
+            window_len = reader.read(6) as u32 + 1;
+        }
+        let Some(shift) = width
+            .checked_sub(window_leading + window_len)
+            .filter(|_| window_len > 0)
+        else {
+            vortex_bail!(
+                "Invalid Gorilla window of {} leading zeros and {} bits for {} bit values",
+                window_leading,
+                window_len,
+                width
+            );
+        };
+        prev ^= reader.read(window_len) << shift;
+        values.push(prev);
+    }
+
+    if reader.overflowed() {
+        vortex_bail!("Gorilla block of {} bytes is truncated", bytes.len());
+    }
+    Ok(values)
+}
+
+pub fn gorilla_decode(array: GorillaArray) -> VortexResult<PrimitiveArray> {
+    let bits = array.bits().into_primitive()?;
+    let bits = bits.maybe_null_slice::<u8>();
+    let block_offsets = array.block_offsets().into_primitive()?;
+    let block_offsets = block_offsets.maybe_null_slice::<u64>();
+
+    let physical_len = array.offset() + array.len();
+    let mut values = Vec::with_capacity(physical_len);
+    for (block, &start) in block_offsets.iter().enumerate() {
+        let stop = block_offsets
+            .get(block + 1)
+            .map_or(bits.len(), |&o| o as usize);
+        let count = (physical_len - block * BLOCK_SIZE).min(BLOCK_SIZE);
+        values.extend(decode_block(
+            &bits[start as usize..stop],
+            count,
+            array.width(),
+        )?);
+    }
+
+    let decoded = match_each_gorilla_float_ptype!(array.ptype(), |$T| {
+        PrimitiveArray::from_vec(
+            values[array.offset()..]
+                .iter()
+                .map(|&v| <$T>::from_bits_u64(v))
+                .collect::<Vec<_>>(),
+            array.validity(),
+        )
+    });
+    Ok(decoded)
+}
+
+/// Writes bits most significant first.
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    pending: u128,
+    pending_bits: u32,
+}
+
+impl BitWriter {
+    /// Write the low `bits` bits of `value`.
+    fn write(&mut self, value: u64, bits: u32) {
+        if bits == 0 {
+            return;
+        }
+        let value = value & (u64::MAX >> (64 - bits));
+        self.pending = (self.pending << bits) | value as u128;
+        self.pending_bits += bits;
+        while self.pending_bits >= 8 {
+            self.pending_bits -= 8;
+            self.bytes.push((self.pending >> self.pending_bits) as u8);
+        }
+        self.pending &= (1 << self.pending_bits) - 1;
+    }
+
+    /// Pad the pending bits with zeros to a whole byte.
+    fn flush(&mut self) {
+        if self.pending_bits > 0 {
+            self.bytes
+                .push((self.pending << (8 - self.pending_bits)) as u8);
+            self.pending = 0;
+            self.pending_bits = 0;
+        }
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Read `bits` bits, which are zero past the end of the bytes.
+    fn read(&mut self, bits: u32) -> u64 {
+        let mut value = 0u64;
+        let mut remaining = bits;
+        while remaining > 0 {
+            let byte = self.bytes.get(self.position / 8).copied().unwrap_or(0);
+            let available = 8 - (self.position % 8) as u32;
+            let take = available.min(remaining);
+            let chunk = (byte >> (available - take)) & (u8::MAX >> (8 - take));
+            value = (((value as u128) << take) | chunk as u128) as u64;
+            remaining -= take;
+            self.position += take as usize;
+        }
+        value
+    }
+
+    fn overflowed(&self) -> bool {
+        self.position > self.bytes.len() * 8
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+    use vortex::stats::ArrayStatistics;
+
+    use crate::{gorilla_decode, gorilla_encode, GorillaArray};
+
+    #[test]
+    fn roundtrip_f64() {
+        let values = (0..3000)
+            .map(|i| 20.0 + (i as f64 / 100.0).sin())
+            .chain([
+                f64::NAN,
+                f64::INFINITY,
+                -0.0,
+                0.0,
+                f64::MIN_POSITIVE,
+                f64::MAX,
+            ])
+            .collect::<Vec<_>>();
+        let array = GorillaArray::encode(&PrimitiveArray::from(values.clone())).unwrap();
+        let decoded = gorilla_decode(array).unwrap();
+        assert_eq!(
+            decoded
+                .maybe_null_slice::<f64>()
+                .iter()
+                .map(|v| v.to_bits())
+                .collect::<Vec<_>>(),
+            values.iter().map(|v| v.to_bits()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn roundtrip_f32_with_nulls() {
+        let values = (0..2500)
+            .map(|i| (i % 7 != 0).then_some(1.5f32 + (i / 10) as f32 * 0.25))
+            .collect::<Vec<_>>();
+        let array =
+            GorillaArray::encode(&PrimitiveArray::from_nullable_vec(values.clone())).unwrap();
+        let decoded = gorilla_decode(array).unwrap();
+        for (i, value) in values.iter().enumerate() {
+            if let Some(value) = value {
+                assert_eq!(decoded.maybe_null_slice::<f32>()[i], *value);
+            }
+        }
+        assert_eq!(decoded.statistics().compute_null_count(), Some(358));
+    }
+
+    #[test]
+    fn slowly_drifting_values_compress() {
+        let values = (0..1024)
+            .map(|i| 100.0 + (i / 16) as f64)
+            .collect::<Vec<_>>();
+        let (bits, block_offsets) = gorilla_encode(&PrimitiveArray::from(values)).unwrap();
+        assert_eq!(block_offsets.maybe_null_slice::<u64>(), &[0]);
+        assert!(bits.len() < 1024, "{} bytes", bits.len());
+    }
+}
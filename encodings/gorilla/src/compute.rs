@@ -0,0 +1,252 @@
+use std::cmp::min;
+
+use num_traits::ToPrimitive;
+use vortex::array::PrimitiveArray;
+use vortex::compute::unary::{scalar_at, ScalarAtFn};
+use vortex::compute::{slice, ArrayCompute, SliceFn, TakeFn};
+use vortex::validity::ArrayValidity;
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::{vortex_bail, vortex_err, VortexExpect as _, VortexResult, VortexUnwrap as _};
+use vortex_scalar::Scalar;
+
+use crate::compress::decode_block;
+use crate::{match_each_gorilla_float_ptype, GorillaArray, GorillaFloat, BLOCK_SIZE};
+
+impl ArrayCompute for GorillaArray {
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl GorillaArray {
+    /// The byte offset in the bits at which `block` starts, or the number of bytes for the block
+    /// past the end.
+    fn block_bits_offset(&self, block: usize) -> VortexResult<usize> {
+        let block_offsets = self.block_offsets();
+        if block == block_offsets.len() {
+            return Ok(self.bits().len());
+        }
+        Ok(u64::try_from(&scalar_at(&block_offsets, block)?)? as usize)
+    }
+
+    /// Decode the bit patterns of the first `count` physical values of `block`.
+    fn decode_block(&self, block: usize, count: usize) -> VortexResult<Vec<u64>> {
+        let bytes = slice(
+            self.bits(),
+            self.block_bits_offset(block)?,
+            self.block_bits_offset(block + 1)?,
+        )?
+        .into_primitive()?;
+        decode_block(bytes.maybe_null_slice::<u8>(), count, self.width())
+    }
+}
+
+impl ScalarAtFn for GorillaArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if !self.is_valid(index) {
+            return Ok(Scalar::null(self.dtype().clone()));
+        }
+
+        let position = index + self.offset();
+        let bits = self
+            .decode_block(position / BLOCK_SIZE, position % BLOCK_SIZE + 1)?
+            .pop()
+            .vortex_expect("decoded at least one value");
+        let nullability = self.dtype().nullability();
+        Ok(match_each_gorilla_float_ptype!(self.ptype(), |$T| {
+            Scalar::primitive(<$T>::from_bits_u64(bits), nullability)
+        }))
+    }
+
+    fn scalar_at_unchecked(&self, index: usize) -> Scalar {
+        ScalarAtFn::scalar_at(self, index).vortex_unwrap()
+    }
+}
+
+impl SliceFn for GorillaArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        let physical_start = start + self.offset();
+        let physical_stop = stop + self.offset();
+
+        let num_blocks = self.block_offsets().len();
+        let start_block = min(physical_start / BLOCK_SIZE, num_blocks);
+        let stop_block = min(physical_stop.div_ceil(BLOCK_SIZE), num_blocks).max(start_block);
+
+        // Keep the bytes of the kept blocks only, and rebase their offsets onto them.
+        let bits_start = self.block_bits_offset(start_block)?;
+        let bits_stop = self.block_bits_offset(stop_block)?;
+        let block_offsets =
+            slice(self.block_offsets(), start_block, stop_block)?.into_primitive()?;
+        let block_offsets = block_offsets
+            .maybe_null_slice::<u64>()
+            .iter()
+            .map(|o| o - bits_start as u64)
+            .collect::<Vec<_>>();
+
+        GorillaArray::try_new(
+            self.dtype().clone(),
+            slice(self.bits(), bits_start, bits_stop)?,
+            PrimitiveArray::from(block_offsets).into_array(),
+            self.validity().slice(start, stop)?,
+            physical_start - start_block * BLOCK_SIZE,
+            stop - start,
+        )
+        .map(IntoArray::into_array)
+    }
+}
+
+impl TakeFn for GorillaArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let primitive_indices = indices.clone().into_primitive()?;
+        let len = self.len();
+        // The indices are checked first, as taking the validity doesn't check them.
+        let positions = match_each_integer_ptype!(primitive_indices.ptype(), |$I| {
+            primitive_indices
+                .maybe_null_slice::<$I>()
+                .iter()
+                .map(|&idx| {
+                    idx.to_usize()
+                        .filter(|&idx| idx < len)
+                        .ok_or_else(|| vortex_err!(OutOfBounds: idx as usize, 0, len))
+                })
+                .collect::<VortexResult<Vec<_>>>()?
+        });
+        let taken = match_each_gorilla_float_ptype!(self.ptype(), |$T| {
+            PrimitiveArray::from(take_primitive::<$T>(self, positions.into_iter())?)
+        });
+        Ok(PrimitiveArray::new(
+            taken.buffer().clone(),
+            taken.ptype(),
+            self.validity().take(indices)?,
+        )
+        .into_array())
+    }
+}
+
+/// Take values from a [`GorillaArray`], decoding only the blocks that are referenced by `indices`.
+///
+/// The most recently decoded block is kept around so that runs of indices into the same block are
+/// served without re-decoding.
+fn take_primitive<T: GorillaFloat>(
+    array: &GorillaArray,
+    indices: impl ExactSizeIterator<Item = usize>,
+) -> VortexResult<Vec<T>> {
+    let offset = array.offset();
+    let len = array.len();
+
+    let mut output = Vec::with_capacity(indices.len());
+    // The currently decoded block, along with its decoded bit patterns.
+    let mut decoded: Option<(usize, Vec<u64>)> = None;
+
+    for idx in indices {
+        if idx >= len {
+            vortex_bail!(OutOfBounds: idx, 0, len);
+        }
+
+        let position = idx + offset;
+        let block = position / BLOCK_SIZE;
+        if decoded.as_ref().map_or(true, |(b, _)| *b != block) {
+            let count = min(offset + len - block * BLOCK_SIZE, BLOCK_SIZE);
+            decoded = Some((block, array.decode_block(block, count)?));
+        }
+
+        let (_, values) = decoded
+            .as_ref()
+            .vortex_expect("block containing index must have been decoded");
+        output.push(T::from_bits_u64(values[position % BLOCK_SIZE]));
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::accessor::ArrayAccessor;
+    use vortex::array::PrimitiveArray;
+    use vortex::compute::unary::scalar_at;
+    use vortex::compute::{slice, take};
+    use vortex::{ArrayDType, IntoArray, IntoArrayVariant};
+    use vortex_scalar::Scalar;
+
+    use crate::GorillaArray;
+
+    fn gorilla() -> (Vec<Option<f64>>, GorillaArray) {
+        let values = (0..3000)
+            .map(|i| (i % 11 != 0).then_some(15.0 + (i as f64 / 300.0).cos()))
+            .collect::<Vec<_>>();
+        let array =
+            GorillaArray::encode(&PrimitiveArray::from_nullable_vec(values.clone())).unwrap();
+        (values, array)
+    }
+
+    #[test]
+    fn scalar_at_and_slice() {
+        let (values, array) = gorilla();
+        assert_eq!(
+            scalar_at(&array, 0).unwrap(),
+            Scalar::null(array.dtype().clone())
+        );
+        assert_eq!(
+            ArrayAccessor::<f64>::with_iterator(
+                &array.clone().into_array().into_primitive().unwrap(),
+                |iter| iter.map(|v| v.copied()).collect::<Vec<_>>()
+            )
+            .unwrap(),
+            values
+        );
+
+        let sliced = slice(&array, 1000, 2100).unwrap();
+        assert_eq!(
+            ArrayAccessor::<f64>::with_iterator(
+                &sliced.clone().into_primitive().unwrap(),
+                |iter| iter.map(|v| v.copied()).collect::<Vec<_>>()
+            )
+            .unwrap(),
+            values[1000..2100]
+        );
+        let resliced = slice(&sliced, 30, 1060).unwrap();
+        assert_eq!(
+            ArrayAccessor::<f64>::with_iterator(
+                &resliced.clone().into_primitive().unwrap(),
+                |iter| iter.map(|v| v.copied()).collect::<Vec<_>>()
+            )
+            .unwrap(),
+            values[1030..2060]
+        );
+        assert_eq!(
+            resliced.into_primitive().unwrap().maybe_null_slice::<f64>()[5],
+            values[1035].unwrap()
+        );
+        assert_eq!(slice(&array, 2048, 2048).unwrap().len(), 0);
+        assert_eq!(slice(&array, 3000, 3000).unwrap().len(), 0);
+    }
+
+    #[test]
+    fn take_across_blocks() {
+        let (values, array) = gorilla();
+        let sliced = slice(&array, 1000, 3000).unwrap();
+        let indices = [1999u32, 0, 1500, 23, 24, 1024];
+        let taken = take(&sliced, PrimitiveArray::from(indices.to_vec()).as_ref()).unwrap();
+        assert_eq!(
+            ArrayAccessor::<f64>::with_iterator(&taken.into_primitive().unwrap(), |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            indices
+                .iter()
+                .map(|&i| values[1000 + i as usize])
+                .collect::<Vec<_>>()
+        );
+        assert!(take(&array, PrimitiveArray::from(vec![3000u32]).as_ref()).is_err());
+        assert!(take(&array, PrimitiveArray::from(vec![-1i32]).as_ref()).is_err());
+    }
+}
@@ -1,13 +1,27 @@
 use croaring::Bitmap;
+use vortex::array::BoolArray;
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{ArrayCompute, SliceFn};
-use vortex::{Array, IntoArray};
-use vortex_error::VortexResult;
+use vortex::compute::{AndFn, ArrayCompute, FilterFn, FilterMask, OrFn, SliceFn, TakeFn};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::RoaringBoolArray;
 
 impl ArrayCompute for RoaringBoolArray {
+    fn and(&self) -> Option<&dyn AndFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn or(&self) -> Option<&dyn OrFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -15,6 +29,10 @@ impl ArrayCompute for RoaringBoolArray {
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
 }
 
 impl ScalarAtFn for RoaringBoolArray {
@@ -36,11 +54,87 @@ impl SliceFn for RoaringBoolArray {
     }
 }
 
+impl TakeFn for RoaringBoolArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let bitmap = self.bitmap();
+        let indices = indices.clone().into_primitive()?;
+        let values = match_each_integer_ptype!(indices.ptype(), |$I| {
+            indices
+                .maybe_null_slice::<$I>()
+                .iter()
+                .map(|&idx| {
+                    let idx = idx as usize;
+                    if idx >= self.len() {
+                        vortex_bail!(OutOfBounds: idx, 0, self.len());
+                    }
+                    Ok(bitmap.contains(idx as u32))
+                })
+                .collect::<VortexResult<Vec<_>>>()?
+        });
+
+        Ok(BoolArray::from(values).into_array())
+    }
+}
+
+impl AndFn for RoaringBoolArray {
+    fn and(&self, array: &Array) -> VortexResult<Array> {
+        bitmap_op(self, array, |l, r| l.and(r))
+    }
+}
+
+impl OrFn for RoaringBoolArray {
+    fn or(&self, array: &Array) -> VortexResult<Array> {
+        bitmap_op(self, array, |l, r| l.or(r))
+    }
+}
+
+fn bitmap_op(
+    lhs: &RoaringBoolArray,
+    rhs: &Array,
+    op: impl Fn(&Bitmap, &Bitmap) -> Bitmap,
+) -> VortexResult<Array> {
+    if let Ok(rhs) = RoaringBoolArray::try_from(rhs) {
+        return RoaringBoolArray::try_new(op(&lhs.bitmap(), &rhs.bitmap()), lhs.len())
+            .map(IntoArray::into_array);
+    }
+
+    let rhs = rhs.clone().into_bool()?;
+    let mut rhs_bitmap = Bitmap::new();
+    for (start, end) in rhs.boolean_buffer().set_slices() {
+        rhs_bitmap.add_range(start as u32..end as u32);
+    }
+    let result = RoaringBoolArray::try_new(op(&lhs.bitmap(), &rhs_bitmap), lhs.len())?;
+    if !rhs.dtype().is_nullable() {
+        return Ok(result.into_array());
+    }
+
+    // Roaring bitmaps can't hold nulls, so a nullable result takes the other side's validity.
+    BoolArray::try_new(result.into_bool()?.boolean_buffer(), rhs.validity())
+        .map(IntoArray::into_array)
+}
+
+impl FilterFn for RoaringBoolArray {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        let bitmap = self.bitmap();
+
+        // Shift the part of the bitmap within each selected range down to its filtered position.
+        let mut filtered = Bitmap::new();
+        let mut filtered_len = 0;
+        for (start, end) in mask.slices().iter().copied() {
+            let selected = bitmap.and(&Bitmap::from_range(start as u32..end as u32));
+            filtered.or_inplace(&selected.add_offset(filtered_len as i64 - start as i64));
+            filtered_len += end - start;
+        }
+        Self::try_new(filtered, filtered_len).map(IntoArray::into_array)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use vortex::array::BoolArray;
-    use vortex::compute::slice;
+    use vortex::accessor::ArrayAccessor;
+    use vortex::array::{BoolArray, PrimitiveArray};
     use vortex::compute::unary::scalar_at;
+    use vortex::compute::{and, filter, or, slice, take, FilterMask};
     use vortex::{IntoArray, IntoArrayVariant};
     use vortex_scalar::Scalar;
 
@@ -78,4 +172,107 @@ mod tests {
             &[false, true]
         );
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_take() {
+        let bool = BoolArray::from(vec![true, false, true, true, false]);
+        let array = RoaringBoolArray::encode(bool.into_array()).unwrap();
+        let indices = PrimitiveArray::from(vec![4u32, 0, 1, 3, 0]).into_array();
+
+        assert_eq!(
+            take(&array, &indices)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[false, true, false, true, true]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_and_or() {
+        let lhs = RoaringBoolArray::encode(
+            BoolArray::from(vec![true, false, true, true, false]).into_array(),
+        )
+        .unwrap();
+        let rhs = RoaringBoolArray::encode(
+            BoolArray::from(vec![true, true, false, true, false]).into_array(),
+        )
+        .unwrap();
+
+        let conjunction = and(&lhs, &rhs).unwrap();
+        assert!(RoaringBoolArray::try_from(&conjunction).is_ok());
+        assert_eq!(
+            conjunction
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[true, false, false, true, false]
+        );
+
+        let disjunction = or(&lhs, &rhs).unwrap();
+        assert!(RoaringBoolArray::try_from(&disjunction).is_ok());
+        assert_eq!(
+            disjunction
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[true, true, true, true, false]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_and_nullable() {
+        let lhs = RoaringBoolArray::encode(BoolArray::from(vec![true, false, true]).into_array())
+            .unwrap();
+        let rhs = BoolArray::from_iter([Some(true), Some(true), None]).into_array();
+        assert_eq!(
+            and(&lhs, &rhs)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .with_iterator(|iter| iter.map(|v| v.copied()).collect::<Vec<_>>())
+                .unwrap(),
+            vec![Some(true), Some(false), None]
+        );
+        assert_eq!(
+            or(&rhs, &lhs)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .with_iterator(|iter| iter.map(|v| v.copied()).collect::<Vec<_>>())
+                .unwrap(),
+            vec![Some(true), Some(true), None]
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    pub fn test_filter() {
+        let array = RoaringBoolArray::encode(
+            BoolArray::from(vec![true, false, true, true, false, true]).into_array(),
+        )
+        .unwrap();
+        let predicate = BoolArray::from(vec![false, true, true, true, false, true]).into_array();
+        let filtered = filter(&array, &FilterMask::try_from(predicate).unwrap()).unwrap();
+        assert!(RoaringBoolArray::try_from(&filtered).is_ok());
+        assert_eq!(
+            filtered
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            &[false, true, true, true]
+        );
+    }
 }
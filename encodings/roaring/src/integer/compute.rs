@@ -1,8 +1,10 @@
 use croaring::Bitmap;
-use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{ArrayCompute, SliceFn};
-use vortex::{Array, IntoArray};
-use vortex_dtype::PType;
+use vortex::array::PrimitiveArray;
+use vortex::compute::unary::{try_cast, ScalarAtFn};
+use vortex::compute::{ArrayCompute, SliceFn, TakeFn};
+use vortex::validity::Validity;
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::{match_each_integer_ptype, PType};
 use vortex_error::{vortex_err, VortexResult, VortexUnwrap as _};
 use vortex_scalar::Scalar;
 
@@ -16,6 +18,10 @@ impl ArrayCompute for RoaringIntArray {
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
 }
 
 impl ScalarAtFn for RoaringIntArray {
@@ -58,11 +64,35 @@ impl SliceFn for RoaringIntArray {
     }
 }
 
+impl TakeFn for RoaringIntArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let bitmap = self.owned_bitmap();
+        let indices = indices.clone().into_primitive()?;
+        // The i-th value of the array is the i-th smallest member of the bitmap.
+        let values = match_each_integer_ptype!(indices.ptype(), |$I| {
+            indices
+                .maybe_null_slice::<$I>()
+                .iter()
+                .map(|&idx| {
+                    let out_of_bounds = || vortex_err!(OutOfBounds: idx as usize, 0, self.len());
+                    let rank = u32::try_from(idx).map_err(|_| out_of_bounds())?;
+                    bitmap.select(rank).ok_or_else(out_of_bounds)
+                })
+                .collect::<VortexResult<Vec<u32>>>()?
+        });
+
+        try_cast(
+            PrimitiveArray::from_vec(values, Validity::NonNullable),
+            self.dtype(),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use vortex::array::PrimitiveArray;
-    use vortex::compute::slice;
     use vortex::compute::unary::scalar_at;
+    use vortex::compute::{slice, take};
 
     use super::*;
 
@@ -96,4 +126,29 @@ mod tests {
         assert_eq!(scalar_at(&sliced, 0).unwrap(), 18u32.into());
         assert_eq!(scalar_at(&sliced, 1).unwrap(), 19u32.into());
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_take() {
+        let ints = PrimitiveArray::from(vec![2u16, 12, 22, 32, 1000]).into_array();
+        let array = RoaringIntArray::encode(ints).unwrap();
+        let indices = PrimitiveArray::from(vec![4u32, 0, 2, 2]).into_array();
+
+        let taken = take(&array, &indices).unwrap().into_primitive().unwrap();
+        assert_eq!(taken.maybe_null_slice::<u16>(), &[1000, 2, 22, 22]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_take_out_of_bounds() {
+        let array = RoaringIntArray::try_new(Bitmap::from_range(10..20), PType::U32).unwrap();
+        let indices = PrimitiveArray::from(vec![10u32]).into_array();
+        assert!(take(&array, &indices).is_err());
+
+        // Indices past u32::MAX must not wrap around onto the members of the bitmap.
+        let indices = PrimitiveArray::from(vec![(1u64 << 32) + 1]).into_array();
+        assert!(take(&array, &indices).is_err());
+        let indices = PrimitiveArray::from(vec![-1i32]).into_array();
+        assert!(take(&array, &indices).is_err());
+    }
 }
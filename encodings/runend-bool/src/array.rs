@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::fmt::{Debug, Display};
 
 use serde::{Deserialize, Serialize};
@@ -5,16 +6,16 @@ use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::compute::unary::scalar_at;
 use vortex::compute::{search_sorted, SearchSortedSide};
 use vortex::encoding::ids;
-use vortex::stats::{ArrayStatistics, ArrayStatisticsCompute, StatsSet};
+use vortex::stats::{ArrayStatistics, ArrayStatisticsCompute, Stat, StatsSet};
 use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use vortex::variants::{ArrayVariants, BoolArrayTrait};
 use vortex::{
     impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArrayVariant, IntoCanonical,
 };
-use vortex_dtype::{DType, PType};
+use vortex_dtype::{match_each_integer_ptype, DType, PType};
 use vortex_error::{vortex_bail, VortexExpect as _, VortexResult};
 
-use crate::compress::runend_bool_decode;
+use crate::compress::{runend_bool_decode, value_at_index};
 
 impl_encoding!("vortex.runendbool", ids::RUN_END_BOOL, RunEndBool);
 
@@ -84,6 +85,18 @@ impl RunEndBoolArray {
             .map(|s| s.to_ends_index(self.ends().len()))
     }
 
+    /// The run ends relative to the start of the array, clamped to its length.
+    pub(crate) fn logical_ends(&self) -> VortexResult<Vec<usize>> {
+        let ends = self.ends().into_primitive()?;
+        let (offset, len) = (self.offset(), self.len());
+        Ok(match_each_integer_ptype!(ends.ptype(), |$E| {
+            ends.maybe_null_slice::<$E>()
+                .iter()
+                .map(|&end| (end as usize).saturating_sub(offset).min(len))
+                .collect()
+        }))
+    }
+
     #[inline]
     pub(crate) fn offset(&self) -> usize {
         self.metadata().offset
@@ -163,7 +176,46 @@ impl AcceptArrayVisitor for RunEndBoolArray {
     }
 }
 
-impl ArrayStatisticsCompute for RunEndBoolArray {}
+impl ArrayStatisticsCompute for RunEndBoolArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        if self.is_empty() {
+            return Ok(StatsSet::new());
+        }
+        if !self.logical_validity().all_valid() {
+            return self.clone().into_bool()?.compute_statistics(stat);
+        }
+
+        // Every run alternates value, so the stats follow from the run lengths alone.
+        let mut first = None;
+        let mut true_count = 0;
+        let mut run_count = 0;
+        let mut prev_end = 0;
+        for (i, end) in self.logical_ends()?.into_iter().enumerate() {
+            if end > prev_end {
+                let value = value_at_index(i, self.start());
+                first.get_or_insert(value);
+                run_count += 1;
+                if value {
+                    true_count += end - prev_end;
+                }
+            }
+            prev_end = end;
+        }
+
+        Ok(StatsSet::from(HashMap::from([
+            (Stat::TrueCount, (true_count as u64).into()),
+            (Stat::NullCount, 0u64.into()),
+            (Stat::RunCount, (run_count as u64).into()),
+            (Stat::IsConstant, (run_count == 1).into()),
+            (Stat::Min, (true_count == self.len()).into()),
+            (Stat::Max, (true_count > 0).into()),
+            (
+                Stat::IsSorted,
+                (run_count == 1 || (run_count == 2 && first == Some(false))).into(),
+            ),
+        ])))
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -1,6 +1,8 @@
-use vortex::array::BoolArray;
+use arrow_buffer::BooleanBufferBuilder;
+use vortex::array::{BoolArray, PrimitiveArray};
 use vortex::compute::unary::ScalarAtFn;
-use vortex::compute::{slice, ArrayCompute, SliceFn, TakeFn};
+use vortex::compute::{slice, AndFn, ArrayCompute, FilterFn, FilterMask, OrFn, SliceFn, TakeFn};
+use vortex::validity::Validity;
 use vortex::{Array, IntoArray, IntoArrayVariant, ToArray};
 use vortex_dtype::match_each_integer_ptype;
 use vortex_error::{vortex_bail, VortexExpect as _, VortexResult};
@@ -10,6 +12,18 @@ use crate::compress::value_at_index;
 use crate::RunEndBoolArray;
 
 impl ArrayCompute for RunEndBoolArray {
+    fn and(&self) -> Option<&dyn AndFn> {
+        Some(self)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn or(&self) -> Option<&dyn OrFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -77,10 +91,281 @@ impl SliceFn for RunEndBoolArray {
         Ok(Self::with_offset_and_size(
             slice(self.ends(), slice_begin, slice_end + 1)?,
             value_at_index(slice_begin, self.start()),
-            self.validity().slice(slice_begin, slice_end + 1)?,
+            self.validity().slice(start, stop)?,
             stop - start,
             start,
         )?
         .into_array())
     }
 }
+
+impl AndFn for RunEndBoolArray {
+    fn and(&self, array: &Array) -> VortexResult<Array> {
+        boolean_op(self, array, |l, r| l & r)
+    }
+}
+
+impl OrFn for RunEndBoolArray {
+    fn or(&self, array: &Array) -> VortexResult<Array> {
+        boolean_op(self, array, |l, r| l | r)
+    }
+}
+
+fn boolean_op(
+    lhs: &RunEndBoolArray,
+    rhs: &Array,
+    op: impl Fn(bool, bool) -> bool,
+) -> VortexResult<Array> {
+    let ends = lhs.logical_ends()?;
+
+    // Two run-end arrays combine by walking both sets of run boundaries at once.
+    if let Ok(rhs) = RunEndBoolArray::try_from(rhs) {
+        let rhs_ends = rhs.logical_ends()?;
+        let mut runs = RunBuilder::default();
+        let (mut i, mut j, mut pos) = (0, 0, 0);
+        while pos < lhs.len() {
+            while ends[i] <= pos {
+                i += 1;
+            }
+            while rhs_ends[j] <= pos {
+                j += 1;
+            }
+            let end = ends[i].min(rhs_ends[j]);
+            runs.push(
+                end,
+                op(
+                    value_at_index(i, lhs.start()),
+                    value_at_index(j, rhs.start()),
+                ),
+            );
+            pos = end;
+        }
+        return runs.finish(lhs.validity().and(rhs.validity())?);
+    }
+
+    // Otherwise each of our runs either fixes the result, or passes the other side through.
+    let rhs = rhs.clone().into_bool()?;
+    let values = rhs.boolean_buffer();
+    let mut result = BooleanBufferBuilder::new(lhs.len());
+    let mut pos = 0;
+    for (i, &end) in ends.iter().enumerate() {
+        if end <= pos {
+            continue;
+        }
+        let value = value_at_index(i, lhs.start());
+        if op(value, !value) == value {
+            result.append_n(end - pos, value);
+        } else {
+            result.append_buffer(&values.slice(pos, end - pos));
+        }
+        pos = end;
+    }
+    BoolArray::try_new(result.finish(), lhs.validity().and(rhs.validity())?)
+        .map(IntoArray::into_array)
+}
+
+impl FilterFn for RunEndBoolArray {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        let ends = self.logical_ends()?;
+
+        // Selected ranges are in order, so a single pass over the runs covers all of them.
+        let mut runs = RunBuilder::default();
+        let mut filtered_len = 0;
+        let mut i = 0;
+        for (start, stop) in mask.slices().iter().copied() {
+            let mut pos = start;
+            while pos < stop {
+                while ends[i] <= pos {
+                    i += 1;
+                }
+                let end = ends[i].min(stop);
+                filtered_len += end - pos;
+                runs.push(filtered_len, value_at_index(i, self.start()));
+                pos = end;
+            }
+        }
+        runs.finish(self.validity().filter(mask)?)
+    }
+}
+
+/// Accumulates runs of alternating values, merging adjacent runs with the same value.
+#[derive(Default)]
+struct RunBuilder {
+    ends: Vec<u64>,
+    start: bool,
+    last: Option<bool>,
+}
+
+impl RunBuilder {
+    fn push(&mut self, end: usize, value: bool) {
+        match self.last {
+            Some(last) if last == value => {
+                if let Some(last_end) = self.ends.last_mut() {
+                    *last_end = end as u64;
+                }
+            }
+            Some(_) => self.ends.push(end as u64),
+            None => {
+                self.start = value;
+                self.ends.push(end as u64);
+            }
+        }
+        self.last = Some(value);
+    }
+
+    fn finish(mut self, validity: Validity) -> VortexResult<Array> {
+        if self.ends.is_empty() {
+            self.ends.push(0);
+        }
+        RunEndBoolArray::try_new(
+            PrimitiveArray::from(self.ends).into_array(),
+            self.start,
+            validity,
+        )
+        .map(IntoArray::into_array)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::accessor::ArrayAccessor;
+    use vortex::array::BoolArray;
+    use vortex::compute::{and, filter, or, slice, FilterMask};
+    use vortex::stats::ArrayStatistics;
+    use vortex::validity::{ArrayValidity, Validity};
+    use vortex::{Array, IntoArray, IntoArrayVariant};
+
+    use crate::RunEndBoolArray;
+
+    fn runs(ends: Vec<u32>, start: bool) -> Array {
+        RunEndBoolArray::try_new(ends.into_array(), start, Validity::NonNullable)
+            .unwrap()
+            .into_array()
+    }
+
+    #[test]
+    fn and_or_runs() {
+        // [t, t, t, f, f, t, t, t]
+        let lhs = runs(vec![3, 5, 8], true);
+        // [f, t, t, t, t, t, f, f]
+        let rhs = runs(vec![1, 6, 8], false);
+
+        let conjunction = and(&lhs, &rhs).unwrap();
+        assert!(RunEndBoolArray::try_from(&conjunction).is_ok());
+        assert_eq!(
+            conjunction
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            [false, true, true, false, false, true, false, false]
+        );
+
+        let disjunction = or(&lhs, &rhs).unwrap();
+        assert!(RunEndBoolArray::try_from(&disjunction).is_ok());
+        assert_eq!(
+            RunEndBoolArray::try_from(&disjunction)
+                .unwrap()
+                .ends()
+                .len(),
+            1
+        );
+        assert_eq!(
+            disjunction
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            [true; 8]
+        );
+    }
+
+    #[test]
+    fn and_sliced_runs() {
+        let lhs = slice(runs(vec![3, 5, 8], true), 2, 7).unwrap();
+        let rhs = slice(runs(vec![1, 6, 8], false), 1, 6).unwrap();
+        assert_eq!(
+            and(&lhs, &rhs)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            [true, false, false, true, true]
+        );
+    }
+
+    #[test]
+    fn and_bool_array() {
+        let lhs = runs(vec![2, 4], true);
+        let rhs = BoolArray::from_iter([Some(true), None, Some(true), Some(false)]).into_array();
+        let to_options = |array: Array| {
+            array
+                .into_bool()
+                .unwrap()
+                .with_iterator(|iter| iter.map(|v| v.copied()).collect::<Vec<_>>())
+                .unwrap()
+        };
+        let expected = vec![Some(true), None, Some(false), Some(false)];
+        assert_eq!(to_options(and(&lhs, &rhs).unwrap()), expected);
+        assert_eq!(to_options(and(&rhs, &lhs).unwrap()), expected);
+        assert_eq!(
+            to_options(or(&lhs, &rhs).unwrap()),
+            vec![Some(true), None, Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn filter_runs() {
+        // [t, t, t, f, f, t, t, t]
+        let array = runs(vec![3, 5, 8], true);
+        let predicate =
+            BoolArray::from(vec![false, true, true, true, false, false, true, false]).into_array();
+        let filtered = filter(&array, &FilterMask::try_from(predicate).unwrap()).unwrap();
+        assert!(RunEndBoolArray::try_from(&filtered).is_ok());
+        assert_eq!(
+            filtered
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            [true, true, false, true]
+        );
+    }
+
+    #[test]
+    fn slice_nullable() {
+        // [t, null, t, f, null, t, t, null]
+        let array = RunEndBoolArray::try_new(
+            vec![3u32, 5, 8].into_array(),
+            true,
+            Validity::from(vec![true, false, true, true, false, true, true, false]),
+        )
+        .unwrap()
+        .into_array();
+        let sliced = slice(&array, 2, 7).unwrap().into_bool().unwrap();
+        assert_eq!(
+            sliced.boolean_buffer().iter().collect::<Vec<_>>(),
+            [true, false, false, true, true]
+        );
+        assert_eq!(
+            (0..sliced.len())
+                .map(|i| sliced.is_valid(i))
+                .collect::<Vec<_>>(),
+            [true, true, false, true, true]
+        );
+    }
+
+    #[test]
+    fn true_count() {
+        let array = runs(vec![3, 5, 8], true);
+        assert_eq!(array.statistics().compute_true_count(), Some(6));
+        let sliced = slice(&array, 3, 6).unwrap();
+        assert_eq!(sliced.statistics().compute_true_count(), Some(1));
+        assert_eq!(sliced.statistics().compute_is_constant(), Some(false));
+    }
+}
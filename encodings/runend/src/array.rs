@@ -4,9 +4,12 @@ use serde::{Deserialize, Serialize};
 use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::array::PrimitiveArray;
 use vortex::compute::unary::scalar_at;
-use vortex::compute::{search_sorted, search_sorted_u64_many, SearchSortedSide};
+use vortex::compute::{search_sorted, search_sorted_u64_many, slice, SearchSortedSide};
 use vortex::encoding::ids;
-use vortex::stats::{ArrayStatistics, ArrayStatisticsCompute, StatsSet};
+use vortex::stats::{
+    compute_canonical_statistics, ArrayStatistics, ArrayStatisticsCompute, Stat, StatsOptions,
+    StatsSet,
+};
 use vortex::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
 use vortex::{
@@ -205,11 +208,62 @@ impl AcceptArrayVisitor for RunEndArray {
     }
 }
 
-impl ArrayStatisticsCompute for RunEndArray {}
+impl ArrayStatisticsCompute for RunEndArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        if self.is_empty() {
+            return Ok(StatsSet::new());
+        }
+        let derivable = matches!(
+            stat,
+            Stat::Min
+                | Stat::Max
+                | Stat::IsSorted
+                | Stat::IsStrictSorted
+                | Stat::IsConstant
+                | Stat::RunCount
+                | Stat::NullCount
+        );
+        if !derivable || !self.logical_validity().all_valid() {
+            return compute_canonical_statistics(self.as_ref(), stat, options);
+        }
+
+        // A slice may keep a trailing run that ends before it starts, so only the values of runs
+        // overlapping the array are considered.
+        let num_runs = self.find_physical_index(self.len() - 1)? + 1;
+        let values = slice(self.values(), 0, num_runs)?;
+        let value = match stat {
+            Stat::NullCount => Some(0u64.into()),
+            Stat::IsStrictSorted => values
+                .statistics()
+                .compute_is_strict_sorted()
+                .map(|strict| (strict && num_runs == self.len()).into()),
+            _ => values.statistics().compute_with_options(stat, options),
+        };
+        let mut stats = StatsSet::new();
+        if let Some(value) = value {
+            if matches!(stat, Stat::Min | Stat::Max) && !values.statistics().is_exact(stat) {
+                stats.set_bound(stat, value);
+            } else {
+                stats.set(stat, value);
+            }
+        }
+        Ok(stats)
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use vortex::compute::slice;
     use vortex::compute::unary::scalar_at;
+    use vortex::stats::ArrayStatistics;
     use vortex::validity::Validity;
     use vortex::{ArrayDType, IntoArray};
     use vortex_dtype::{DType, Nullability, PType};
@@ -238,4 +292,29 @@ mod tests {
         assert_eq!(scalar_at(arr.as_ref(), 5).unwrap(), 3.into());
         assert_eq!(scalar_at(arr.as_ref(), 9).unwrap(), 3.into());
     }
+
+    #[test]
+    fn stats() {
+        let arr = RunEndArray::try_new(
+            vec![2u32, 5, 10].into_array(),
+            vec![1i32, 2, 9].into_array(),
+            Validity::NonNullable,
+        )
+        .unwrap()
+        .into_array();
+        let stats = arr.statistics();
+        assert_eq!(stats.compute_max::<i32>(), Some(9));
+        assert_eq!(stats.compute_run_count(), Some(3));
+        assert_eq!(stats.compute_is_sorted(), Some(true));
+        assert_eq!(stats.compute_is_strict_sorted(), Some(false));
+
+        // The slice keeps the run of 9s, which starts where the slice ends.
+        let sliced = slice(&arr, 0, 5).unwrap();
+        let stats = sliced.statistics();
+        assert_eq!(stats.compute_min::<i32>(), Some(1));
+        assert_eq!(stats.compute_max::<i32>(), Some(2));
+        assert_eq!(stats.compute_run_count(), Some(2));
+        assert_eq!(stats.compute_null_count(), Some(0));
+        assert_eq!(stats.compute_bit_width_freq().map(|freq| freq[2]), Some(3));
+    }
 }
@@ -1,8 +1,11 @@
-use vortex::array::{ConstantArray, PrimitiveArray, SparseArray};
-use vortex::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{filter, slice, take, ArrayCompute, SliceFn, TakeFn};
-use vortex::validity::Validity;
-use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex::array::{BoolArray, ConstantArray, PrimitiveArray, SparseArray};
+use vortex::compute::unary::{scalar_at, scalar_at_unchecked, unary_math, MathOp, ScalarAtFn};
+use vortex::compute::{
+    between, filter, is_in, search_sorted, slice, take, top_k, ArrayCompute, BetweenFn, FilterMask,
+    Inclusivity, IsInFn, SearchResult, SearchSortedFn, SearchSortedSide, SliceFn, TakeFn, TopKFn,
+};
+use vortex::validity::{ArrayValidity, Validity};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
 use vortex_dtype::match_each_integer_ptype;
 use vortex_error::{VortexExpect as _, VortexResult};
 use vortex_scalar::{Scalar, ScalarValue};
@@ -10,10 +13,22 @@ use vortex_scalar::{Scalar, ScalarValue};
 use crate::RunEndArray;
 
 impl ArrayCompute for RunEndArray {
+    fn between(&self) -> Option<&dyn BetweenFn> {
+        Some(self)
+    }
+
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
@@ -21,6 +36,27 @@ impl ArrayCompute for RunEndArray {
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
+
+    fn top_k(&self) -> Option<&dyn TopKFn> {
+        Some(self)
+    }
+
+    fn unary_math(&self, op: MathOp) -> Option<VortexResult<Array>> {
+        // Evaluate the function once per run, keeping the run ends as they are.
+        Some(
+            unary_math(self.values(), op)
+                .and_then(|values| {
+                    Self::with_offset_and_length(
+                        self.ends(),
+                        values,
+                        self.validity(),
+                        self.offset(),
+                        self.len(),
+                    )
+                })
+                .map(IntoArray::into_array),
+        )
+    }
 }
 
 impl ScalarAtFn for RunEndArray {
@@ -36,6 +72,81 @@ impl ScalarAtFn for RunEndArray {
     }
 }
 
+impl BetweenFn for RunEndArray {
+    fn between(
+        &self,
+        lower: &Scalar,
+        upper: &Scalar,
+        inclusivity: Inclusivity,
+    ) -> VortexResult<Array> {
+        // Test each run value once and expand the answers over the run lengths.
+        self.expand_runs(between(self.values(), lower, upper, inclusivity)?)
+    }
+}
+
+impl IsInFn for RunEndArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        // Test each run value once and expand the answers over the run lengths.
+        self.expand_runs(is_in(self.values(), values)?)
+    }
+}
+
+impl TopKFn for RunEndArray {
+    fn top_k(&self, k: usize, descending: bool) -> VortexResult<Array> {
+        if !self.logical_validity().all_valid() {
+            return top_k(Array::from(self.clone().into_canonical()?), k, descending);
+        }
+
+        if self.is_empty() {
+            return Ok(PrimitiveArray::from(Vec::<u64>::new()).into_array());
+        }
+
+        // Every run up to the one holding the last row holds at least one row, so the first k
+        // rows come from the best k of those runs. A slice may keep empty runs past its end.
+        let num_runs = self.find_physical_index(self.len() - 1)? + 1;
+        let best = top_k(slice(self.values(), 0, num_runs)?, k, descending)?.into_primitive()?;
+        let ends = self.ends().into_primitive()?;
+        let run_ends = match_each_integer_ptype!(ends.ptype(), |$E| {
+            ends.maybe_null_slice::<$E>()
+                .iter()
+                .map(|&end| (end as usize - self.offset()).min(self.len()))
+                .collect::<Vec<_>>()
+        });
+
+        let mut rows = Vec::with_capacity(k);
+        for &run in best.maybe_null_slice::<u64>() {
+            let run = run as usize;
+            let start = if run == 0 { 0 } else { run_ends[run - 1] };
+            rows.extend(
+                (start..run_ends[run])
+                    .take(k - rows.len())
+                    .map(|row| row as u64),
+            );
+            if rows.len() == k {
+                break;
+            }
+        }
+        Ok(PrimitiveArray::from(rows).into_array())
+    }
+}
+
+impl RunEndArray {
+    /// Expand a boolean answer for each run into an answer for each row.
+    fn expand_runs(&self, matches: Array) -> VortexResult<Array> {
+        let matches = matches.into_bool()?.boolean_buffer();
+        let ends = self.ends().into_primitive()?;
+        let decoded = match_each_integer_ptype!(ends.ptype(), |$E| {
+            let mut decoded = Vec::with_capacity(self.len());
+            for (&end, matched) in ends.maybe_null_slice::<$E>().iter().zip(matches.iter()) {
+                let end = (end as usize - self.offset()).min(self.len());
+                decoded.extend(std::iter::repeat(matched).take(end - decoded.len()));
+            }
+            decoded
+        });
+        Ok(BoolArray::from_vec(decoded, self.validity()).into_array())
+    }
+}
+
 impl TakeFn for RunEndArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         let primitive_indices = indices.clone().into_primitive()?;
@@ -69,21 +180,16 @@ impl TakeFn for RunEndArray {
                 ConstantArray::new(Scalar::null(self.dtype().clone()), indices.len()).into_array()
             }
             Validity::Array(original_validity) => {
-                let dense_validity = take(&original_validity, indices)?;
+                let dense_validity = FilterMask::from(
+                    take(&original_validity, indices)?
+                        .into_bool()?
+                        .boolean_buffer(),
+                );
                 let filtered_values = filter(&dense_values, &dense_validity)?;
                 let length = dense_validity.len();
-                let dense_nonnull_indices = PrimitiveArray::from(
-                    dense_validity
-                        .into_bool()?
-                        .boolean_buffer()
-                        .set_indices()
-                        .map(|idx| idx as u64)
-                        .collect::<Vec<_>>(),
-                )
-                .into_array();
 
                 SparseArray::try_new(
-                    dense_nonnull_indices,
+                    dense_validity.to_indices_array(),
                     filtered_values,
                     length,
                     ScalarValue::Null,
@@ -94,6 +200,27 @@ impl TakeFn for RunEndArray {
     }
 }
 
+impl SearchSortedFn for RunEndArray {
+    /// Search the run values, then map the resulting run index back to a logical index through
+    /// the run ends.
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        let run_result = search_sorted(&self.values(), value.clone(), side)?;
+        let run = run_result.to_index();
+        // Every run index maps to the logical start of that run, i.e. the end of the previous run.
+        let index = if run == 0 {
+            0
+        } else {
+            let run_end: usize = scalar_at(self.ends(), run - 1)?.as_ref().try_into()?;
+            (run_end - self.offset()).min(self.len())
+        };
+
+        Ok(match run_result {
+            SearchResult::Found(_) => SearchResult::Found(index),
+            SearchResult::NotFound(_) => SearchResult::NotFound(index),
+        })
+    }
+}
+
 impl SliceFn for RunEndArray {
     fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
         let slice_begin = self.find_physical_index(start)?;
@@ -113,8 +240,11 @@ impl SliceFn for RunEndArray {
 #[cfg(test)]
 mod test {
     use vortex::array::{BoolArray, PrimitiveArray};
-    use vortex::compute::unary::{scalar_at, try_cast};
-    use vortex::compute::{slice, take};
+    use vortex::compute::unary::{abs, scalar_at, try_cast};
+    use vortex::compute::{
+        between, is_in, search_sorted, slice, take, top_k, Inclusivity, SearchResult,
+        SearchSortedSide,
+    };
     use vortex::validity::{ArrayValidity, Validity};
     use vortex::{ArrayDType, IntoArray, IntoArrayVariant, ToArray};
     use vortex_dtype::{DType, Nullability, PType};
@@ -351,4 +481,131 @@ mod test {
         assert_eq!(scalar_at(taken.as_ref(), 1).unwrap(), 2.into());
         assert_eq!(scalar_at(taken.as_ref(), 2).unwrap(), 5.into());
     }
+
+    fn sorted_ree_array() -> RunEndArray {
+        RunEndArray::encode(
+            PrimitiveArray::from(vec![1, 1, 1, 2, 2, 4, 4, 4, 4, 7, 7, 9]).to_array(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn ree_search_sorted() {
+        let array = sorted_ree_array().into_array();
+        assert_eq!(
+            search_sorted(&array, 4, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(5)
+        );
+        assert_eq!(
+            search_sorted(&array, 4, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(9)
+        );
+        assert_eq!(
+            search_sorted(&array, 1, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(0)
+        );
+        assert_eq!(
+            search_sorted(&array, 9, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(12)
+        );
+    }
+
+    #[test]
+    fn ree_search_sorted_not_found() {
+        let array = sorted_ree_array().into_array();
+        assert_eq!(
+            search_sorted(&array, 3, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(5)
+        );
+        assert_eq!(
+            search_sorted(&array, 3, SearchSortedSide::Right).unwrap(),
+            SearchResult::NotFound(5)
+        );
+        assert_eq!(
+            search_sorted(&array, 0, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(0)
+        );
+        assert_eq!(
+            search_sorted(&array, 10, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(12)
+        );
+    }
+
+    #[test]
+    fn ree_search_sorted_sliced() {
+        let array = slice(sorted_ree_array().as_ref(), 4, 10).unwrap();
+        assert_eq!(
+            search_sorted(&array, 2, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(0)
+        );
+        assert_eq!(
+            search_sorted(&array, 4, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(5)
+        );
+        assert_eq!(
+            search_sorted(&array, 7, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(6)
+        );
+        assert_eq!(
+            search_sorted(&array, 8, SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(6)
+        );
+    }
+
+    #[test]
+    fn ree_is_in() {
+        let sliced = slice(ree_array().as_ref(), 2, 9).unwrap();
+        let matches = is_in(&sliced, PrimitiveArray::from(vec![2, 1]).as_ref())
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            matches.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![true, false, false, false, true, true, false]
+        );
+    }
+
+    #[test]
+    fn ree_abs() {
+        let ree = RunEndArray::encode(
+            PrimitiveArray::from(vec![-1i32, -1, 2, 2, 2, -3, -3, 4]).to_array(),
+        )
+        .unwrap();
+        let sliced = slice(ree.as_ref(), 1, 7).unwrap();
+
+        let result = abs(&sliced).unwrap();
+        assert!(RunEndArray::try_from(&result).is_ok());
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, 2, 2, 2, 3, 3]
+        );
+    }
+
+    #[test]
+    fn ree_between() {
+        let sliced = slice(ree_array().as_ref(), 2, 10).unwrap();
+        let result = between(&sliced, &2.into(), &4.into(), Inclusivity::Both)
+            .unwrap()
+            .into_bool()
+            .unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, true, true, true, true, false, false]
+        );
+    }
+
+    #[test]
+    fn ree_top_k() {
+        // The runs hold 1, 4, 2 and 5.
+        let sliced = slice(ree_array().as_ref(), 2, 10).unwrap();
+        let largest = top_k(&sliced, 4, true).unwrap().into_primitive().unwrap();
+        assert_eq!(largest.maybe_null_slice::<u64>(), &[6, 7, 1, 2]);
+        let smallest = top_k(&sliced, 2, false).unwrap().into_primitive().unwrap();
+        assert_eq!(smallest.maybe_null_slice::<u64>(), &[0, 4]);
+
+        // The slice ends where the run of 2s starts, which is kept as an empty run.
+        let sliced = slice(ree_array().as_ref(), 3, 6).unwrap();
+        let smallest = top_k(&sliced, 1, false).unwrap().into_primitive().unwrap();
+        assert_eq!(smallest.maybe_null_slice::<u64>(), &[0]);
+    }
 }
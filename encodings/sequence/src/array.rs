@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+
+use serde::{Deserialize, Serialize};
+use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use vortex::array::PrimitiveArray;
+use vortex::encoding::ids;
+use vortex::stats::{
+    compute_canonical_statistics, sum_ptype, ArrayStatisticsCompute, Stat, StatsOptions, StatsSet,
+};
+use vortex::validity::{ArrayValidity, LogicalValidity, Validity};
+use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
+use vortex::{impl_encoding, ArrayDType, ArrayTrait, Canonical, IntoCanonical};
+use vortex_dtype::{match_each_integer_ptype, DType, Nullability, PType};
+use vortex_error::{vortex_bail, vortex_err, VortexResult, VortexUnwrap as _};
+use vortex_scalar::{PrimitiveScalar, Scalar, ScalarValue};
+
+impl_encoding!("vortex.sequence", ids::SEQUENCE, Sequence);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SequenceMetadata {
+    base: ScalarValue,
+    step: i64,
+}
+
+impl Display for SequenceMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+/// An array of integers in arithmetic progression, i.e. whose value at index `i` is
+/// `base + step * i`.
+///
+/// Row ids and auto-increment keys take no storage at all beyond the base and the step, and every
+/// compute function is answered from those two numbers without materializing any values.
+///
+/// # Details
+///
+/// The values are never null, although the array may have a nullable type. The step may be zero
+/// or negative, but every value of the progression must be representable in the integer type.
+impl SequenceArray {
+    pub fn try_new(base: Scalar, step: i64, length: usize) -> VortexResult<Self> {
+        let DType::Primitive(ptype, nullability) = base.dtype() else {
+            vortex_bail!(MismatchedTypes: "integer", base.dtype());
+        };
+        if !ptype.is_int() {
+            vortex_bail!(MismatchedTypes: "integer", base.dtype());
+        }
+        if base.is_null() {
+            vortex_bail!("Base of a SequenceArray cannot be null");
+        }
+
+        // The progression is monotonic, so it is in range if its last value is.
+        if let Some(last) = length.checked_sub(1) {
+            let last = scalar_to_i128(&base)? + i128::from(step) * last as i128;
+            integer_scalar(*ptype, *nullability, last)?;
+        }
+
+        Self::try_from_parts(
+            base.dtype().clone(),
+            length,
+            SequenceMetadata {
+                base: base.into_value(),
+                step,
+            },
+            [].into(),
+            StatsSet::new(),
+        )
+    }
+
+    /// The first value of the progression.
+    #[inline]
+    pub fn base(&self) -> Scalar {
+        Scalar::new(self.dtype().clone(), self.metadata().base.clone())
+    }
+
+    /// The difference between consecutive values.
+    #[inline]
+    pub fn step(&self) -> i64 {
+        self.metadata().step
+    }
+
+    pub fn ptype(&self) -> PType {
+        PType::try_from(self.dtype()).vortex_unwrap()
+    }
+
+    /// The value at `index`, which may lie past the end of the array.
+    pub(crate) fn value_at(&self, index: usize) -> i128 {
+        scalar_to_i128(&self.base()).vortex_unwrap() + i128::from(self.step()) * index as i128
+    }
+
+    /// A scalar of the type of the array holding `value`.
+    pub(crate) fn scalar(&self, value: i128) -> VortexResult<Scalar> {
+        integer_scalar(self.ptype(), self.dtype().nullability(), value)
+    }
+
+    /// Materialize the given values, which must be in range of the type of the array.
+    pub(crate) fn values_to_primitive(
+        &self,
+        values: impl Iterator<Item = i128>,
+        validity: Validity,
+    ) -> PrimitiveArray {
+        match_each_integer_ptype!(self.ptype(), |$T| {
+            PrimitiveArray::from_vec(values.map(|v| v as $T).collect::<Vec<_>>(), validity)
+        })
+    }
+
+    pub(crate) fn validity(&self) -> Validity {
+        match self.dtype().nullability() {
+            Nullability::NonNullable => Validity::NonNullable,
+            Nullability::Nullable => Validity::AllValid,
+        }
+    }
+}
+
+/// The value of a non-null integer scalar.
+pub(crate) fn scalar_to_i128(scalar: &Scalar) -> VortexResult<i128> {
+    let primitive = PrimitiveScalar::try_from(scalar)?;
+    if !primitive.ptype().is_int() {
+        vortex_bail!(MismatchedTypes: "integer", scalar.dtype());
+    }
+    match_each_integer_ptype!(primitive.ptype(), |$T| {
+        primitive
+            .typed_value::<$T>()
+            .map(i128::from)
+            .ok_or_else(|| vortex_err!("Expected a non-null integer, got {}", scalar))
+    })
+}
+
+fn integer_scalar(ptype: PType, nullability: Nullability, value: i128) -> VortexResult<Scalar> {
+    match_each_integer_ptype!(ptype, |$T| {
+        <$T>::try_from(value)
+            .map(|v| Scalar::primitive(v, nullability))
+            .map_err(|_| vortex_err!("Value {} is out of range for {}", value, ptype))
+    })
+}
+
+impl ArrayTrait for SequenceArray {}
+
+impl ArrayVariants for SequenceArray {
+    fn as_primitive_array(&self) -> Option<&dyn PrimitiveArrayTrait> {
+        Some(self)
+    }
+}
+
+impl PrimitiveArrayTrait for SequenceArray {}
+
+impl IntoCanonical for SequenceArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        let base = scalar_to_i128(&self.base())?;
+        let step = self.step();
+        let len = self.len();
+        Ok(Canonical::Primitive(
+            match_each_integer_ptype!(self.ptype(), |$T| {
+                // Wrapping arithmetic in the narrower type gives the same values as exact arithmetic,
+                // as all of them are in range.
+                let step = step as $T;
+                let mut next = base as $T;
+                let values = (0..len)
+                    .map(|_| {
+                        let value = next;
+                        next = next.wrapping_add(step);
+                        value
+                    })
+                    .collect::<Vec<_>>();
+                PrimitiveArray::from_vec(values, self.validity())
+            }),
+        ))
+    }
+}
+
+impl ArrayValidity for SequenceArray {
+    fn is_valid(&self, _index: usize) -> bool {
+        true
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        LogicalValidity::AllValid(self.len())
+    }
+}
+
+impl AcceptArrayVisitor for SequenceArray {
+    fn accept(&self, _visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        Ok(())
+    }
+}
+
+impl ArrayStatisticsCompute for SequenceArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        let len = self.len();
+        if len == 0
+            || !matches!(
+                stat,
+                Stat::Min
+                    | Stat::Max
+                    | Stat::IsConstant
+                    | Stat::IsSorted
+                    | Stat::IsStrictSorted
+                    | Stat::RunCount
+                    | Stat::NullCount
+                    | Stat::Sum
+            )
+        {
+            return compute_canonical_statistics(self.as_ref(), stat, options);
+        }
+
+        let first = self.value_at(0);
+        let last = self.value_at(len - 1);
+        let constant = first == last;
+        let mut stats = HashMap::from([
+            (Stat::Min, self.scalar(first.min(last))?),
+            (Stat::Max, self.scalar(first.max(last))?),
+            (Stat::IsConstant, constant.into()),
+            (Stat::IsSorted, (first <= last).into()),
+            (Stat::IsStrictSorted, (first < last || len == 1).into()),
+            (
+                Stat::RunCount,
+                (if constant { 1 } else { len as u64 }).into(),
+            ),
+            (Stat::NullCount, 0u64.into()),
+        ]);
+
+        let sum = (first + last) * len as i128 / 2;
+        let sum = match sum_ptype(self.ptype()) {
+            PType::I64 => i64::try_from(sum).ok().map(Scalar::from),
+            _ => u64::try_from(sum).ok().map(Scalar::from),
+        };
+        if let Some(sum) = sum {
+            stats.insert(Stat::Sum, sum);
+        }
+
+        Ok(StatsSet::from(stats))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::stats::ArrayStatistics;
+    use vortex::IntoArrayVariant;
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::SequenceArray;
+
+    #[test]
+    fn canonical() {
+        let array = SequenceArray::try_new(Scalar::from(10u8), 3, 5).unwrap();
+        assert_eq!(
+            array.into_primitive().unwrap().maybe_null_slice::<u8>(),
+            [10, 13, 16, 19, 22]
+        );
+
+        let array = SequenceArray::try_new(Scalar::from(250u8), -50, 6).unwrap();
+        assert_eq!(
+            array.into_primitive().unwrap().maybe_null_slice::<u8>(),
+            [250, 200, 150, 100, 50, 0]
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert!(SequenceArray::try_new(Scalar::from(250u8), -50, 7).is_err());
+        assert!(SequenceArray::try_new(Scalar::from(i64::MAX - 2), 1, 4).is_err());
+        assert!(SequenceArray::try_new(Scalar::from(1.5f64), 1, 4).is_err());
+        assert!(SequenceArray::try_new(
+            Scalar::null(DType::Primitive(PType::I32, Nullability::Nullable)),
+            1,
+            4
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn statistics() {
+        let array =
+            SequenceArray::try_new(Scalar::primitive(100i32, Nullability::Nullable), -7, 10)
+                .unwrap();
+        let stats = array.statistics();
+        assert_eq!(stats.compute_min::<i32>(), Some(37));
+        assert_eq!(stats.compute_max::<i32>(), Some(100));
+        assert_eq!(stats.compute_is_sorted(), Some(false));
+        assert_eq!(stats.compute_is_constant(), Some(false));
+        assert_eq!(stats.compute_null_count(), Some(0));
+        assert_eq!(stats.compute_sum::<i64>(), Some(685));
+    }
+}
@@ -0,0 +1,76 @@
+use vortex::array::PrimitiveArray;
+use vortex::compute::unary::scalar_at;
+use vortex::validity::ArrayValidity;
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::VortexResult;
+
+use crate::SequenceArray;
+
+/// Encode an integer array as a [`SequenceArray`] if its values are exactly in arithmetic
+/// progression, or return `None` otherwise.
+///
+/// Arrays that are empty, have any nulls, or whose step does not fit in an `i64` are not
+/// sequences.
+pub fn sequence_encode(array: &PrimitiveArray) -> VortexResult<Option<SequenceArray>> {
+    if array.is_empty() || !array.ptype().is_int() || !array.logical_validity().all_valid() {
+        return Ok(None);
+    }
+
+    let step = match_each_integer_ptype!(array.ptype(), |$T| {
+        sequence_step(array.maybe_null_slice::<$T>())
+    });
+    step.map(|step| SequenceArray::try_new(scalar_at(array, 0)?, step, array.len()))
+        .transpose()
+}
+
+fn sequence_step<T: Copy + Into<i128>>(values: &[T]) -> Option<i64> {
+    let step = match values {
+        [first, second, ..] => i64::try_from((*second).into() - (*first).into()).ok()?,
+        _ => 0,
+    };
+    values
+        .windows(2)
+        .all(|w| w[1].into() - w[0].into() == i128::from(step))
+        .then_some(step)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+    use vortex::IntoArrayVariant;
+
+    use crate::sequence_encode;
+
+    #[test]
+    fn encode() {
+        let values = (0..1000i64).map(|i| 1_000_000 - 3 * i).collect::<Vec<_>>();
+        let array = sequence_encode(&PrimitiveArray::from(values.clone()))
+            .unwrap()
+            .unwrap();
+        assert_eq!(array.step(), -3);
+        assert_eq!(
+            array.into_primitive().unwrap().maybe_null_slice::<i64>(),
+            values
+        );
+
+        let single = sequence_encode(&PrimitiveArray::from(vec![7u16]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(single.step(), 0);
+    }
+
+    #[test]
+    fn not_a_sequence() {
+        let encode = |array: PrimitiveArray| sequence_encode(&array).unwrap();
+        assert!(encode(PrimitiveArray::from(vec![0u32, 1, 2, 4])).is_none());
+        assert!(encode(PrimitiveArray::from(vec![1.0f32, 2.0, 3.0])).is_none());
+        assert!(encode(PrimitiveArray::from_nullable_vec(vec![
+            Some(1i32),
+            None,
+            Some(3)
+        ]))
+        .is_none());
+        assert!(encode(PrimitiveArray::from(vec![0u64, u64::MAX])).is_none());
+        assert!(encode(PrimitiveArray::from(Vec::<i32>::new())).is_none());
+    }
+}
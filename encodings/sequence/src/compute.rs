@@ -0,0 +1,385 @@
+use num_traits::ToPrimitive;
+use vortex::array::{BoolArray, ConstantArray};
+use vortex::compute::unary::ScalarAtFn;
+use vortex::compute::{
+    ArrayCompute, FilterFn, FilterMask, MaybeCompareFn, Operator, SearchResult, SearchSortedFn,
+    SearchSortedSide, SliceFn, TakeFn,
+};
+use vortex::validity::Validity;
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::{vortex_bail, vortex_err, VortexResult, VortexUnwrap as _};
+use vortex_scalar::Scalar;
+
+use crate::array::scalar_to_i128;
+use crate::SequenceArray;
+
+impl ArrayCompute for SequenceArray {
+    fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        MaybeCompareFn::maybe_compare(self, other, operator)
+    }
+
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for SequenceArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        if index >= self.len() {
+            vortex_bail!(OutOfBounds: index, 0, self.len());
+        }
+        self.scalar(self.value_at(index))
+    }
+
+    fn scalar_at_unchecked(&self, index: usize) -> Scalar {
+        ScalarAtFn::scalar_at(self, index).vortex_unwrap()
+    }
+}
+
+impl SliceFn for SequenceArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        // The value at `start` is past the end of an empty slice, and may be out of range.
+        let base = if start < stop {
+            self.scalar(self.value_at(start))?
+        } else {
+            self.base()
+        };
+        Self::try_new(base, self.step(), stop - start).map(IntoArray::into_array)
+    }
+}
+
+impl TakeFn for SequenceArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        let primitive_indices = indices.clone().into_primitive()?;
+        let len = self.len();
+        let values = match_each_integer_ptype!(primitive_indices.ptype(), |$I| {
+            primitive_indices
+                .maybe_null_slice::<$I>()
+                .iter()
+                .map(|&idx| {
+                    idx.to_usize()
+                        .filter(|&idx| idx < len)
+                        .map(|idx| self.value_at(idx))
+                        .ok_or_else(|| vortex_err!(OutOfBounds: idx as usize, 0, len))
+                })
+                .collect::<VortexResult<Vec<_>>>()?
+        });
+        Ok(self
+            .values_to_primitive(values.into_iter(), self.validity().take(indices)?)
+            .into_array())
+    }
+}
+
+impl FilterFn for SequenceArray {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        let indices = mask.indices();
+        // Every other k-th value of an arithmetic progression is also an arithmetic progression.
+        let stride = match indices {
+            [first, second, ..] => second - first,
+            _ => 0,
+        };
+        if let (Some(&first), Some(step)) = (
+            indices.first(),
+            i64::try_from(stride)
+                .ok()
+                .and_then(|stride| self.step().checked_mul(stride)),
+        ) {
+            if indices.windows(2).all(|w| w[1] - w[0] == stride) {
+                return Self::try_new(self.scalar(self.value_at(first))?, step, indices.len())
+                    .map(IntoArray::into_array);
+            }
+        }
+
+        Ok(self
+            .values_to_primitive(
+                indices.iter().map(|&idx| self.value_at(idx)),
+                self.validity().filter(mask)?,
+            )
+            .into_array())
+    }
+}
+
+impl SearchSortedFn for SequenceArray {
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        let len = self.len() as i128;
+        let step = if len > 1 { i128::from(self.step()) } else { 0 };
+        if step < 0 {
+            vortex_bail!("Cannot search a SequenceArray with negative step {}", step);
+        }
+
+        let offset = scalar_to_i128(value)? - scalar_to_i128(&self.base())?;
+        let (index, found) = if step == 0 {
+            match (offset.signum(), side) {
+                (-1, _) | (0, SearchSortedSide::Left) => (0, offset == 0),
+                _ => (len, offset == 0),
+            }
+        } else {
+            let position = offset.div_euclid(step);
+            let index = match side {
+                SearchSortedSide::Left => -(-offset).div_euclid(step),
+                SearchSortedSide::Right => position + 1,
+            };
+            (
+                index,
+                offset.rem_euclid(step) == 0 && (0..len).contains(&position),
+            )
+        };
+
+        let index = index.clamp(0, len) as usize;
+        Ok(if found && len > 0 {
+            SearchResult::Found(index)
+        } else {
+            SearchResult::NotFound(index)
+        })
+    }
+}
+
+impl MaybeCompareFn for SequenceArray {
+    fn maybe_compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
+        let constant = ConstantArray::try_from(other).ok()?;
+        let value = constant.owned_scalar();
+        if value.is_null() {
+            return None;
+        }
+        Some(compare_sequence_constant(self, &value, operator))
+    }
+}
+
+/// Compare a sequence to a constant by solving for the range of indices whose values satisfy the
+/// operator, rather than by computing the values.
+fn compare_sequence_constant(
+    array: &SequenceArray,
+    value: &Scalar,
+    operator: Operator,
+) -> VortexResult<Array> {
+    let len = array.len() as i128;
+    let mut offset = scalar_to_i128(value)? - scalar_to_i128(&array.base())?;
+    let mut step = i128::from(array.step());
+    let mut operator = operator;
+    // Solve `step * i <op> offset` with a positive step, flipping both sides otherwise.
+    if step < 0 {
+        step = -step;
+        offset = -offset;
+        operator = operator.swap();
+    }
+
+    let (start, stop, invert) = if step == 0 {
+        let holds = match operator {
+            Operator::Eq => offset == 0,
+            Operator::NotEq => offset != 0,
+            Operator::Gt => offset < 0,
+            Operator::Gte => offset <= 0,
+            Operator::Lt => offset > 0,
+            Operator::Lte => offset >= 0,
+        };
+        (0, if holds { len } else { 0 }, false)
+    } else {
+        let floor = offset.div_euclid(step);
+        let ceil = -(-offset).div_euclid(step);
+        let exact = offset.rem_euclid(step) == 0;
+        match operator {
+            Operator::Eq | Operator::NotEq if exact => {
+                (floor, floor + 1, operator == Operator::NotEq)
+            }
+            Operator::Eq | Operator::NotEq => (0, 0, operator == Operator::NotEq),
+            Operator::Lt => (0, ceil, false),
+            Operator::Lte => (0, floor + 1, false),
+            Operator::Gt => (floor + 1, len, false),
+            Operator::Gte => (ceil, len, false),
+        }
+    };
+
+    let (start, stop) = (start.clamp(0, len), stop.clamp(0, len));
+    let validity = if array.dtype().is_nullable() || value.dtype().is_nullable() {
+        Validity::AllValid
+    } else {
+        Validity::NonNullable
+    };
+    Ok(BoolArray::from_vec(
+        (0..len)
+            .map(|i| (start..stop).contains(&i) != invert)
+            .collect(),
+        validity,
+    )
+    .into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex::array::{ConstantArray, PrimitiveArray};
+    use vortex::compute::unary::scalar_at;
+    use vortex::compute::{
+        compare, filter, search_sorted, slice, take, FilterMask, Operator, SearchResult,
+        SearchSortedSide,
+    };
+    use vortex::{Array, ArrayDef, IntoArray, IntoArrayVariant};
+    use vortex_scalar::Scalar;
+
+    use crate::{Sequence, SequenceArray};
+
+    fn sequence(base: i32, step: i64, len: usize) -> Array {
+        SequenceArray::try_new(Scalar::from(base), step, len)
+            .unwrap()
+            .into_array()
+    }
+
+    #[test]
+    fn scalar_at_slice_take() {
+        let array = sequence(-10, 5, 100);
+        assert_eq!(scalar_at(&array, 3).unwrap(), Scalar::from(5));
+        assert!(scalar_at(&array, 100).is_err());
+
+        let sliced = slice(&array, 10, 20).unwrap();
+        assert!(sliced.is_encoding(Sequence::ID));
+        assert_eq!(
+            sliced.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            (10..20).map(|i| -10 + 5 * i).collect::<Vec<_>>()
+        );
+
+        let taken = take(&array, PrimitiveArray::from(vec![99u8, 0, 42]).as_ref()).unwrap();
+        assert_eq!(
+            taken.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[485, -10, 200]
+        );
+        assert!(take(&array, PrimitiveArray::from(vec![100u8]).as_ref()).is_err());
+        assert!(take(&array, PrimitiveArray::from(vec![-1i32]).as_ref()).is_err());
+    }
+
+    #[test]
+    fn slice_empty_at_end() {
+        let descending = SequenceArray::try_new(Scalar::from(250u8), -50, 6)
+            .unwrap()
+            .into_array();
+        assert_eq!(slice(&descending, 6, 6).unwrap().len(), 0);
+
+        let full = SequenceArray::try_new(Scalar::from(0u8), 1, 256)
+            .unwrap()
+            .into_array();
+        assert_eq!(slice(&full, 256, 256).unwrap().len(), 0);
+        assert_eq!(slice(&full, 255, 256).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn filter_strided_and_arbitrary() {
+        let array = sequence(7, -2, 50);
+
+        let strided = filter(&array, &FilterMask::from_indices(50, [3, 6, 9, 12])).unwrap();
+        assert!(strided.is_encoding(Sequence::ID));
+        assert_eq!(
+            strided.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, -5, -11, -17]
+        );
+
+        let arbitrary = filter(&array, &FilterMask::from_indices(50, [0, 1, 49])).unwrap();
+        assert!(!arbitrary.is_encoding(Sequence::ID));
+        assert_eq!(
+            arbitrary
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            &[7, 5, -91]
+        );
+
+        assert_eq!(
+            filter(&array, &FilterMask::from_indices(50, []))
+                .unwrap()
+                .len(),
+            0
+        );
+    }
+
+    #[test]
+    fn search() {
+        let array = sequence(10, 10, 10);
+        let search = |value: i32, side| search_sorted(&array, value, side).unwrap();
+        assert_eq!(search(30, SearchSortedSide::Left), SearchResult::Found(2));
+        assert_eq!(search(30, SearchSortedSide::Right), SearchResult::Found(3));
+        assert_eq!(
+            search(35, SearchSortedSide::Left),
+            SearchResult::NotFound(3)
+        );
+        assert_eq!(
+            search(35, SearchSortedSide::Right),
+            SearchResult::NotFound(3)
+        );
+        assert_eq!(
+            search(-5, SearchSortedSide::Left),
+            SearchResult::NotFound(0)
+        );
+        assert_eq!(
+            search(500, SearchSortedSide::Right),
+            SearchResult::NotFound(10)
+        );
+
+        let constant = sequence(4, 0, 5);
+        assert_eq!(
+            search_sorted(&constant, 4, SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(0)
+        );
+        assert_eq!(
+            search_sorted(&constant, 4, SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(5)
+        );
+        assert!(search_sorted(&sequence(4, -1, 5), 4, SearchSortedSide::Left).is_err());
+    }
+
+    #[test]
+    fn compare_to_constant() {
+        for (base, step) in [(3, 4), (100, -7), (5, 0), (-20, 1)] {
+            let array = sequence(base, step, 40);
+            let values = array.clone().into_primitive().unwrap();
+            for value in [-100, -20, 0, 3, 5, 19, 23, 58, 100, 1000] {
+                for operator in [
+                    Operator::Eq,
+                    Operator::NotEq,
+                    Operator::Gt,
+                    Operator::Gte,
+                    Operator::Lt,
+                    Operator::Lte,
+                ] {
+                    let result = compare(
+                        &array,
+                        ConstantArray::new(value, array.len()).into_array(),
+                        operator,
+                    )
+                    .unwrap()
+                    .into_bool()
+                    .unwrap()
+                    .boolean_buffer()
+                    .iter()
+                    .collect::<Vec<_>>();
+                    let expected = values
+                        .maybe_null_slice::<i32>()
+                        .iter()
+                        .map(|&v| match operator {
+                            Operator::Eq => v == value,
+                            Operator::NotEq => v != value,
+                            Operator::Gt => v > value,
+                            Operator::Gte => v >= value,
+                            Operator::Lt => v < value,
+                            Operator::Lte => v <= value,
+                        })
+                        .collect::<Vec<_>>();
+                    assert_eq!(result, expected, "{base} + {step} * i {operator:?} {value}");
+                }
+            }
+        }
+    }
+}
@@ -4,7 +4,10 @@ use serde::{Deserialize, Serialize};
 use vortex::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use vortex::array::PrimitiveArray;
 use vortex::encoding::ids;
-use vortex::stats::{ArrayStatisticsCompute, StatsSet};
+use vortex::stats::{
+    compute_canonical_statistics, ArrayStatistics, ArrayStatisticsCompute, Stat, StatsOptions,
+    StatsSet,
+};
 use vortex::validity::{ArrayValidity, LogicalValidity};
 use vortex::variants::{ArrayVariants, PrimitiveArrayTrait};
 use vortex::{
@@ -94,10 +97,53 @@ impl AcceptArrayVisitor for ZigZagArray {
     }
 }
 
-impl ArrayStatisticsCompute for ZigZagArray {}
+impl ArrayStatisticsCompute for ZigZagArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        // ZigZag maps values one-to-one but interleaves positive and negative values, so only the
+        // stats that don't depend on order carry over from the encoded array.
+        if matches!(stat, Stat::NullCount | Stat::IsConstant | Stat::RunCount) {
+            return Ok(self
+                .encoded()
+                .statistics()
+                .compute(stat)
+                .map(|value| StatsSet::of(stat, value))
+                .unwrap_or_default());
+        }
+
+        compute_canonical_statistics(self.as_ref(), stat, options)
+    }
+}
 
 impl IntoCanonical for ZigZagArray {
     fn into_canonical(self) -> VortexResult<Canonical> {
         zigzag_decode(self.encoded().into_primitive()?).map(Canonical::Primitive)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use vortex::array::PrimitiveArray;
+    use vortex::stats::ArrayStatistics;
+    use vortex::IntoArray;
+
+    use crate::ZigZagArray;
+
+    #[test]
+    fn stats() {
+        let array = PrimitiveArray::from(vec![-3i16, -3, 2, i16::MIN, 7]).into_array();
+        let zigzag = ZigZagArray::encode(&array).unwrap();
+        let stats = zigzag.statistics();
+        assert_eq!(stats.compute_run_count(), Some(4));
+        assert_eq!(stats.compute_is_constant(), Some(false));
+        assert_eq!(stats.compute_min::<i16>(), Some(i16::MIN));
+        assert_eq!(stats.compute_max::<i16>(), Some(7));
+    }
+}
@@ -1,6 +1,7 @@
-use vortex::compute::unary::{scalar_at_unchecked, ScalarAtFn};
-use vortex::compute::{slice, ArrayCompute, SliceFn};
-use vortex::{Array, ArrayDType, IntoArray};
+use vortex::array::PrimitiveArray;
+use vortex::compute::unary::{scalar_at_unchecked, MathOp, ScalarAtFn};
+use vortex::compute::{slice, take, ArrayCompute, SliceFn, TakeFn};
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
 use vortex_dtype::match_each_unsigned_integer_ptype;
 use vortex_error::{vortex_err, VortexResult, VortexUnwrap as _};
 use vortex_scalar::{PrimitiveScalar, Scalar};
@@ -16,6 +17,35 @@ impl ArrayCompute for ZigZagArray {
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+
+    fn unary_math(&self, op: MathOp) -> Option<VortexResult<Array>> {
+        matches!(op, MathOp::Abs | MathOp::Negate).then(|| zigzag_math(self, op))
+    }
+}
+
+/// Compute `abs` or `negate` directly on the zigzag encoded values.
+///
+/// Non-negative `n` is encoded as `2n` and negative `n` as `-2n - 1`, so negating a value moves
+/// it to the neighbouring code. The minimum signed value is encoded as the maximum unsigned value
+/// and maps to itself, matching wrapping arithmetic.
+fn zigzag_math(array: &ZigZagArray, op: MathOp) -> VortexResult<Array> {
+    let encoded = array.encoded().into_primitive()?;
+    let values = match_each_unsigned_integer_ptype!(encoded.ptype(), |$P| {
+        let values = encoded.maybe_null_slice::<$P>().iter().map(|&u: &$P| {
+            let negative = u & 1 == 1;
+            match op {
+                MathOp::Abs | MathOp::Negate if negative => u.saturating_add(1),
+                MathOp::Negate if u != 0 => u - 1,
+                _ => u,
+            }
+        });
+        PrimitiveArray::from_vec(values.collect::<Vec<_>>(), encoded.validity()).into_array()
+    });
+    ZigZagArray::try_new(values).map(IntoArray::into_array)
 }
 
 impl ScalarAtFn for ZigZagArray {
@@ -71,13 +101,19 @@ impl SliceFn for ZigZagArray {
     }
 }
 
+impl TakeFn for ZigZagArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        Ok(Self::try_new(take(self.encoded(), indices)?)?.into_array())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use vortex::array::PrimitiveArray;
-    use vortex::compute::unary::scalar_at;
-    use vortex::compute::{search_sorted, SearchResult, SearchSortedSide};
+    use vortex::compute::unary::{abs, negate, scalar_at};
+    use vortex::compute::{search_sorted, take, SearchResult, SearchSortedSide};
     use vortex::validity::Validity;
-    use vortex::IntoArray;
+    use vortex::{IntoArray, IntoArrayVariant};
     use vortex_dtype::Nullability;
     use vortex_scalar::Scalar;
 
@@ -104,4 +140,70 @@ mod tests {
             Scalar::primitive(-160, Nullability::Nullable)
         );
     }
+
+    #[test]
+    pub fn take_indices() {
+        let zigzag =
+            ZigZagArray::encode(&PrimitiveArray::from(vec![-189i32, -160, 1, 42, -7]).into_array())
+                .unwrap();
+        let indices = PrimitiveArray::from(vec![4u32, 0, 3, 3]).into_array();
+
+        let taken = take(&zigzag, &indices).unwrap();
+        assert!(ZigZagArray::try_from(&taken).is_ok());
+        assert_eq!(
+            taken.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[-7, -189, 42, 42]
+        );
+    }
+
+    #[test]
+    pub fn take_nullable() {
+        let zigzag = ZigZagArray::encode(
+            &PrimitiveArray::from_nullable_vec(vec![Some(-3i64), None, Some(5)]).into_array(),
+        )
+        .unwrap();
+        let indices = PrimitiveArray::from(vec![1u32, 2]).into_array();
+
+        let taken = take(&zigzag, &indices).unwrap();
+        assert!(scalar_at(&taken, 0).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&taken, 1).unwrap(),
+            Scalar::primitive(5i64, Nullability::Nullable)
+        );
+    }
+
+    #[test]
+    pub fn abs_negate_encoded() {
+        let values = vec![
+            Some(-3i8),
+            None,
+            Some(0),
+            Some(5),
+            Some(i8::MIN),
+            Some(i8::MAX),
+        ];
+        let zigzag =
+            ZigZagArray::encode(&PrimitiveArray::from_nullable_vec(values.clone()).into_array())
+                .unwrap();
+
+        let absolute = abs(&zigzag).unwrap();
+        assert!(ZigZagArray::try_from(&absolute).is_ok());
+        let negated = negate(&zigzag).unwrap();
+        assert!(ZigZagArray::try_from(&negated).is_ok());
+
+        for (i, value) in values.iter().enumerate() {
+            let expected_abs = value.map(i8::wrapping_abs);
+            let expected_neg = value.map(i8::wrapping_neg);
+            let abs_scalar = scalar_at(&absolute, i).unwrap();
+            let neg_scalar = scalar_at(&negated, i).unwrap();
+            assert_eq!(
+                (!abs_scalar.is_null()).then(|| i8::try_from(&abs_scalar).unwrap()),
+                expected_abs
+            );
+            assert_eq!(
+                (!neg_scalar.is_null()).then(|| i8::try_from(&neg_scalar).unwrap()),
+                expected_neg
+            );
+        }
+    }
 }
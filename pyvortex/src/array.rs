@@ -5,7 +5,7 @@ use pyo3::prelude::*;
 use pyo3::types::{IntoPyDict, PyInt, PyList};
 use vortex::array::ChunkedArray;
 use vortex::compute::unary::{fill_forward, scalar_at};
-use vortex::compute::{compare, slice, take, Operator};
+use vortex::compute::{compare, slice, take, FilterMask, Operator};
 use vortex::{Array, ArrayDType, IntoCanonical};
 
 use crate::dtype::PyDType;
@@ -278,7 +278,8 @@ impl PyArray {
     fn filter(&self, filter: &Bound<PyArray>) -> PyResult<PyArray> {
         let filter = filter.borrow();
 
-        vortex::compute::filter(&self.inner, &filter.inner)
+        FilterMask::try_from(filter.inner.clone())
+            .and_then(|mask| vortex::compute::filter(&self.inner, &mask))
             .map_err(PyVortexError::map_err)
             .map(|arr| PyArray { inner: arr })
     }
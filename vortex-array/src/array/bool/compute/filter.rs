@@ -1,41 +1,40 @@
 use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::VortexResult;
 
 use crate::array::BoolArray;
-use crate::compute::FilterFn;
-use crate::variants::BoolArrayTrait;
+use crate::compute::{FilterFn, FilterIter, FilterMask};
 use crate::{Array, IntoArray};
 
 impl FilterFn for BoolArray {
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
-        filter_select_bool(self, predicate).map(|a| a.into_array())
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        filter_select_bool(self, mask).map(|a| a.into_array())
     }
 }
 
-fn filter_select_bool(arr: &BoolArray, predicate: &Array) -> VortexResult<BoolArray> {
-    predicate.with_dyn(|b| {
-        let validity = arr.validity().filter(predicate)?;
-        let predicate = b.as_bool_array().ok_or(vortex_err!(
-            NotImplemented: "as_bool_array",
-            predicate.encoding().id()
-        ))?;
-        let selection_count = predicate.true_count();
-        let out = if selection_count * 2 > arr.len() {
-            filter_select_bool_by_slice(&arr.boolean_buffer(), predicate, selection_count)
-        } else {
-            filter_select_bool_by_index(&arr.boolean_buffer(), predicate, selection_count)
-        };
-        BoolArray::try_new(out, validity)
-    })
+fn filter_select_bool(arr: &BoolArray, mask: &FilterMask) -> VortexResult<BoolArray> {
+    let validity = arr.validity().filter(mask)?;
+    let values = arr.boolean_buffer();
+    let out = match mask.iter() {
+        FilterIter::Slices(slices) => {
+            filter_select_bool_by_slice(&values, slices, mask.true_count())
+        }
+        FilterIter::Indices(indices) => {
+            filter_select_bool_by_index(&values, indices.iter().copied(), mask.true_count())
+        }
+        FilterIter::Bitmap(bitmap) => {
+            filter_select_bool_by_index(&values, bitmap.set_indices(), mask.true_count())
+        }
+    };
+    BoolArray::try_new(out, validity)
 }
 
 fn filter_select_bool_by_slice(
     values: &BooleanBuffer,
-    predicate: &dyn BoolArrayTrait,
+    slices: &[(usize, usize)],
     selection_count: usize,
 ) -> BooleanBuffer {
     let mut out_buf = BooleanBufferBuilder::new(selection_count);
-    predicate.maybe_null_slices_iter().for_each(|(start, end)| {
+    slices.iter().for_each(|&(start, end)| {
         out_buf.append_buffer(&values.slice(start, end - start));
     });
     out_buf.finish()
@@ -43,13 +42,11 @@ fn filter_select_bool_by_slice(
 
 fn filter_select_bool_by_index(
     values: &BooleanBuffer,
-    predicate: &dyn BoolArrayTrait,
+    indices: impl Iterator<Item = usize>,
     selection_count: usize,
 ) -> BooleanBuffer {
     let mut out_buf = BooleanBufferBuilder::new(selection_count);
-    predicate
-        .maybe_null_indices_iter()
-        .for_each(|idx| out_buf.append(values.value(idx)));
+    indices.for_each(|idx| out_buf.append(values.value(idx)));
     out_buf.finish()
 }
 
@@ -61,14 +58,16 @@ mod test {
         filter_select_bool, filter_select_bool_by_index, filter_select_bool_by_slice,
     };
     use crate::array::BoolArray;
+    use crate::compute::FilterMask;
     use crate::ToArray;
 
     #[test]
     fn filter_bool_test() {
         let arr = BoolArray::from(vec![true, true, false]);
-        let filter = BoolArray::from(vec![true, false, true]);
+        let mask =
+            FilterMask::try_from(BoolArray::from(vec![true, false, true]).to_array()).unwrap();
 
-        let filtered = filter_select_bool(&arr, &filter.to_array()).unwrap();
+        let filtered = filter_select_bool(&arr, &mask).unwrap();
         assert_eq!(2, filtered.len());
 
         assert_eq!(
@@ -80,9 +79,8 @@ mod test {
     #[test]
     fn filter_bool_by_slice_test() {
         let arr = BoolArray::from(vec![true, true, false]);
-        let filter = BoolArray::from(vec![true, false, true]);
 
-        let filtered = filter_select_bool_by_slice(&arr.boolean_buffer(), &filter, 2);
+        let filtered = filter_select_bool_by_slice(&arr.boolean_buffer(), &[(0, 1), (2, 3)], 2);
         assert_eq!(2, filtered.len());
 
         assert_eq!(vec![true, false], filtered.iter().collect_vec())
@@ -91,9 +89,8 @@ mod test {
     #[test]
     fn filter_bool_by_index_test() {
         let arr = BoolArray::from(vec![true, true, false]);
-        let filter = BoolArray::from(vec![true, false, true]);
 
-        let filtered = filter_select_bool_by_index(&arr.boolean_buffer(), &filter, 2);
+        let filtered = filter_select_bool_by_index(&arr.boolean_buffer(), [0, 2].into_iter(), 2);
         assert_eq!(2, filtered.len());
 
         assert_eq!(vec![true, false], filtered.iter().collect_vec())
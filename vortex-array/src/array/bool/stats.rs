@@ -7,10 +7,13 @@ use vortex_error::VortexResult;
 use crate::array::BoolArray;
 use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
 use crate::validity::{ArrayValidity, LogicalValidity};
-use crate::{ArrayDType, IntoArrayVariant};
+use crate::{ArrayDType, ArrayTrait, IntoArrayVariant};
 
 impl ArrayStatisticsCompute for BoolArray {
     fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        if stat == Stat::UncompressedSizeInBytes {
+            return Ok(StatsSet::of(stat, self.nbytes().into()));
+        }
         if self.is_empty() {
             return Ok(StatsSet::new());
         }
@@ -1,41 +1,36 @@
 use arrow_buffer::BooleanBufferBuilder;
 use vortex_error::{VortexExpect, VortexResult};
 
-use crate::array::{BoolArray, ChunkedArray, PrimitiveArray};
-use crate::compute::{filter, take, FilterFn, SearchSorted, SearchSortedSide};
+use crate::array::{ChunkedArray, PrimitiveArray};
+use crate::compute::{
+    filter, take, FilterFn, FilterIter, FilterMask, SearchSorted, SearchSortedSide,
+};
 use crate::{Array, ArrayDType, IntoArray, IntoCanonical};
 
-// This is modeled after the constant with the equivalent name in arrow-rs.
-const FILTER_SLICES_SELECTIVITY_THRESHOLD: f64 = 0.8;
-
 impl FilterFn for ChunkedArray {
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
-        predicate.with_dyn(move |a| {
-            // SAFETY: the DType should be checked in the top-level `filter` function.
-            let bool_array = a.as_bool_array_unchecked();
-            let selected = bool_array.true_count();
-
-            if selected == self.len() {
-                // Fast path 1: no filtering
-                Ok(self.clone().into_array())
-            } else if selected == 0 {
-                // Fast path 2: empty array after filter.
-                Ok(ChunkedArray::try_new(vec![], self.dtype().clone())?.into_array())
-            } else {
-                // General path: perform filtering.
-                //
-                // Based on filter selectivity, we take the values between a range of slices, or
-                // we take individual indices.
-                let selectivity = selected as f64 / self.len() as f64;
-                let chunks = if selectivity > FILTER_SLICES_SELECTIVITY_THRESHOLD {
-                    filter_slices(self, bool_array.maybe_null_slices_iter())?
-                } else {
-                    filter_indices(self, bool_array.maybe_null_indices_iter())?
-                };
-
-                Ok(ChunkedArray::try_new(chunks, self.dtype().clone())?.into_array())
-            }
-        })
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        let selected = mask.true_count();
+
+        if selected == self.len() {
+            // Fast path 1: no filtering
+            Ok(self.clone().into_array())
+        } else if selected == 0 {
+            // Fast path 2: empty array after filter.
+            Ok(ChunkedArray::try_new(vec![], self.dtype().clone())?.into_array())
+        } else {
+            // General path: perform filtering.
+            //
+            // Based on filter selectivity, we take the values between a range of slices, or
+            // we take individual indices.
+            let chunks = match mask.iter() {
+                FilterIter::Slices(slices) => filter_slices(self, slices.iter().copied())?,
+                FilterIter::Indices(_) | FilterIter::Bitmap(_) => {
+                    filter_indices(self, mask.indices().iter().copied())?
+                }
+            };
+
+            Ok(ChunkedArray::try_new(chunks, self.dtype().clone())?.into_array())
+        }
     }
 }
 
@@ -50,9 +45,9 @@ enum ChunkFilter {
     Slices(Vec<(usize, usize)>),
 }
 
-/// Given a sequence of slices that indicate ranges of set values, returns a boolean array
+/// Given a sequence of slices that indicate ranges of set values, returns a filter mask
 /// representing the same thing.
-fn slices_to_predicate(slices: &[(usize, usize)], len: usize) -> Array {
+fn slices_to_mask(slices: &[(usize, usize)], len: usize) -> FilterMask {
     let mut buffer = BooleanBufferBuilder::new(len);
 
     let mut pos = 0;
@@ -69,13 +64,13 @@ fn slices_to_predicate(slices: &[(usize, usize)], len: usize) -> Array {
     let n_trailing_false = len - pos;
     buffer.append_n(n_trailing_false, false);
 
-    BoolArray::from(buffer.finish()).into_array()
+    FilterMask::from(buffer.finish())
 }
 
 /// Filter the chunks using slice ranges.
-fn filter_slices<'a>(
-    array: &'a ChunkedArray,
-    set_slices: Box<dyn Iterator<Item = (usize, usize)> + 'a>,
+fn filter_slices(
+    array: &ChunkedArray,
+    set_slices: impl Iterator<Item = (usize, usize)>,
 ) -> VortexResult<Vec<Array>> {
     let mut result = Vec::with_capacity(array.nchunks());
 
@@ -141,7 +136,7 @@ fn filter_slices<'a>(
             ChunkFilter::None => {}
             // Slices => turn the slices into a boolean buffer.
             ChunkFilter::Slices(slices) => {
-                result.push(filter(&chunk, slices_to_predicate(slices, chunk.len()))?);
+                result.push(filter(&chunk, &slices_to_mask(slices, chunk.len()))?);
             }
         }
     }
@@ -150,9 +145,9 @@ fn filter_slices<'a>(
 }
 
 /// Filter the chunks using indices.
-fn filter_indices<'a>(
-    array: &'a ChunkedArray,
-    set_indices: Box<dyn Iterator<Item = usize> + 'a>,
+fn filter_indices(
+    array: &ChunkedArray,
+    set_indices: impl Iterator<Item = usize>,
 ) -> VortexResult<Vec<Array>> {
     let mut result = Vec::new();
     let mut current_chunk_id = 0;
@@ -218,22 +213,17 @@ mod test {
     use vortex_dtype::half::f16;
     use vortex_dtype::{DType, Nullability, PType};
 
-    use crate::array::chunked::compute::filter::slices_to_predicate;
+    use crate::array::chunked::compute::filter::slices_to_mask;
     use crate::array::{BoolArray, ChunkedArray, PrimitiveArray};
-    use crate::compute::filter;
-    use crate::{IntoArray, IntoArrayVariant};
+    use crate::compute::{filter, FilterMask};
+    use crate::IntoArray;
 
     #[test]
-    fn test_slices_to_predicate() {
+    fn test_slices_to_mask() {
         let slices = [(2, 4), (6, 8), (9, 10)];
-        let predicate = slices_to_predicate(&slices, 11);
+        let mask = slices_to_mask(&slices, 11);
 
-        let bools = predicate
-            .into_bool()
-            .unwrap()
-            .boolean_buffer()
-            .iter()
-            .collect_vec();
+        let bools = mask.boolean_buffer().iter().collect_vec();
 
         assert_eq!(
             bools,
@@ -271,7 +261,7 @@ mod test {
             true, false, false, true, true, true, true, true, true, true, true,
         ])
         .into_array();
-        let filtered = filter(&chunked, &mask).unwrap();
+        let filtered = filter(&chunked, &FilterMask::try_from(mask).unwrap()).unwrap();
         assert_eq!(filtered.len(), 9);
     }
 }
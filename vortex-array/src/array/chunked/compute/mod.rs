@@ -4,7 +4,7 @@ use vortex_error::VortexResult;
 use crate::array::chunked::ChunkedArray;
 use crate::compute::unary::{try_cast, CastFn, ScalarAtFn, SubtractScalarFn};
 use crate::compute::{
-    compare, slice, ArrayCompute, CompareFn, FilterFn, Operator, SliceFn, TakeFn,
+    compare, slice, ArrayCompute, CompareFn, FilterFn, Operator, SliceFn, TakeFn, TopKFn,
 };
 use crate::{Array, IntoArray};
 
@@ -12,6 +12,7 @@ mod filter;
 mod scalar_at;
 mod slice;
 mod take;
+mod top_k;
 
 impl ArrayCompute for ChunkedArray {
     fn cast(&self) -> Option<&dyn CastFn> {
@@ -41,6 +42,10 @@ impl ArrayCompute for ChunkedArray {
     fn filter(&self) -> Option<&dyn FilterFn> {
         Some(self)
     }
+
+    fn top_k(&self) -> Option<&dyn TopKFn> {
+        Some(self)
+    }
 }
 
 impl CastFn for ChunkedArray {
@@ -0,0 +1,123 @@
+use std::cmp::Ordering;
+
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::{ChunkedArray, PrimitiveArray};
+use crate::compute::unary::scalar_at;
+use crate::compute::{take, top_k, top_k_canonical, TopKFn};
+use crate::stats::{ArrayStatistics, Stat};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
+
+impl TopKFn for ChunkedArray {
+    fn top_k(&self, k: usize, descending: bool) -> VortexResult<Array> {
+        // Visit the chunks with the best bounds first, so that a full set of candidates is found
+        // early and the remaining chunks can be skipped by their bounds alone. NaNs are left out of
+        // the bounds of float chunks, so those are never skipped.
+        let bound_stat = if descending { Stat::Max } else { Stat::Min };
+        let mut offset = 0;
+        let mut chunks = Vec::with_capacity(self.nchunks());
+        for chunk in self.chunks() {
+            let bound = if self.dtype().is_float() {
+                None
+            } else {
+                chunk
+                    .statistics()
+                    .compute(bound_stat)
+                    .filter(Scalar::is_valid)
+            };
+            let chunk_offset = offset;
+            offset += chunk.len() as u64;
+            chunks.push((chunk_offset, chunk, bound));
+        }
+        chunks.sort_by(|(_, _, lhs), (_, _, rhs)| match (lhs, rhs) {
+            (Some(lhs), Some(rhs)) => {
+                let ordering = lhs.partial_cmp(rhs).unwrap_or(Ordering::Equal);
+                if descending {
+                    ordering.reverse()
+                } else {
+                    ordering
+                }
+            }
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        });
+
+        let mut indices: Vec<u64> = Vec::with_capacity(k);
+        let mut values: Option<Array> = None;
+        let mut threshold: Option<Scalar> = None;
+        for (offset, chunk, bound) in chunks {
+            if let (Some(threshold), Some(bound)) = (&threshold, &bound) {
+                let beaten = if descending {
+                    bound < threshold
+                } else {
+                    bound > threshold
+                };
+                if beaten {
+                    continue;
+                }
+            }
+            if chunk.is_empty() {
+                continue;
+            }
+
+            let local = top_k(&chunk, k, descending)?.into_primitive()?;
+            indices.extend(local.maybe_null_slice::<u64>().iter().map(|i| i + offset));
+            let chunk_values = take(&chunk, local.as_ref())?;
+            let candidates = match values.take() {
+                Some(values) => Array::from(
+                    ChunkedArray::try_new(vec![values, chunk_values], self.dtype().clone())?
+                        .into_canonical()?,
+                ),
+                None => chunk_values,
+            };
+
+            let order = top_k_canonical(&candidates, k, descending)?;
+            indices = order
+                .clone()
+                .into_primitive()?
+                .maybe_null_slice::<u64>()
+                .iter()
+                .map(|&i| indices[i as usize])
+                .collect();
+            let best = take(&candidates, &order)?;
+            if indices.len() == k {
+                threshold = Some(scalar_at(&best, k - 1)?).filter(Scalar::is_valid);
+            }
+            values = Some(best);
+        }
+
+        Ok(PrimitiveArray::from(indices).into_array())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::{ChunkedArray, PrimitiveArray};
+    use crate::compute::top_k;
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn top_k_chunks() {
+        let chunks = vec![
+            PrimitiveArray::from(vec![5i64, 1, 7]).into_array(),
+            PrimitiveArray::from(Vec::<i64>::new()).into_array(),
+            PrimitiveArray::from(vec![100i64, 3]).into_array(),
+            PrimitiveArray::from(vec![-4i64, 2, 90, 6]).into_array(),
+        ];
+        let chunked = ChunkedArray::try_new(
+            chunks,
+            DType::Primitive(PType::I64, Nullability::NonNullable),
+        )
+        .unwrap()
+        .into_array();
+
+        let largest = top_k(&chunked, 3, true).unwrap().into_primitive().unwrap();
+        assert_eq!(largest.maybe_null_slice::<u64>(), &[3, 7, 2]);
+        let smallest = top_k(&chunked, 2, false).unwrap().into_primitive().unwrap();
+        assert_eq!(smallest.maybe_null_slice::<u64>(), &[5, 1]);
+    }
+}
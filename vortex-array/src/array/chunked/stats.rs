@@ -1,16 +1,24 @@
 use vortex_error::VortexResult;
 
 use crate::array::chunked::ChunkedArray;
-use crate::stats::{ArrayStatistics, ArrayStatisticsCompute, Stat, StatsSet};
+use crate::stats::{ArrayStatistics, ArrayStatisticsCompute, Stat, StatsOptions, StatsSet};
 
 impl ArrayStatisticsCompute for ChunkedArray {
     fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
         Ok(self
             .chunks()
             .map(|c| {
                 let s = c.statistics();
                 // HACK(robert): This will compute all stats, but we could just compute one
-                s.compute(stat);
+                s.compute_with_options(stat, options);
                 s.to_set()
             })
             .reduce(|mut acc, x| {
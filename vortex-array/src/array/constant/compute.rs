@@ -1,19 +1,25 @@
 use std::cmp::Ordering;
 
-use vortex_dtype::Nullability;
-use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
+use vortex_dtype::{match_each_integer_ptype, Nullability, PType};
+use vortex_error::{vortex_bail, VortexExpect, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::array::constant::ConstantArray;
-use crate::compute::unary::{scalar_at, ScalarAtFn};
+use crate::compute::unary::{scalar_at, unary_math, MathOp, ScalarAtFn};
 use crate::compute::{
-    scalar_cmp, AndFn, ArrayCompute, FilterFn, MaybeCompareFn, Operator, OrFn, SearchResult,
-    SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
+    is_in_canonical, scalar_cmp, AndFn, ArrayCompute, BitwiseOperator, FilterFn, FilterMask,
+    IsInFn, MaybeCompareFn, Operator, OrFn, SearchResult, SearchSortedFn, SearchSortedSide,
+    SliceFn, TakeFn,
 };
 use crate::stats::{ArrayStatistics, Stat};
-use crate::{Array, ArrayDType, IntoArray};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
 
 impl ArrayCompute for ConstantArray {
+    fn bitwise(&self, other: &Array, operator: BitwiseOperator) -> Option<VortexResult<Array>> {
+        let other = ConstantArray::try_from(other).ok()?;
+        Some(constant_bitwise(self, &other, operator))
+    }
+
     fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
         MaybeCompareFn::maybe_compare(self, other, operator)
     }
@@ -22,6 +28,10 @@ impl ArrayCompute for ConstantArray {
         Some(self)
     }
 
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        Some(self)
+    }
+
     fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
         Some(self)
     }
@@ -34,6 +44,10 @@ impl ArrayCompute for ConstantArray {
         Some(self)
     }
 
+    fn unary_math(&self, op: MathOp) -> Option<VortexResult<Array>> {
+        Some(constant_math(self, op))
+    }
+
     fn take(&self) -> Option<&dyn TakeFn> {
         Some(self)
     }
@@ -57,6 +71,15 @@ impl ScalarAtFn for ConstantArray {
     }
 }
 
+impl IsInFn for ConstantArray {
+    fn is_in(&self, values: &Array) -> VortexResult<Array> {
+        // Test membership of the single constant value once, then broadcast the answer.
+        let single = ConstantArray::new(self.owned_scalar(), 1).into_canonical()?;
+        let contained = scalar_at(is_in_canonical(single, values)?, 0)?;
+        Ok(ConstantArray::new(contained, self.len()).into_array())
+    }
+}
+
 impl TakeFn for ConstantArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
         Ok(Self::new(self.owned_scalar(), indices.len()).into_array())
@@ -70,19 +93,8 @@ impl SliceFn for ConstantArray {
 }
 
 impl FilterFn for ConstantArray {
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
-        Ok(Self::new(
-            self.owned_scalar(),
-            predicate.with_dyn(|p| {
-                p.as_bool_array()
-                    .ok_or(vortex_err!(
-                        NotImplemented: "as_bool_array",
-                        predicate.encoding().id()
-                    ))
-                    .map(|x| x.true_count())
-            })?,
-        )
-        .into_array())
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        Ok(Self::new(self.owned_scalar(), mask.true_count()).into_array())
     }
 }
 
@@ -141,6 +153,37 @@ impl OrFn for ConstantArray {
     }
 }
 
+fn constant_math(array: &ConstantArray, op: MathOp) -> VortexResult<Array> {
+    // Evaluate the function on the single constant value, then broadcast the result.
+    let single = ConstantArray::new(array.owned_scalar(), 1)
+        .into_array()
+        .into_primitive()?;
+    let scalar = scalar_at(unary_math(single, op)?, 0)?;
+    Ok(ConstantArray::new(scalar, array.len()).into_array())
+}
+
+fn constant_bitwise(
+    lhs: &ConstantArray,
+    rhs: &ConstantArray,
+    operator: BitwiseOperator,
+) -> VortexResult<Array> {
+    let len = lhs.len();
+    let nullability = (lhs.dtype().is_nullable() || rhs.dtype().is_nullable()).into();
+    let (lhs, rhs) = (lhs.owned_scalar(), rhs.owned_scalar());
+    let scalar = if lhs.is_null() || rhs.is_null() {
+        Scalar::null(lhs.dtype().with_nullability(nullability))
+    } else {
+        let ptype = PType::try_from(lhs.dtype())?;
+        match_each_integer_ptype!(ptype, |$T| {
+            Scalar::primitive(
+                operator.apply(<$T>::try_from(&lhs)?, <$T>::try_from(&rhs)?),
+                nullability,
+            )
+        })
+    };
+    Ok(ConstantArray::new(scalar, len).into_array())
+}
+
 fn constant_array_bool_impl(
     constant_array: &ConstantArray,
     other: &Array,
@@ -172,11 +215,27 @@ mod test {
     use rstest::rstest;
 
     use crate::array::constant::ConstantArray;
-    use crate::array::BoolArray;
-    use crate::compute::unary::scalar_at;
-    use crate::compute::{and, or, search_sorted, SearchResult, SearchSortedSide};
+    use crate::array::{BoolArray, PrimitiveArray};
+    use crate::compute::unary::{scalar_at, sqrt};
+    use crate::compute::{and, is_in, or, search_sorted, SearchResult, SearchSortedSide};
     use crate::{Array, IntoArray, IntoArrayVariant};
 
+    #[test]
+    fn constant_is_in() {
+        let cst = ConstantArray::new(5u8, 4).into_array();
+        let hit = is_in(&cst, PrimitiveArray::from(vec![1u8, 5]).as_ref()).unwrap();
+        assert_eq!(
+            scalar_at(&hit, 3).unwrap().value().as_bool().unwrap(),
+            Some(true)
+        );
+        let miss = is_in(&cst, PrimitiveArray::from(vec![1u8, 2]).as_ref()).unwrap();
+        assert_eq!(miss.len(), 4);
+        assert_eq!(
+            scalar_at(&miss, 0).unwrap().value().as_bool().unwrap(),
+            Some(false)
+        );
+    }
+
     #[test]
     pub fn search() {
         let cst = ConstantArray::new(42, 5000).into_array();
@@ -237,4 +296,12 @@ mod test {
         assert!(v2.unwrap());
         assert!(!v3.unwrap());
     }
+
+    #[test]
+    fn constant_sqrt() {
+        let cst = ConstantArray::new(16u32, 3).into_array();
+        let result = sqrt(&cst).unwrap();
+        assert!(ConstantArray::try_from(&result).is_ok());
+        assert_eq!(f64::try_from(&scalar_at(&result, 2).unwrap()).unwrap(), 4.0);
+    }
 }
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
+use vortex_dtype::DType;
 use vortex_error::{vortex_panic, VortexResult};
 use vortex_scalar::{Scalar, ScalarValue};
 
@@ -41,13 +42,17 @@ impl ConstantArray {
         let scalar = scalar.into();
         // TODO(aduffy): add stats for bools, ideally there should be a
         //  StatsSet::constant(Scalar) constructor that does this for us, like StatsSet::nulls.
-        let stats = StatsSet::from(HashMap::from([
-            (Stat::Max, scalar.clone()),
-            (Stat::Min, scalar.clone()),
+        let mut stats = StatsSet::from(HashMap::from([
             (Stat::IsConstant, true.into()),
             (Stat::IsSorted, true.into()),
             (Stat::RunCount, 1.into()),
         ]));
+        // Long strings are truncated to bounds that depend on the StatsOptions, so their minimum
+        // and maximum are left to be computed.
+        if scalar.is_null() || !matches!(scalar.dtype(), DType::Utf8(_) | DType::Binary(_)) {
+            stats.set(Stat::Max, scalar.clone());
+            stats.set(Stat::Min, scalar.clone());
+        }
 
         Self::try_from_parts(
             scalar.dtype().clone(),
@@ -1,13 +1,24 @@
 use std::collections::HashMap;
 
+use vortex_dtype::{DType, PType};
 use vortex_error::VortexResult;
-use vortex_scalar::ScalarValue;
+use vortex_scalar::{Scalar, ScalarValue};
 
 use crate::array::constant::ConstantArray;
-use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
+use crate::array::varbin::{set_string_bounds, varbin_needle};
+use crate::stats::{sum_ptype, ArrayStatisticsCompute, Stat, StatsOptions, StatsSet};
+use crate::ArrayDType;
 
 impl ArrayStatisticsCompute for ConstantArray {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        _stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
         let mut stats_map = HashMap::from([(Stat::IsConstant, true.into())]);
 
         if let ScalarValue::Bool(b) = self.scalar_value() {
@@ -16,6 +27,100 @@ impl ArrayStatisticsCompute for ConstantArray {
             stats_map.insert(Stat::TrueCount, true_count.into());
         }
 
-        Ok(StatsSet::from(stats_map))
+        let pvalue = match PType::try_from(self.dtype()) {
+            Ok(ptype) => self.scalar_value().as_pvalue()?.map(|value| (ptype, value)),
+            Err(_) => None,
+        };
+        if let Some((ptype, value)) = pvalue {
+            let value = value.reinterpret_cast(ptype);
+            let len = self.len();
+            let sum = match sum_ptype(ptype) {
+                PType::I64 => i64::try_from(value)?
+                    .checked_mul(len as i64)
+                    .map(Scalar::from),
+                PType::U64 => u64::try_from(value)?
+                    .checked_mul(len as u64)
+                    .map(Scalar::from),
+                _ => Some((f64::try_from(value)? * len as f64).into()),
+            };
+            if let Some(sum) = sum {
+                stats_map.insert(Stat::Sum, sum);
+            }
+            if ptype.is_float() {
+                let nan_count = if f64::try_from(value)?.is_nan() {
+                    len
+                } else {
+                    0
+                };
+                stats_map.insert(Stat::NaNCount, nan_count.into());
+            }
+        }
+
+        let mut stats = StatsSet::from(stats_map);
+        if matches!(self.dtype(), DType::Utf8(_) | DType::Binary(_))
+            && !self.scalar_value().is_null()
+        {
+            let value = varbin_needle(&self.owned_scalar())?;
+            set_string_bounds(&mut stats, &value, &value, self.dtype(), options);
+        }
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_buffer::BufferString;
+    use vortex_dtype::Nullability;
+    use vortex_scalar::Scalar;
+
+    use crate::array::ConstantArray;
+    use crate::stats::{ArrayStatistics, Stat};
+
+    #[test]
+    fn sum_and_nan_count() {
+        let array = ConstantArray::new(3i32, 10);
+        assert_eq!(array.statistics().compute_sum::<i64>(), Some(30));
+        assert_eq!(array.statistics().compute_nan_count(), None);
+
+        let array = ConstantArray::new(f32::NAN, 10);
+        assert_eq!(array.statistics().compute_nan_count(), Some(10));
+        assert_eq!(
+            array.statistics().compute_uncompressed_size_in_bytes(),
+            Some(40)
+        );
+
+        // Nullable arrays also carry a validity bitmap once canonicalized.
+        let array = ConstantArray::new(Scalar::primitive(3i64, Nullability::Nullable), 10);
+        assert_eq!(
+            array.statistics().compute_uncompressed_size_in_bytes(),
+            Some(82)
+        );
+    }
+
+    #[test]
+    fn truncated_string_bounds() {
+        let array = ConstantArray::new("a".repeat(100).as_str(), 10);
+        let stats = array.statistics();
+        assert_eq!(
+            stats.compute_min::<BufferString>().unwrap().as_str(),
+            "a".repeat(64)
+        );
+        assert!(!stats.is_exact(Stat::Min));
+        assert_eq!(
+            stats.compute_max::<BufferString>().unwrap().as_str(),
+            format!("{}b", "a".repeat(63))
+        );
+        assert!(!stats.is_exact(Stat::Max));
+
+        let array = ConstantArray::new("abc", 10);
+        assert_eq!(
+            array
+                .statistics()
+                .compute_min::<BufferString>()
+                .unwrap()
+                .as_str(),
+            "abc"
+        );
+        assert!(array.statistics().is_exact(Stat::Min));
     }
 }
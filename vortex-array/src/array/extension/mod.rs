@@ -3,10 +3,11 @@ use std::fmt::{Debug, Display};
 use serde::{Deserialize, Serialize};
 use vortex_dtype::{DType, ExtDType, ExtID};
 use vortex_error::{VortexExpect as _, VortexResult};
+use vortex_scalar::Scalar;
 
 use crate::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use crate::encoding::ids;
-use crate::stats::ArrayStatisticsCompute;
+use crate::stats::{ArrayStatistics, ArrayStatisticsCompute, Stat, StatsOptions, StatsSet};
 use crate::validity::{ArrayValidity, LogicalValidity};
 use crate::variants::{ArrayVariants, ExtensionArrayTrait};
 use crate::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoCanonical};
@@ -90,5 +91,56 @@ impl AcceptArrayVisitor for ExtensionArray {
 }
 
 impl ArrayStatisticsCompute for ExtensionArray {
-    // TODO(ngates): pass through stats to the underlying and cast the scalars.
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        let storage = self.storage();
+        storage.statistics().compute_with_options(stat, options);
+        let mut stats = storage.statistics().to_set();
+        // Bounds are values of the array and so must carry the extension dtype.
+        for bound_stat in [Stat::Min, Stat::Max] {
+            if let Some(value) = stats.get(bound_stat) {
+                let value = Scalar::extension(self.ext_dtype().clone(), value.clone());
+                if stats.is_exact(bound_stat) {
+                    stats.set(bound_stat, value);
+                } else {
+                    stats.set_bound(bound_stat, value);
+                }
+            }
+        }
+        Ok(stats)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{ExtDType, ExtID};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{ExtensionArray, PrimitiveArray};
+    use crate::stats::{ArrayStatistics, Stat};
+    use crate::{ArrayDType, IntoArray};
+
+    #[test]
+    fn stats_from_storage() {
+        let ext_dtype = ExtDType::new(ExtID::from("test.ext"), None);
+        let array = ExtensionArray::new(
+            ext_dtype.clone(),
+            PrimitiveArray::from(vec![3i64, 1, 2, 2]).into_array(),
+        )
+        .into_array();
+
+        let min = array.statistics().compute(Stat::Min).unwrap();
+        assert_eq!(min.dtype(), array.dtype());
+        assert_eq!(min, Scalar::extension(ext_dtype, Scalar::from(1i64)));
+        assert_eq!(array.statistics().compute_null_count(), Some(0));
+        assert_eq!(array.statistics().compute_is_sorted(), Some(false));
+        assert_eq!(array.statistics().compute_run_count(), Some(3));
+    }
 }
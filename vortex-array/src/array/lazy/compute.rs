@@ -0,0 +1,128 @@
+use vortex_error::{VortexExpect, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::LazyArray;
+use crate::compute::unary::{scalar_at, ScalarAtFn};
+use crate::compute::{filter, slice, take, ArrayCompute, FilterFn, FilterMask, SliceFn, TakeFn};
+use crate::{Array, IntoArray, IntoCanonical};
+
+impl ArrayCompute for LazyArray {
+    fn filter(&self) -> Option<&dyn FilterFn> {
+        Some(self)
+    }
+
+    fn scalar_at(&self) -> Option<&dyn ScalarAtFn> {
+        Some(self)
+    }
+
+    fn slice(&self) -> Option<&dyn SliceFn> {
+        Some(self)
+    }
+
+    fn take(&self) -> Option<&dyn TakeFn> {
+        Some(self)
+    }
+}
+
+impl ScalarAtFn for LazyArray {
+    fn scalar_at(&self, index: usize) -> VortexResult<Scalar> {
+        // Only evaluate the operation for the requested row.
+        let row = self
+            .map_children(|child| slice(child, index, index + 1))?
+            .into_canonical()?;
+        scalar_at(Array::from(row), 0)
+    }
+
+    fn scalar_at_unchecked(&self, index: usize) -> Scalar {
+        <Self as ScalarAtFn>::scalar_at(self, index).vortex_expect("Failed to get scalar")
+    }
+}
+
+impl SliceFn for LazyArray {
+    fn slice(&self, start: usize, stop: usize) -> VortexResult<Array> {
+        self.map_children(|child| slice(child, start, stop))
+            .map(IntoArray::into_array)
+    }
+}
+
+impl TakeFn for LazyArray {
+    fn take(&self, indices: &Array) -> VortexResult<Array> {
+        self.map_children(|child| take(child, indices))
+            .map(IntoArray::into_array)
+    }
+}
+
+impl FilterFn for LazyArray {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        self.map_children(|child| filter(child, mask))
+            .map(IntoArray::into_array)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{LazyArray, LazyOp, PrimitiveArray};
+    use crate::compute::unary::scalar_at;
+    use crate::compute::{filter, slice, take, FilterMask, Operator};
+    use crate::{Array, IntoArray, IntoArrayVariant};
+
+    fn lazy_gt() -> Array {
+        let lhs = PrimitiveArray::from(vec![1i64, 8, 3, 9, 5]).into_array();
+        let rhs = PrimitiveArray::from(vec![4i64, 4, 4, 4, 4]).into_array();
+        LazyArray::try_new(LazyOp::Compare(Operator::Gt), vec![lhs, rhs])
+            .unwrap()
+            .into_array()
+    }
+
+    #[test]
+    fn slice_stays_lazy() {
+        let sliced = slice(lazy_gt(), 1, 4).unwrap();
+        let lazy = LazyArray::try_from(&sliced).unwrap();
+        assert!(lazy.children().all(|child| child.len() == 3));
+        assert_eq!(
+            sliced
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+    }
+
+    #[test]
+    fn take_and_filter() {
+        let taken = take(lazy_gt(), PrimitiveArray::from(vec![4u32, 0, 3])).unwrap();
+        assert!(LazyArray::try_from(&taken).is_ok());
+        assert_eq!(
+            taken
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+
+        let filtered = filter(lazy_gt(), &FilterMask::from_indices(5, [1, 2])).unwrap();
+        assert!(LazyArray::try_from(&filtered).is_ok());
+        assert_eq!(
+            filtered
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            vec![true, false]
+        );
+    }
+
+    #[test]
+    fn scalar_at_row() {
+        let array = lazy_gt();
+        assert_eq!(
+            scalar_at(&array, 3).unwrap().value().as_bool().unwrap(),
+            Some(true)
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use std::fmt::{Debug, Display};
+use std::sync::Arc;
+
+use arrow_buffer::NullBuffer;
+use vortex_dtype::{DType, Nullability, PType};
+use vortex_error::{vortex_bail, VortexExpect as _, VortexResult};
+
+use crate::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
+use crate::array::{BoolArray, PrimitiveArray};
+use crate::compute::unary::{unary_math, MathOp};
+use crate::compute::{and, bitwise, compare, or, BitwiseOperator, Operator};
+use crate::encoding::ids;
+use crate::stats::ArrayStatisticsCompute;
+use crate::validity::{ArrayValidity, LogicalValidity, Validity};
+use crate::{
+    impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArrayVariant, IntoCanonical,
+    TryDeserializeArrayMetadata, TrySerializeArrayMetadata,
+};
+
+mod compute;
+mod variants;
+
+impl_encoding!("vortex.lazy", ids::LAZY, Lazy);
+
+/// An element-wise compute operation recorded by a [LazyArray].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LazyOp {
+    Compare(Operator),
+    Bitwise(BitwiseOperator),
+    UnaryMath(MathOp),
+    And,
+    Or,
+}
+
+impl Display for LazyOp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LazyOp::Compare(op) => write!(f, "compare({op})"),
+            LazyOp::Bitwise(op) => write!(f, "bitwise({op})"),
+            LazyOp::UnaryMath(op) => Display::fmt(op, f),
+            LazyOp::And => write!(f, "and"),
+            LazyOp::Or => write!(f, "or"),
+        }
+    }
+}
+
+impl LazyOp {
+    /// The number of child arrays the operation is applied over.
+    pub fn arity(&self) -> usize {
+        match self {
+            LazyOp::UnaryMath(_) => 1,
+            LazyOp::Compare(_) | LazyOp::Bitwise(_) | LazyOp::And | LazyOp::Or => 2,
+        }
+    }
+
+    /// The type of the result of applying the operation to children of the given types.
+    fn result_dtype(&self, children: &[DType]) -> VortexResult<DType> {
+        let nullability = children.iter().any(|dtype| dtype.is_nullable()).into();
+        if let [lhs, rhs] = children {
+            if !lhs.eq_ignore_nullability(rhs) {
+                vortex_bail!(MismatchedTypes: lhs, rhs);
+            }
+        }
+
+        let input = &children[0];
+        Ok(match self {
+            LazyOp::Compare(_) => DType::Bool(nullability),
+            LazyOp::Bitwise(_) if input.is_int() => input.with_nullability(nullability),
+            LazyOp::And | LazyOp::Or if input.is_boolean() => DType::Bool(nullability),
+            LazyOp::UnaryMath(op) => match input {
+                DType::Primitive(ptype, _) if ptype.is_float() => input.clone(),
+                DType::Primitive(ptype, _) => match op {
+                    MathOp::Sqrt | MathOp::Log => DType::Primitive(PType::F64, nullability),
+                    MathOp::Negate if ptype.is_unsigned_int() => {
+                        vortex_bail!("Cannot negate unsigned integer array of type {}", input)
+                    }
+                    _ => input.clone(),
+                },
+                _ => vortex_bail!(NotImplemented: op.to_string(), input),
+            },
+            _ => vortex_bail!(NotImplemented: self.to_string(), input),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LazyMetadata {
+    op: LazyOp,
+    children_dtypes: Vec<DType>,
+}
+
+// Lazy arrays only live in memory, nothing reads or writes them yet, so their operation has no
+// serialized form.
+impl TrySerializeArrayMetadata for LazyMetadata {
+    fn try_serialize_metadata(&self) -> VortexResult<Arc<[u8]>> {
+        vortex_bail!("Lazy arrays cannot be serialized, canonicalize them first")
+    }
+}
+
+impl<'m> TryDeserializeArrayMetadata<'m> for LazyMetadata {
+    fn try_deserialize_metadata(_metadata: Option<&'m [u8]>) -> VortexResult<Self> {
+        vortex_bail!("Lazy arrays cannot be deserialized")
+    }
+}
+
+impl Display for LazyMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self, f)
+    }
+}
+
+impl LazyArray {
+    /// Record `op` over the given children without evaluating it.
+    ///
+    /// The children must all have the same length, and the operation must be valid for their
+    /// types. The work is deferred until the array is canonicalized.
+    pub fn try_new(op: LazyOp, children: Vec<Array>) -> VortexResult<Self> {
+        if children.len() != op.arity() {
+            vortex_bail!(
+                "{} expects {} children, got {}",
+                op,
+                op.arity(),
+                children.len()
+            );
+        }
+        let len = children[0].len();
+        if children.iter().any(|child| child.len() != len) {
+            vortex_bail!("Lazy operations require children of equal length");
+        }
+
+        let children_dtypes = children
+            .iter()
+            .map(|child| child.dtype().clone())
+            .collect::<Vec<_>>();
+        Self::try_from_parts(
+            op.result_dtype(&children_dtypes)?,
+            len,
+            LazyMetadata {
+                op,
+                children_dtypes,
+            },
+            children.into(),
+            Default::default(),
+        )
+    }
+
+    #[inline]
+    pub fn op(&self) -> LazyOp {
+        self.metadata().op
+    }
+
+    pub fn child(&self, idx: usize) -> Option<Array> {
+        self.as_ref()
+            .child(idx, self.metadata().children_dtypes.get(idx)?, self.len())
+            .ok()
+    }
+
+    pub fn children(&self) -> impl Iterator<Item = Array> + '_ {
+        (0..self.metadata().children_dtypes.len()).map(|idx| {
+            self.child(idx)
+                .vortex_expect("Missing child array in LazyArray")
+        })
+    }
+
+    /// Apply the recorded operation to the children, returning the result in whichever encoding
+    /// the compute kernel produces.
+    pub fn evaluate(&self) -> VortexResult<Array> {
+        let children = self.children().collect::<Vec<_>>();
+        match (self.op(), children.as_slice()) {
+            (LazyOp::Compare(op), [lhs, rhs]) => compare(lhs, rhs, op),
+            (LazyOp::Bitwise(op), [lhs, rhs]) => bitwise(lhs, rhs, op),
+            (LazyOp::And, [lhs, rhs]) => and(lhs, rhs),
+            (LazyOp::Or, [lhs, rhs]) => or(lhs, rhs),
+            (LazyOp::UnaryMath(op), [input]) => unary_math(input, op),
+            (op, _) => vortex_bail!("{} expects {} children", op, op.arity()),
+        }
+    }
+
+    /// Record the same operation over a transformation of each of the children.
+    fn map_children(&self, f: impl Fn(&Array) -> VortexResult<Array>) -> VortexResult<Self> {
+        let children = self
+            .children()
+            .map(|child| f(&child))
+            .collect::<VortexResult<Vec<_>>>()?;
+        Self::try_new(self.op(), children)
+    }
+}
+
+impl ArrayTrait for LazyArray {}
+
+impl IntoCanonical for LazyArray {
+    fn into_canonical(self) -> VortexResult<Canonical> {
+        // Kernels don't agree on the nullability of their results, so reconcile it with ours.
+        let result = self.evaluate()?;
+        let nullability = self.dtype().nullability();
+        let validity = if result.dtype().nullability() == nullability {
+            None
+        } else {
+            let validity = result.with_dyn(|a| a.logical_validity());
+            Some(match nullability {
+                Nullability::Nullable => validity.into_validity().into_nullable(),
+                Nullability::NonNullable if validity.all_valid() => Validity::NonNullable,
+                Nullability::NonNullable => {
+                    vortex_bail!("{} produced nulls for non-nullable inputs", self.op())
+                }
+            })
+        };
+
+        let canonical = match (self.dtype(), validity) {
+            (DType::Bool(_), Some(validity)) => Canonical::Bool(BoolArray::try_new(
+                result.into_bool()?.boolean_buffer(),
+                validity,
+            )?),
+            (DType::Primitive(..), Some(validity)) => {
+                let primitive = result.into_primitive()?;
+                Canonical::Primitive(PrimitiveArray::new(
+                    primitive.buffer().clone(),
+                    primitive.ptype(),
+                    validity,
+                ))
+            }
+            (..) => result.into_canonical()?,
+        };
+        Ok(canonical)
+    }
+}
+
+impl ArrayValidity for LazyArray {
+    // Each operation produces null exactly where any of its inputs is null.
+    fn is_valid(&self, index: usize) -> bool {
+        self.children()
+            .all(|child| child.with_dyn(|a| a.is_valid(index)))
+    }
+
+    fn logical_validity(&self) -> LogicalValidity {
+        let nulls = self
+            .children()
+            .map(|child| child.with_dyn(|a| a.logical_validity()).to_null_buffer())
+            .try_fold(None, |acc: Option<NullBuffer>, nulls| {
+                nulls.map(|nulls| NullBuffer::union(acc.as_ref(), nulls.as_ref()))
+            })
+            .vortex_expect("Failed to compute logical validity of LazyArray children");
+        match nulls {
+            None => LogicalValidity::AllValid(self.len()),
+            Some(nulls) => Validity::from(nulls.into_inner()).to_logical(self.len()),
+        }
+    }
+}
+
+impl AcceptArrayVisitor for LazyArray {
+    fn accept(&self, visitor: &mut dyn ArrayVisitor) -> VortexResult<()> {
+        for (idx, child) in self.children().enumerate() {
+            visitor.visit_child(format!("[{}]", idx).as_str(), &child)?;
+        }
+        Ok(())
+    }
+}
+
+impl ArrayStatisticsCompute for LazyArray {}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::{BoolArray, LazyArray, LazyOp, PrimitiveArray};
+    use crate::compute::unary::{scalar_at, MathOp};
+    use crate::compute::Operator;
+    use crate::validity::ArrayValidity;
+    use crate::{ArrayDType, IntoArray, IntoArrayVariant, TrySerializeArrayMetadata};
+
+    #[test]
+    fn compare_canonical() {
+        let lhs = PrimitiveArray::from(vec![1i32, 5, 7]).into_array();
+        let rhs = PrimitiveArray::from(vec![2i32, 5, 3]).into_array();
+        let lazy = LazyArray::try_new(LazyOp::Compare(Operator::Gte), vec![lhs, rhs]).unwrap();
+        assert_eq!(lazy.dtype(), &DType::Bool(Nullability::NonNullable));
+
+        let result = lazy.into_array().into_bool().unwrap();
+        assert_eq!(result.dtype(), &DType::Bool(Nullability::NonNullable));
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, true]
+        );
+    }
+
+    #[test]
+    fn not_serializable() {
+        let lhs = PrimitiveArray::from(vec![1i32, 5, 7]).into_array();
+        let rhs = PrimitiveArray::from(vec![2i32, 5, 3]).into_array();
+        let lazy = LazyArray::try_new(LazyOp::Compare(Operator::Gte), vec![lhs, rhs]).unwrap();
+        assert!(lazy.metadata().try_serialize_metadata().is_err());
+    }
+
+    #[test]
+    fn unary_math_nulls() {
+        let input = PrimitiveArray::from_nullable_vec(vec![Some(4u32), None, Some(9)]);
+        let lazy =
+            LazyArray::try_new(LazyOp::UnaryMath(MathOp::Sqrt), vec![input.into_array()]).unwrap();
+        assert_eq!(
+            lazy.dtype(),
+            &DType::Primitive(PType::F64, Nullability::Nullable)
+        );
+        assert!(!lazy.is_valid(1));
+        assert!(!lazy.logical_validity().all_valid());
+
+        let result = lazy.into_array();
+        assert_eq!(f64::try_from(&scalar_at(&result, 2).unwrap()).unwrap(), 3.0);
+        assert!(scalar_at(&result, 1).unwrap().is_null());
+    }
+
+    #[test]
+    fn invalid_children() {
+        let ints = PrimitiveArray::from(vec![1u8, 2]).into_array();
+        let bools = BoolArray::from(vec![true, false]).into_array();
+        assert!(LazyArray::try_new(LazyOp::And, vec![ints.clone(), ints.clone()]).is_err());
+        assert!(
+            LazyArray::try_new(LazyOp::Compare(Operator::Eq), vec![ints.clone(), bools]).is_err()
+        );
+        assert!(LazyArray::try_new(LazyOp::UnaryMath(MathOp::Negate), vec![ints.clone()]).is_err());
+        assert!(
+            LazyArray::try_new(LazyOp::UnaryMath(MathOp::Abs), vec![ints.clone(), ints]).is_err()
+        );
+    }
+}
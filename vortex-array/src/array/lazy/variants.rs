@@ -0,0 +1,39 @@
+use vortex_dtype::DType;
+use vortex_error::VortexExpect as _;
+
+use crate::array::LazyArray;
+use crate::variants::{ArrayVariants, BoolArrayTrait, PrimitiveArrayTrait};
+use crate::{ArrayDType, IntoArrayVariant};
+
+/// Lazy operations produce either booleans or primitives.
+impl ArrayVariants for LazyArray {
+    fn as_bool_array(&self) -> Option<&dyn BoolArrayTrait> {
+        matches!(self.dtype(), DType::Bool(_)).then_some(self)
+    }
+
+    fn as_primitive_array(&self) -> Option<&dyn PrimitiveArrayTrait> {
+        matches!(self.dtype(), DType::Primitive(..)).then_some(self)
+    }
+}
+
+impl BoolArrayTrait for LazyArray {
+    fn maybe_null_indices_iter<'a>(&'a self) -> Box<dyn Iterator<Item = usize> + 'a> {
+        let buffer = self
+            .clone()
+            .into_bool()
+            .vortex_expect("Failed to evaluate LazyArray")
+            .boolean_buffer();
+        Box::new(buffer.set_indices().collect::<Vec<_>>().into_iter())
+    }
+
+    fn maybe_null_slices_iter<'a>(&'a self) -> Box<dyn Iterator<Item = (usize, usize)> + 'a> {
+        let buffer = self
+            .clone()
+            .into_bool()
+            .vortex_expect("Failed to evaluate LazyArray")
+            .boolean_buffer();
+        Box::new(buffer.set_slices().collect::<Vec<_>>().into_iter())
+    }
+}
+
+impl PrimitiveArrayTrait for LazyArray {}
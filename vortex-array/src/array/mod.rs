@@ -8,6 +8,7 @@ mod chunked;
 mod constant;
 mod datetime;
 mod extension;
+mod lazy;
 mod null;
 mod primitive;
 mod sparse;
@@ -25,6 +26,7 @@ pub use self::chunked::*;
 pub use self::constant::*;
 pub use self::datetime::*;
 pub use self::extension::*;
+pub use self::lazy::*;
 pub use self::null::*;
 pub use self::primitive::*;
 pub use self::sparse::*;
@@ -55,7 +55,9 @@ impl ArrayValidity for NullArray {
 
 impl ArrayStatisticsCompute for NullArray {
     fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
-        Ok(StatsSet::nulls(self.len(), &DType::Null))
+        let mut stats = StatsSet::nulls(self.len(), &DType::Null);
+        stats.set(Stat::UncompressedSizeInBytes, 0.into());
+        Ok(stats)
     }
 }
 
@@ -1,47 +1,35 @@
 use vortex_dtype::{match_each_native_ptype, NativePType};
-use vortex_error::{vortex_err, VortexResult};
+use vortex_error::VortexResult;
 
 use crate::array::primitive::PrimitiveArray;
-use crate::compute::FilterFn;
-use crate::variants::BoolArrayTrait;
+use crate::compute::{FilterFn, FilterIter, FilterMask};
 use crate::{Array, IntoArray};
 
 impl FilterFn for PrimitiveArray {
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
-        filter_select_primitive(self, predicate).map(|a| a.into_array())
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        filter_select_primitive(self, mask).map(|a| a.into_array())
     }
 }
 
 fn filter_select_primitive(
     arr: &PrimitiveArray,
-    predicate: &Array,
+    mask: &FilterMask,
 ) -> VortexResult<PrimitiveArray> {
-    predicate.with_dyn(|b| {
-        let validity = arr.validity().filter(predicate)?;
-        let predicate = b.as_bool_array().ok_or_else(||vortex_err!(
-                NotImplemented: "as_bool_array",
-                predicate.encoding().id()
-            ))?;
-        let selection_count = predicate.true_count();
-        match_each_native_ptype!(arr.ptype(), |$T| {
-            let slice = arr.maybe_null_slice::<$T>();
-            Ok(PrimitiveArray::from_vec(filter_primitive_slice(slice, predicate, selection_count), validity))
-        })
+    let validity = arr.validity().filter(mask)?;
+    match_each_native_ptype!(arr.ptype(), |$T| {
+        let slice = arr.maybe_null_slice::<$T>();
+        Ok(PrimitiveArray::from_vec(filter_primitive_slice(slice, mask), validity))
     })
 }
 
-pub fn filter_primitive_slice<T: NativePType>(
-    arr: &[T],
-    predicate: &dyn BoolArrayTrait,
-    selection_count: usize,
-) -> Vec<T> {
-    let mut chunks = Vec::with_capacity(selection_count);
-    if selection_count * 2 > predicate.len() {
-        predicate.maybe_null_slices_iter().for_each(|(start, end)| {
+pub fn filter_primitive_slice<T: NativePType>(arr: &[T], mask: &FilterMask) -> Vec<T> {
+    let mut chunks = Vec::with_capacity(mask.true_count());
+    match mask.iter() {
+        FilterIter::Slices(slices) => slices.iter().for_each(|&(start, end)| {
             chunks.extend_from_slice(&arr[start..end]);
-        });
-    } else {
-        chunks.extend(predicate.maybe_null_indices_iter().map(|idx| arr[idx]));
+        }),
+        FilterIter::Indices(indices) => chunks.extend(indices.iter().map(|&idx| arr[idx])),
+        FilterIter::Bitmap(bitmap) => chunks.extend(bitmap.set_indices().map(|idx| arr[idx])),
     }
     chunks
 }
@@ -53,6 +41,8 @@ mod test {
     use crate::array::primitive::compute::filter::filter_select_primitive;
     use crate::array::primitive::PrimitiveArray;
     use crate::array::BoolArray;
+    use crate::compute::FilterMask;
+    use crate::IntoArray;
 
     #[test]
     fn filter_run_variant_mixed_test() {
@@ -60,7 +50,7 @@ mod test {
         let arr = PrimitiveArray::from(vec![1u32, 24, 54, 2, 3, 2, 3, 2]);
 
         let filtered =
-            filter_select_primitive(&arr, BoolArray::from(filter.clone()).as_ref()).unwrap();
+            filter_select_primitive(&arr, &mask(BoolArray::from(filter.clone()))).unwrap();
         assert_eq!(
             filtered.len(),
             filter.iter().filter(|x| **x).collect_vec().len()
@@ -77,4 +67,8 @@ mod test {
                 .collect_vec()
         )
     }
+
+    fn mask(predicate: BoolArray) -> FilterMask {
+        FilterMask::try_from(predicate.into_array()).unwrap()
+    }
 }
@@ -3,23 +3,45 @@ use std::collections::HashMap;
 use std::mem::size_of;
 
 use arrow_buffer::buffer::BooleanBuffer;
-use num_traits::PrimInt;
+use num_traits::{PrimInt, ToPrimitive};
 use vortex_dtype::half::f16;
 use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability};
 use vortex_error::VortexResult;
 use vortex_scalar::Scalar;
 
 use crate::array::primitive::PrimitiveArray;
-use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
+use crate::stats::{
+    ArrayStatisticsCompute, BloomFilter, Histogram, HyperLogLog, Stat, StatsOptions, StatsSet,
+    TDigest,
+};
 use crate::validity::{ArrayValidity, LogicalValidity};
-use crate::{ArrayDType, IntoArrayVariant};
+use crate::{ArrayDType, ArrayTrait, IntoArrayVariant};
 
-trait PStatsType: NativePType + Into<Scalar> + BitWidth {}
+trait PStatsType: NativePType + Into<Scalar> + BitWidth + Summable {}
 
-impl<T: NativePType + Into<Scalar> + BitWidth> PStatsType for T {}
+impl<T: NativePType + Into<Scalar> + BitWidth + Summable> PStatsType for T {}
 
 impl ArrayStatisticsCompute for PrimitiveArray {
     fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        // Sketches are costly to build, so they are only computed when asked for.
+        if matches!(
+            stat,
+            Stat::ApproxDistinct | Stat::ApproxQuantiles | Stat::BloomFilter | Stat::Histogram
+        ) {
+            return compute_sketch(self, stat, options);
+        }
+        if stat == Stat::UncompressedSizeInBytes {
+            return Ok(StatsSet::of(stat, self.nbytes().into()));
+        }
+
         match_each_native_ptype!(self.ptype(), |$P| {
             match self.logical_validity() {
                 LogicalValidity::AllValid(_) => self.maybe_null_slice::<$P>().compute_statistics(stat),
@@ -34,6 +56,45 @@ impl ArrayStatisticsCompute for PrimitiveArray {
     }
 }
 
+fn compute_sketch(
+    array: &PrimitiveArray,
+    stat: Stat,
+    options: &StatsOptions,
+) -> VortexResult<StatsSet> {
+    let validity = array.logical_validity().to_null_buffer()?;
+    let is_valid = |i: usize| validity.as_ref().map_or(true, |v| v.is_valid(i));
+    match_each_native_ptype!(array.ptype(), |$P| {
+        let values = array
+            .maybe_null_slice::<$P>()
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| is_valid(*i))
+            .map(|(_, v)| v);
+        let sketch = match stat {
+            Stat::ApproxDistinct => {
+                let mut hll = HyperLogLog::new();
+                values.for_each(|v| hll.insert(&v.to_le_bytes()));
+                hll.into()
+            }
+            Stat::BloomFilter => {
+                let null_count = validity.as_ref().map_or(0, |v| v.null_count());
+                let mut bloom = BloomFilter::with_capacity(array.len() - null_count);
+                values.for_each(|v| bloom.insert_native(*v));
+                bloom.into()
+            }
+            Stat::Histogram => {
+                Histogram::from_values(
+                    values.filter_map(|v| v.to_f64()),
+                    options.histogram_buckets,
+                )
+                    .into()
+            }
+            _ => TDigest::from_values(values.filter_map(|v| v.to_f64())).into(),
+        };
+        Ok(StatsSet::of(stat, sketch))
+    })
+}
+
 impl<T: PStatsType> ArrayStatisticsCompute for &[T] {
     fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
         if self.is_empty() {
@@ -109,6 +170,61 @@ int_bit_width!(i16);
 int_bit_width!(i32);
 int_bit_width!(i64);
 
+/// Values are summed in the widest type of their kind, see [sum_ptype](crate::stats::sum_ptype).
+trait Summable: Copy {
+    type Sum: Copy + Into<Scalar>;
+
+    fn zero_sum() -> Self::Sum;
+
+    /// Returns `None` if the sum overflows.
+    fn checked_sum(sum: Self::Sum, value: Self) -> Option<Self::Sum>;
+}
+
+macro_rules! int_summable {
+    ($T:ty, $S:ty) => {
+        impl Summable for $T {
+            type Sum = $S;
+
+            fn zero_sum() -> Self::Sum {
+                0
+            }
+
+            fn checked_sum(sum: Self::Sum, value: Self) -> Option<Self::Sum> {
+                sum.checked_add(<$S>::from(value))
+            }
+        }
+    };
+}
+
+int_summable!(u8, u64);
+int_summable!(u16, u64);
+int_summable!(u32, u64);
+int_summable!(u64, u64);
+int_summable!(i8, i64);
+int_summable!(i16, i64);
+int_summable!(i32, i64);
+int_summable!(i64, i64);
+
+macro_rules! float_summable {
+    ($T:ty) => {
+        impl Summable for $T {
+            type Sum = f64;
+
+            fn zero_sum() -> Self::Sum {
+                0.0
+            }
+
+            fn checked_sum(sum: Self::Sum, value: Self) -> Option<Self::Sum> {
+                Some(sum + f64::from(value))
+            }
+        }
+    };
+}
+
+float_summable!(f16);
+float_summable!(f32);
+float_summable!(f64);
+
 // TODO(ngates): just skip counting this in the implementation.
 macro_rules! float_bit_width {
     ($T:ty) => {
@@ -137,6 +253,7 @@ struct StatsAccumulator<T: PStatsType> {
     run_count: usize,
     null_count: usize,
     nan_count: usize,
+    sum: Option<T::Sum>,
     bit_widths: Vec<usize>,
     trailing_zeros: Vec<usize>,
     len: usize,
@@ -156,6 +273,7 @@ impl<T: PStatsType> StatsAccumulator<T> {
             trailing_zeros: vec![0; size_of::<T>() * 8 + 1],
             len: 1,
             nan_count: first_value.is_nan().then_some(1).unwrap_or_default(),
+            sum: T::checked_sum(T::zero_sum(), first_value),
         };
         stats.bit_widths[first_value.bit_width() as usize] += 1;
         stats.trailing_zeros[first_value.trailing_zeros() as usize] += 1;
@@ -189,6 +307,7 @@ impl<T: PStatsType> StatsAccumulator<T> {
         if next.is_nan() {
             self.nan_count += 1;
         }
+        self.sum = self.sum.and_then(|sum| T::checked_sum(sum, next));
 
         if next.is_eq(self.prev) {
             self.is_strict_sorted = false;
@@ -210,7 +329,7 @@ impl<T: PStatsType> StatsAccumulator<T> {
         let is_constant = (self.min == self.max && self.null_count == 0 && self.nan_count == 0)
             || self.null_count == self.len;
 
-        StatsSet::from(HashMap::from([
+        let mut stats = StatsSet::from(HashMap::from([
             (Stat::Min, self.min.into()),
             (Stat::Max, self.max.into()),
             (Stat::NullCount, self.null_count.into()),
@@ -223,7 +342,14 @@ impl<T: PStatsType> StatsAccumulator<T> {
                 (self.is_sorted && self.is_strict_sorted).into(),
             ),
             (Stat::RunCount, self.run_count.into()),
-        ]))
+        ]));
+        if let Some(sum) = self.sum {
+            stats.set(Stat::Sum, sum.into());
+        }
+        if T::PTYPE.is_float() {
+            stats.set(Stat::NaNCount, self.nan_count.into());
+        }
+        stats
     }
 }
 
@@ -233,7 +359,8 @@ mod test {
     use vortex_scalar::Scalar;
 
     use crate::array::primitive::PrimitiveArray;
-    use crate::stats::{ArrayStatistics, Stat};
+    use crate::stats::{ArrayStatistics, Histogram, Stat, StatsOptions};
+    use crate::ArrayTrait;
 
     #[test]
     fn stats() {
@@ -301,4 +428,73 @@ mod test {
         assert_eq!(min, Some(null_i32.clone()));
         assert_eq!(max, Some(null_i32));
     }
+
+    #[test]
+    fn sum_and_nan_count() {
+        let arr =
+            PrimitiveArray::from_nullable_vec(vec![Some(1.5f32), None, Some(f32::NAN), Some(2.5)]);
+        assert!(arr.statistics().compute_sum::<f64>().unwrap().is_nan());
+        assert_eq!(arr.statistics().compute_nan_count(), Some(1));
+        assert_eq!(
+            arr.statistics().compute_uncompressed_size_in_bytes(),
+            Some(arr.nbytes())
+        );
+
+        let arr = PrimitiveArray::from_nullable_vec(vec![Some(200u8), None, Some(100)]);
+        assert_eq!(arr.statistics().compute_sum::<u64>(), Some(300));
+        assert_eq!(arr.statistics().compute_nan_count(), None);
+
+        let arr = PrimitiveArray::from(vec![i64::MAX, 1]);
+        assert_eq!(arr.statistics().compute(Stat::Sum), None);
+    }
+
+    #[test]
+    fn approx_sketches() {
+        let arr = PrimitiveArray::from_nullable_vec(
+            (0..10_000i64)
+                .map(|i| (i % 7 != 0).then_some(i % 1_000))
+                .collect(),
+        );
+        let distinct = arr.statistics().compute_approx_distinct_count().unwrap();
+        assert!(distinct.abs_diff(1_000) < 20, "{distinct}");
+        let median = arr.statistics().compute_approx_quantile(0.5).unwrap();
+        assert!((median - 500.0).abs() < 20.0, "{median}");
+        assert_eq!(arr.statistics().compute_approx_quantile(1.0), Some(999.0));
+    }
+
+    #[test]
+    fn bloom_filter() {
+        let arr = PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(-7), Some(3)]);
+        let bloom = arr.statistics().compute_bloom_filter().unwrap();
+        assert!(bloom.might_contain_native(3i32));
+        assert!(bloom.might_contain_native(-7i32));
+        assert!(!bloom.might_contain_native(4i32));
+    }
+
+    #[test]
+    fn histogram() {
+        let arr = PrimitiveArray::from_nullable_vec(
+            (0..1000u32).map(|i| (i % 10 != 0).then_some(i)).collect(),
+        );
+        let histogram = arr.statistics().compute_histogram().unwrap();
+        assert_eq!(histogram.count(), 900);
+        let le = histogram.fraction_le(499.0).unwrap();
+        assert!((le - 0.5).abs() < 0.01, "{le}");
+    }
+
+    #[test]
+    fn histogram_buckets_option() {
+        let arr = PrimitiveArray::from((0..1000u32).collect::<Vec<_>>());
+        let options = StatsOptions {
+            histogram_buckets: 4,
+            ..StatsOptions::default()
+        };
+        let histogram = Histogram::try_from(
+            &arr.statistics()
+                .compute_with_options(Stat::Histogram, &options)
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(histogram.num_buckets(), 4);
+    }
 }
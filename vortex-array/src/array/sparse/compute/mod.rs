@@ -3,11 +3,11 @@ use vortex_error::{VortexExpect, VortexResult, VortexUnwrap as _};
 use vortex_scalar::Scalar;
 
 use crate::array::sparse::SparseArray;
-use crate::array::PrimitiveArray;
-use crate::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
+use crate::array::{ConstantArray, PrimitiveArray};
+use crate::compute::unary::{scalar_at, scalar_at_unchecked, unary_math, MathOp, ScalarAtFn};
 use crate::compute::{
-    search_sorted, take, ArrayCompute, FilterFn, SearchResult, SearchSortedFn, SearchSortedSide,
-    SliceFn, TakeFn,
+    search_sorted, take, ArrayCompute, FilterFn, FilterMask, SearchResult, SearchSortedFn,
+    SearchSortedSide, SliceFn, TakeFn,
 };
 use crate::{Array, IntoArray, IntoArrayVariant};
 
@@ -34,6 +34,26 @@ impl ArrayCompute for SparseArray {
     fn filter(&self) -> Option<&dyn FilterFn> {
         Some(self)
     }
+
+    fn unary_math(&self, op: MathOp) -> Option<VortexResult<Array>> {
+        Some(sparse_math(self, op))
+    }
+}
+
+fn sparse_math(array: &SparseArray, op: MathOp) -> VortexResult<Array> {
+    // Apply the function to the patched values and to the fill value, keeping the same positions.
+    let fill = ConstantArray::new(array.fill_scalar(), 1)
+        .into_array()
+        .into_primitive()?;
+    let fill = scalar_at(unary_math(fill, op)?, 0)?;
+    SparseArray::try_new_with_offset(
+        array.indices(),
+        unary_math(array.values(), op)?,
+        array.len(),
+        array.indices_offset(),
+        fill.into_value(),
+    )
+    .map(IntoArray::into_array)
 }
 
 impl ScalarAtFn for SparseArray {
@@ -74,8 +94,8 @@ impl SearchSortedFn for SparseArray {
 }
 
 impl FilterFn for SparseArray {
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
-        let buffer = predicate.clone().into_bool()?.boolean_buffer();
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        let buffer = mask.boolean_buffer();
         let mut coordinate_indices: Vec<u64> = Vec::new();
         let mut value_indices = Vec::new();
         let mut last_inserted_index = 0;
@@ -103,7 +123,7 @@ impl FilterFn for SparseArray {
         Ok(SparseArray::try_new(
             PrimitiveArray::from(coordinate_indices).into_array(),
             take(self.values(), PrimitiveArray::from(value_indices))?,
-            buffer.count_set_bits(),
+            mask.true_count(),
             self.fill_value().clone(),
         )?
         .into_array())
@@ -118,7 +138,10 @@ mod test {
     use crate::array::primitive::PrimitiveArray;
     use crate::array::sparse::SparseArray;
     use crate::array::BoolArray;
-    use crate::compute::{filter, search_sorted, slice, SearchResult, SearchSortedSide};
+    use crate::compute::unary::{floor, scalar_at};
+    use crate::compute::{
+        filter, search_sorted, slice, FilterMask, SearchResult, SearchSortedSide,
+    };
     use crate::validity::Validity;
     use crate::{Array, IntoArray, IntoArrayVariant};
 
@@ -194,7 +217,7 @@ mod test {
         predicate.extend_from_slice(&[false; 17]);
         let predicate = BoolArray::from_vec(predicate, Validity::NonNullable).into_array();
 
-        let filtered_array = filter(&array, &predicate).unwrap();
+        let filtered_array = filter(&array, &FilterMask::try_from(predicate).unwrap()).unwrap();
         let filtered_array = SparseArray::try_from(filtered_array).unwrap();
 
         assert_eq!(filtered_array.len(), 1);
@@ -218,7 +241,7 @@ mod test {
         .unwrap()
         .into_array();
 
-        let filtered_array = filter(&array, &predicate).unwrap();
+        let filtered_array = filter(&array, &FilterMask::try_from(predicate).unwrap()).unwrap();
         let filtered_array = SparseArray::try_from(filtered_array).unwrap();
 
         assert_eq!(filtered_array.len(), 4);
@@ -226,4 +249,25 @@ mod test {
 
         assert_eq!(primitive.maybe_null_slice::<u64>(), &[1, 3]);
     }
+
+    #[test]
+    fn sparse_floor() {
+        let sparse = SparseArray::try_new(
+            PrimitiveArray::from(vec![1u64, 3]).into_array(),
+            PrimitiveArray::from(vec![2.5f64, -0.5]).into_array(),
+            5,
+            ScalarValue::from(1.5f64),
+        )
+        .unwrap()
+        .into_array();
+
+        let result = floor(&sparse).unwrap();
+        assert!(SparseArray::try_from(&result).is_ok());
+        assert_eq!(
+            (0..result.len())
+                .map(|i| f64::try_from(&scalar_at(&result, i).unwrap()).unwrap())
+                .collect::<Vec<_>>(),
+            vec![1.0, 2.0, 1.0, -1.0, 1.0]
+        );
+    }
 }
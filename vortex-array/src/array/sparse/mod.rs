@@ -10,12 +10,13 @@ use crate::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use crate::compute::unary::scalar_at;
 use crate::compute::{search_sorted, SearchResult, SearchSortedSide};
 use crate::encoding::ids;
-use crate::stats::{ArrayStatisticsCompute, StatsSet};
+use crate::stats::StatsSet;
 use crate::validity::{ArrayValidity, LogicalValidity};
 use crate::{impl_encoding, Array, ArrayDType, ArrayTrait, IntoArray, IntoArrayVariant};
 
 mod compute;
 mod flatten;
+mod stats;
 mod variants;
 
 impl_encoding!("vortex.sparse", ids::SPARSE, Sparse);
@@ -179,8 +180,6 @@ impl AcceptArrayVisitor for SparseArray {
     }
 }
 
-impl ArrayStatisticsCompute for SparseArray {}
-
 impl ArrayValidity for SparseArray {
     fn is_valid(&self, index: usize) -> bool {
         match self.search_index(index).map(SearchResult::to_found) {
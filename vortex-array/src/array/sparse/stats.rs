@@ -0,0 +1,163 @@
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::SparseArray;
+use crate::compute::unary::scalar_at;
+use crate::stats::{
+    compute_canonical_statistics, ArrayStatistics, ArrayStatisticsCompute, Stat, StatsOptions,
+    StatsSet,
+};
+use crate::{Array, ArrayDType};
+
+impl ArrayStatisticsCompute for SparseArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        if self.is_empty() {
+            return Ok(StatsSet::new());
+        }
+
+        // Combine the stats of the patch values with the fill value of the remaining positions.
+        let values = self.values();
+        let fill = self.fill_scalar();
+        let fill_len = self.len() - values.len();
+        let value = match stat {
+            Stat::NullCount => {
+                let fill_nulls = if fill.is_null() { fill_len } else { 0 };
+                values_count(&values, Stat::NullCount).map(|n| (n + fill_nulls).into())
+            }
+            Stat::TrueCount => {
+                let fill_trues = if fill.value().as_bool()? == Some(true) {
+                    fill_len
+                } else {
+                    0
+                };
+                values_count(&values, Stat::TrueCount).map(|n| (n + fill_trues).into())
+            }
+            Stat::Min | Stat::Max => {
+                let values_bound = if values.is_empty() {
+                    None
+                } else {
+                    match values.statistics().compute_with_options(stat, options) {
+                        Some(bound) => Some(bound).filter(Scalar::is_valid),
+                        None => return Ok(StatsSet::new()),
+                    }
+                };
+                let fill_bound = (fill_len > 0 && fill.is_valid()).then_some(fill);
+                // The fill value is an actual value, so it is exact even where the bound of the
+                // values is not, as long as it isn't beaten by that bound.
+                let (bound, is_exact) = match (values_bound, fill_bound) {
+                    (Some(lhs), Some(rhs)) => {
+                        let rhs_wins = if stat == Stat::Min {
+                            rhs <= lhs
+                        } else {
+                            rhs >= lhs
+                        };
+                        if rhs_wins {
+                            (rhs, true)
+                        } else {
+                            (lhs, values.statistics().is_exact(stat))
+                        }
+                    }
+                    (Some(bound), None) => (bound, values.statistics().is_exact(stat)),
+                    (None, Some(bound)) => (bound, true),
+                    (None, None) => (Scalar::null(self.dtype().clone()), true),
+                };
+                let mut stats = StatsSet::new();
+                if is_exact {
+                    stats.set(stat, bound);
+                } else {
+                    stats.set_bound(stat, bound);
+                }
+                return Ok(stats);
+            }
+            Stat::IsConstant => {
+                if values.is_empty() {
+                    Some(true.into())
+                } else {
+                    match values.statistics().compute_is_constant() {
+                        Some(true) => {
+                            Some((fill_len == 0 || scalar_at(&values, 0)? == fill).into())
+                        }
+                        is_constant => is_constant.map(Scalar::from),
+                    }
+                }
+            }
+            _ => {
+                return compute_canonical_statistics(self.as_ref(), stat, options);
+            }
+        };
+        Ok(value
+            .map(|value| StatsSet::of(stat, value))
+            .unwrap_or_default())
+    }
+}
+
+/// A count stat of the patch values, which is zero when there are none.
+fn values_count(values: &Array, stat: Stat) -> Option<usize> {
+    if values.is_empty() {
+        return Some(0);
+    }
+    values.statistics().compute_as::<usize>(stat)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::array::{PrimitiveArray, SparseArray};
+    use crate::compute::slice;
+    use crate::stats::{ArrayStatistics, Stat};
+    use crate::IntoArray;
+
+    #[test]
+    fn stats_with_fill() {
+        let sparse = SparseArray::try_new(
+            vec![2u64, 5, 8].into_array(),
+            vec![100i32, -7, 300].into_array(),
+            10,
+            42i32.into(),
+        )
+        .unwrap()
+        .into_array();
+        let stats = sparse.statistics();
+        assert_eq!(stats.compute_min::<i32>(), Some(-7));
+        assert_eq!(stats.compute_max::<i32>(), Some(300));
+        assert_eq!(stats.compute_null_count(), Some(0));
+        assert_eq!(stats.compute_is_constant(), Some(false));
+        assert_eq!(stats.compute_run_count(), Some(7));
+
+        let only_fill = slice(&sparse, 3, 5).unwrap();
+        assert_eq!(only_fill.statistics().compute_is_constant(), Some(true));
+        assert_eq!(only_fill.statistics().compute_max::<i32>(), Some(42));
+    }
+
+    #[test]
+    fn stats_with_null_fill() {
+        let dtype = DType::Primitive(PType::I32, Nullability::Nullable);
+        let sparse = SparseArray::try_new(
+            vec![2u64, 5].into_array(),
+            PrimitiveArray::from_nullable_vec(vec![Some(100i32), None]).into_array(),
+            10,
+            Scalar::null(dtype.clone()).into_value(),
+        )
+        .unwrap()
+        .into_array();
+        let stats = sparse.statistics();
+        assert_eq!(stats.compute_null_count(), Some(9));
+        assert_eq!(stats.compute_min::<i32>(), Some(100));
+
+        let all_null = slice(&sparse, 6, 10).unwrap();
+        assert_eq!(
+            all_null.statistics().compute(Stat::Max),
+            Some(Scalar::null(dtype))
+        );
+    }
+}
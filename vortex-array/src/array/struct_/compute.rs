@@ -4,8 +4,7 @@ use vortex_scalar::Scalar;
 
 use crate::array::struct_::StructArray;
 use crate::compute::unary::{scalar_at, scalar_at_unchecked, ScalarAtFn};
-use crate::compute::{filter, slice, take, ArrayCompute, FilterFn, SliceFn, TakeFn};
-use crate::stats::ArrayStatistics;
+use crate::compute::{filter, slice, take, ArrayCompute, FilterFn, FilterMask, SliceFn, TakeFn};
 use crate::variants::StructArrayTrait;
 use crate::{Array, ArrayDType, IntoArray};
 
@@ -78,22 +77,17 @@ impl SliceFn for StructArray {
 }
 
 impl FilterFn for StructArray {
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
         let fields: Vec<Array> = self
             .children()
-            .map(|field| filter(&field, predicate))
+            .map(|field| filter(&field, mask))
             .try_collect()?;
-        let length = fields
-            .first()
-            .map(|a| a.len())
-            .or_else(|| predicate.statistics().compute_true_count())
-            .unwrap_or_default();
 
         Self::try_new(
             self.names().clone(),
             fields,
-            length,
-            self.validity().filter(predicate)?,
+            mask.true_count(),
+            self.validity().filter(mask)?,
         )
         .map(|a| a.into_array())
     }
@@ -101,8 +95,10 @@ impl FilterFn for StructArray {
 
 #[cfg(test)]
 mod tests {
-    use crate::array::{BoolArray, StructArray};
-    use crate::compute::filter;
+    use arrow_buffer::BooleanBuffer;
+
+    use crate::array::StructArray;
+    use crate::compute::{filter, FilterMask};
     use crate::validity::Validity;
 
     #[test]
@@ -112,7 +108,11 @@ mod tests {
         let mask = vec![
             false, true, false, true, false, true, false, true, false, true,
         ];
-        let filtered = filter(struct_arr.as_ref(), BoolArray::from(mask)).unwrap();
+        let filtered = filter(
+            struct_arr.as_ref(),
+            &FilterMask::from(BooleanBuffer::from(mask)),
+        )
+        .unwrap();
         assert_eq!(filtered.len(), 5);
     }
 
@@ -120,7 +120,11 @@ mod tests {
     fn filter_empty_struct_with_empty_filter() {
         let struct_arr =
             StructArray::try_new(vec![].into(), vec![], 0, Validity::NonNullable).unwrap();
-        let filtered = filter(struct_arr.as_ref(), BoolArray::from(vec![])).unwrap();
+        let filtered = filter(
+            struct_arr.as_ref(),
+            &FilterMask::from(BooleanBuffer::from(vec![])),
+        )
+        .unwrap();
         assert_eq!(filtered.len(), 0);
     }
 }
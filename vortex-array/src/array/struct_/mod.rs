@@ -7,7 +7,7 @@ use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexExpect as _, Vor
 
 use crate::array::visitor::{AcceptArrayVisitor, ArrayVisitor};
 use crate::encoding::ids;
-use crate::stats::{ArrayStatisticsCompute, StatsSet};
+use crate::stats::{ArrayStatistics, ArrayStatisticsCompute, Stat, StatsSet};
 use crate::validity::{ArrayValidity, LogicalValidity, Validity, ValidityMetadata};
 use crate::variants::{ArrayVariants, StructArrayTrait};
 use crate::{impl_encoding, Array, ArrayDType, ArrayTrait, Canonical, IntoArray, IntoCanonical};
@@ -189,7 +189,23 @@ impl AcceptArrayVisitor for StructArray {
     }
 }
 
-impl ArrayStatisticsCompute for StructArray {}
+impl ArrayStatisticsCompute for StructArray {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        if stat != Stat::UncompressedSizeInBytes {
+            return Ok(StatsSet::new());
+        }
+
+        // The fields of a canonical struct may still be encoded.
+        let mut size = 0;
+        for child in self.children().chain(self.validity().into_array()) {
+            match child.statistics().compute_uncompressed_size_in_bytes() {
+                Some(child_size) => size += child_size,
+                None => return Ok(StatsSet::new()),
+            }
+        }
+        Ok(StatsSet::of(stat, size.into()))
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -199,7 +215,8 @@ mod test {
     use crate::array::primitive::PrimitiveArray;
     use crate::array::struct_::StructArray;
     use crate::array::varbin::VarBinArray;
-    use crate::array::BoolArray;
+    use crate::array::{BoolArray, ConstantArray};
+    use crate::stats::ArrayStatistics;
     use crate::validity::Validity;
     use crate::variants::StructArrayTrait;
     use crate::IntoArray;
@@ -240,4 +257,22 @@ mod test {
         let prims = PrimitiveArray::try_from(struct_b.field(1).unwrap()).unwrap();
         assert_eq!(prims.maybe_null_slice::<i64>(), [0i64, 1, 2, 3, 4]);
     }
+
+    #[test]
+    fn uncompressed_size() {
+        let xs = PrimitiveArray::from_vec(vec![0i64, 1, 2, 3, 4], Validity::NonNullable);
+        let ys = ConstantArray::new(7i32, 5);
+        let array = StructArray::try_new(
+            FieldNames::from(["xs".into(), "ys".into()]),
+            vec![xs.into_array(), ys.into_array()],
+            5,
+            Validity::NonNullable,
+        )
+        .unwrap();
+
+        assert_eq!(
+            array.statistics().compute_uncompressed_size_in_bytes(),
+            Some(5 * 8 + 5 * 4)
+        );
+    }
 }
@@ -5,37 +5,30 @@ use vortex_error::{vortex_err, vortex_panic, VortexResult};
 
 use crate::array::varbin::builder::VarBinBuilder;
 use crate::array::varbin::VarBinArray;
-use crate::compute::FilterFn;
+use crate::compute::{FilterFn, FilterIter, FilterMask};
 use crate::validity::Validity;
-use crate::variants::BoolArrayTrait;
 use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
 
 impl FilterFn for VarBinArray {
-    fn filter(&self, predicate: &Array) -> VortexResult<Array> {
-        filter_select_var_bin(self, predicate).map(|a| a.into_array())
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array> {
+        filter_select_var_bin(self, mask).map(|a| a.into_array())
     }
 }
 
-fn filter_select_var_bin(arr: &VarBinArray, predicate: &Array) -> VortexResult<VarBinArray> {
-    predicate.with_dyn(|p| {
-        let predicate = p.as_bool_array().ok_or_else(|| {
-            vortex_err!(
-                NotImplemented: "as_bool_array",
-                predicate.encoding().id()
-            )
-        })?;
-        let selection_count = predicate.true_count();
-        if selection_count * 2 > predicate.len() {
-            filter_select_var_bin_by_slice(arr, predicate, selection_count)
-        } else {
-            filter_select_var_bin_by_index(arr, predicate, selection_count)
+fn filter_select_var_bin(arr: &VarBinArray, mask: &FilterMask) -> VortexResult<VarBinArray> {
+    match mask.iter() {
+        FilterIter::Slices(slices) => {
+            filter_select_var_bin_by_slice(arr, slices, mask.true_count())
         }
-    })
+        FilterIter::Indices(_) | FilterIter::Bitmap(_) => {
+            filter_select_var_bin_by_index(arr, mask.indices(), mask.true_count())
+        }
+    }
 }
 
 fn filter_select_var_bin_by_slice(
     values: &VarBinArray,
-    predicate: &dyn BoolArrayTrait,
+    slices: &[(usize, usize)],
     selection_count: usize,
 ) -> VortexResult<VarBinArray> {
     let offsets = values.offsets().into_primitive()?;
@@ -44,7 +37,7 @@ fn filter_select_var_bin_by_slice(
             values.dtype().clone(),
             offsets.maybe_null_slice::<$O>(),
             values.bytes().into_primitive()?.maybe_null_slice::<u8>(),
-            predicate,
+            slices,
             values.validity(),
             selection_count
         )
@@ -55,7 +48,7 @@ fn filter_select_var_bin_by_slice_primitive_offset<O>(
     dtype: DType,
     offsets: &[O],
     data: &[u8],
-    predicate: &dyn BoolArrayTrait,
+    slices: &[(usize, usize)],
     validity: Validity,
     selection_count: usize,
 ) -> VortexResult<VarBinArray>
@@ -67,7 +60,7 @@ where
     if let Some(val) = logical_validity.to_null_buffer()? {
         let mut builder = VarBinBuilder::<O>::with_capacity(selection_count);
 
-        for (start, end) in predicate.maybe_null_slices_iter() {
+        for &(start, end) in slices {
             let null_sl = val.slice(start, end - start);
             if null_sl.null_count() == 0 {
                 update_non_nullable_slice(data, offsets, &mut builder, start, end)
@@ -99,7 +92,7 @@ where
 
     let mut builder = VarBinBuilder::<O>::with_capacity(selection_count);
 
-    predicate.maybe_null_slices_iter().for_each(|(start, end)| {
+    slices.iter().for_each(|&(start, end)| {
         update_non_nullable_slice(data, offsets, &mut builder, start, end)
     });
 
@@ -134,7 +127,7 @@ fn update_non_nullable_slice<O>(
 
 fn filter_select_var_bin_by_index(
     values: &VarBinArray,
-    predicate: &dyn BoolArrayTrait,
+    indices: &[usize],
     selection_count: usize,
 ) -> VortexResult<VarBinArray> {
     let offsets = values.offsets().into_primitive()?;
@@ -143,7 +136,7 @@ fn filter_select_var_bin_by_index(
             values.dtype().clone(),
             offsets.maybe_null_slice::<$O>(),
             values.bytes().into_primitive()?.maybe_null_slice::<u8>(),
-            predicate,
+            indices,
             values.validity(),
             selection_count
         )
@@ -154,12 +147,12 @@ fn filter_select_var_bin_by_index_primitive_offset<O: NativePType>(
     dtype: DType,
     offsets: &[O],
     data: &[u8],
-    predicate: &dyn BoolArrayTrait,
+    indices: &[usize],
     validity: Validity,
     selection_count: usize,
 ) -> VortexResult<VarBinArray> {
     let mut builder = VarBinBuilder::<O>::with_capacity(selection_count);
-    for idx in predicate.maybe_null_indices_iter() {
+    for &idx in indices {
         if validity.is_valid(idx) {
             let (start, end) = (
                 offsets[idx].to_usize().ok_or_else(|| {
@@ -208,9 +201,7 @@ mod test {
             ],
             DType::Utf8(NonNullable),
         );
-        let filter = BoolArray::from(vec![true, false, true]);
-
-        let buf = filter_select_var_bin_by_index(&arr, &filter, 2)
+        let buf = filter_select_var_bin_by_index(&arr, &[0, 2], 2)
             .unwrap()
             .to_array();
 
@@ -231,9 +222,7 @@ mod test {
             ],
             DType::Utf8(NonNullable),
         );
-        let filter = BoolArray::from(vec![true, false, true, false, true]);
-
-        let buf = filter_select_var_bin_by_slice(&arr, &filter, 3)
+        let buf = filter_select_var_bin_by_slice(&arr, &[(0, 1), (2, 3), (4, 5)], 3)
             .unwrap()
             .to_array();
 
@@ -263,9 +252,7 @@ mod test {
         let validity =
             Validity::Array(BoolArray::from(vec![true, false, true, true, true, true]).to_array());
         let arr = VarBinArray::try_new(offsets, bytes, DType::Utf8(Nullable), validity).unwrap();
-        let filter = BoolArray::from(vec![true, true, true, false, true, true]);
-
-        let buf = filter_select_var_bin_by_slice(&arr, &filter, 5)
+        let buf = filter_select_var_bin_by_slice(&arr, &[(0, 3), (4, 6)], 5)
             .unwrap()
             .to_array();
 
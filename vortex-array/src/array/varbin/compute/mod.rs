@@ -3,11 +3,14 @@ use vortex_scalar::Scalar;
 
 use crate::array::varbin::{varbin_scalar, VarBinArray};
 use crate::compute::unary::ScalarAtFn;
-use crate::compute::{ArrayCompute, FilterFn, MaybeCompareFn, Operator, SliceFn, TakeFn};
+use crate::compute::{
+    ArrayCompute, FilterFn, MaybeCompareFn, Operator, SearchSortedFn, SliceFn, TakeFn,
+};
 use crate::{Array, ArrayDType};
 
 mod compare;
 mod filter;
+mod search_sorted;
 mod slice;
 mod take;
 
@@ -24,6 +27,10 @@ impl ArrayCompute for VarBinArray {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
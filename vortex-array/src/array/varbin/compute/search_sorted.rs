@@ -0,0 +1,115 @@
+use std::cmp::Ordering;
+use std::cmp::Ordering::Greater;
+
+use vortex_dtype::{match_each_integer_ptype, NativePType};
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::array::varbin::{varbin_needle, VarBinArray};
+use crate::compute::{IndexOrd, Len, SearchResult, SearchSorted, SearchSortedFn, SearchSortedSide};
+use crate::validity::Validity;
+use crate::{ArrayDType, IntoArrayVariant};
+
+impl SearchSortedFn for VarBinArray {
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        let needle = varbin_needle(&value.cast(self.dtype())?)?;
+        let offsets = self.offsets().into_primitive()?;
+        let bytes = self.bytes().into_primitive()?;
+        match_each_integer_ptype!(offsets.ptype(), |$O| {
+            Ok(SearchSortedVarBin {
+                offsets: offsets.maybe_null_slice::<$O>(),
+                bytes: bytes.maybe_null_slice::<u8>(),
+                validity: self.validity(),
+            }
+            .search_sorted(&needle.as_slice(), side))
+        })
+    }
+}
+
+/// Byte-wise ordering of the values of a [VarBinArray], with nulls sorted last.
+struct SearchSortedVarBin<'a, O> {
+    offsets: &'a [O],
+    bytes: &'a [u8],
+    validity: Validity,
+}
+
+impl<O: NativePType> IndexOrd<&[u8]> for SearchSortedVarBin<'_, O> {
+    fn index_cmp(&self, idx: usize, elem: &&[u8]) -> Option<Ordering> {
+        if self.validity.is_null(idx) {
+            return Some(Greater);
+        }
+
+        let start = self.offsets[idx].to_usize()?;
+        let end = self.offsets[idx + 1].to_usize()?;
+        Some(self.bytes[start..end].cmp(elem))
+    }
+}
+
+impl<O> Len for SearchSortedVarBin<'_, O> {
+    fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::array::VarBinArray;
+    use crate::compute::{search_sorted, slice, SearchResult, SearchSortedSide};
+    use crate::IntoArray;
+
+    #[test]
+    fn search_sorted_strings() {
+        let array = VarBinArray::from_vec(
+            vec!["apple", "banana", "banana", "cherry", "date"],
+            DType::Utf8(Nullability::NonNullable),
+        )
+        .into_array();
+
+        assert_eq!(
+            search_sorted(&array, "banana", SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(1)
+        );
+        assert_eq!(
+            search_sorted(&array, "banana", SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(3)
+        );
+        assert_eq!(
+            search_sorted(&array, "blueberry", SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(3)
+        );
+        assert_eq!(
+            search_sorted(&array, "a", SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(0)
+        );
+        assert_eq!(
+            search_sorted(&array, "zucchini", SearchSortedSide::Right).unwrap(),
+            SearchResult::NotFound(5)
+        );
+
+        let sliced = slice(&array, 2, 5).unwrap();
+        assert_eq!(
+            search_sorted(&sliced, "cherry", SearchSortedSide::Left).unwrap(),
+            SearchResult::Found(1)
+        );
+    }
+
+    #[test]
+    fn search_sorted_nulls_last() {
+        let array = VarBinArray::from_iter(
+            vec![Some("a"), Some("c"), None, None],
+            DType::Utf8(Nullability::Nullable),
+        )
+        .into_array();
+
+        assert_eq!(
+            search_sorted(&array, "b", SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(1)
+        );
+        assert_eq!(
+            search_sorted(&array, "c", SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(2)
+        );
+    }
+}
@@ -2,7 +2,8 @@ use std::fmt::{Debug, Display};
 
 use num_traits::AsPrimitive;
 use serde::{Deserialize, Serialize};
-pub use stats::compute_stats;
+pub(crate) use stats::set_string_bounds;
+pub use stats::{compute_approx_distinct, compute_bloom_filter, compute_stats};
 use vortex_buffer::Buffer;
 use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability, PType};
 use vortex_error::{
@@ -279,6 +280,15 @@ pub fn varbin_scalar(value: Buffer, dtype: &DType) -> Scalar {
     }
 }
 
+/// The raw bytes of a utf8 or binary scalar, e.g. the value passed to a search.
+pub(crate) fn varbin_needle(value: &Scalar) -> VortexResult<Buffer> {
+    match value.dtype() {
+        DType::Utf8(_) => value.value().as_buffer_string()?.map(Buffer::from),
+        _ => value.value().as_buffer()?,
+    }
+    .ok_or_else(|| vortex_err!("Expected a non-null utf8 or binary value"))
+}
+
 #[cfg(test)]
 mod test {
     use rstest::{fixture, rstest};
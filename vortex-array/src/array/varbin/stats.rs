@@ -7,19 +7,53 @@ use vortex_error::VortexResult;
 
 use crate::accessor::ArrayAccessor;
 use crate::array::varbin::{varbin_scalar, VarBinArray};
-use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
+use crate::stats::{
+    ArrayStatisticsCompute, BloomFilter, HyperLogLog, Stat, StatsOptions, StatsSet,
+};
 use crate::ArrayDType;
 
 impl ArrayStatisticsCompute for VarBinArray {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
         if self.is_empty() {
             return Ok(StatsSet::new());
         }
-        self.with_iterator(|iter| compute_stats(iter, self.dtype()))
+        if stat == Stat::ApproxDistinct {
+            return self.with_iterator(compute_approx_distinct);
+        }
+        if stat == Stat::BloomFilter {
+            return self.with_iterator(|iter| compute_bloom_filter(iter, self.len()));
+        }
+        self.with_iterator(|iter| compute_stats(iter, self.dtype(), options))
     }
 }
 
-pub fn compute_stats(iter: &mut dyn Iterator<Item = Option<&[u8]>>, dtype: &DType) -> StatsSet {
+/// Sketch the distinct non-null values, which is only done when the stat is asked for.
+pub fn compute_approx_distinct(iter: &mut dyn Iterator<Item = Option<&[u8]>>) -> StatsSet {
+    let mut hll = HyperLogLog::new();
+    iter.flatten().for_each(|v| hll.insert(v));
+    StatsSet::of(Stat::ApproxDistinct, hll.into())
+}
+
+/// Build a bloom filter of the non-null values, sized for `len` values.
+pub fn compute_bloom_filter(iter: &mut dyn Iterator<Item = Option<&[u8]>>, len: usize) -> StatsSet {
+    let mut bloom = BloomFilter::with_capacity(len);
+    iter.flatten().for_each(|v| bloom.insert(v));
+    StatsSet::of(Stat::BloomFilter, bloom.into())
+}
+
+pub fn compute_stats(
+    iter: &mut dyn Iterator<Item = Option<&[u8]>>,
+    dtype: &DType,
+    options: &StatsOptions,
+) -> StatsSet {
     let mut leading_nulls: usize = 0;
     let mut first_value: Option<&[u8]> = None;
     for v in &mut *iter {
@@ -35,7 +69,7 @@ pub fn compute_stats(iter: &mut dyn Iterator<Item = Option<&[u8]>>, dtype: &DTyp
         let mut acc = VarBinAccumulator::new(first_non_null);
         acc.n_nulls(leading_nulls);
         iter.for_each(|n| acc.nullable_next(n));
-        acc.finish(dtype)
+        acc.finish(dtype, options)
     } else {
         StatsSet::nulls(leading_nulls, dtype)
     }
@@ -105,20 +139,90 @@ impl<'a> VarBinAccumulator<'a> {
         self.runs += 1;
     }
 
-    pub fn finish(&self, dtype: &DType) -> StatsSet {
+    pub fn finish(&self, dtype: &DType, options: &StatsOptions) -> StatsSet {
         let is_constant =
             (self.min == self.max && self.null_count == 0) || self.null_count == self.len;
 
-        StatsSet::from(HashMap::from([
-            (Stat::Min, varbin_scalar(Buffer::from(self.min), dtype)),
-            (Stat::Max, varbin_scalar(Buffer::from(self.max), dtype)),
+        let mut stats = StatsSet::from(HashMap::from([
             (Stat::RunCount, self.runs.into()),
             (Stat::IsSorted, self.is_sorted.into()),
             (Stat::IsStrictSorted, self.is_strict_sorted.into()),
             (Stat::IsConstant, is_constant.into()),
             (Stat::NullCount, self.null_count.into()),
-        ]))
+        ]));
+
+        set_string_bounds(&mut stats, self.min, self.max, dtype, options);
+        stats
+    }
+}
+
+/// Set the [Stat::Min] and [Stat::Max] of utf8 or binary values, which are truncated and kept as
+/// bounds when longer than [StatsOptions::max_string_bound_length].
+pub(crate) fn set_string_bounds(
+    stats: &mut StatsSet,
+    min: &[u8],
+    max: &[u8],
+    dtype: &DType,
+    options: &StatsOptions,
+) {
+    let max_len = options.max_string_bound_length;
+    let utf8 = matches!(dtype, DType::Utf8(_));
+    if min.len() <= max_len {
+        stats.set(Stat::Min, varbin_scalar(Buffer::from(min), dtype));
+    } else {
+        let min = truncate_min(min, max_len, utf8);
+        stats.set_bound(Stat::Min, varbin_scalar(Buffer::from(min), dtype));
+    }
+    if max.len() <= max_len {
+        stats.set(Stat::Max, varbin_scalar(Buffer::from(max), dtype));
+    } else if let Some(max) = truncate_max(max, max_len, utf8) {
+        stats.set_bound(Stat::Max, varbin_scalar(Buffer::from(max), dtype));
+    }
+}
+
+/// A prefix of `value` of at most `max_len` bytes, which is never greater than `value`.
+fn truncate_min(value: &[u8], max_len: usize, utf8: bool) -> &[u8] {
+    if value.len() <= max_len {
+        return value;
+    }
+    let mut len = max_len;
+    // Don't split a utf8 code point.
+    while utf8 && len > 0 && is_continuation_byte(value[len]) {
+        len -= 1;
     }
+    &value[..len]
+}
+
+/// The smallest value of at most `max_len` bytes that is greater than every value starting with the
+/// truncated prefix of `value`, or `None` if no such value exists.
+fn truncate_max(value: &[u8], max_len: usize, utf8: bool) -> Option<Vec<u8>> {
+    let mut prefix = truncate_min(value, max_len, utf8).to_vec();
+    if !utf8 {
+        while let Some(last) = prefix.pop() {
+            if last < u8::MAX {
+                prefix.push(last + 1);
+                return Some(prefix);
+            }
+        }
+        return None;
+    }
+
+    let mut chars = String::from_utf8(prefix).ok()?.chars().collect::<Vec<_>>();
+    while let Some(last) = chars.pop() {
+        // Incrementing a char can lengthen its encoding, in which case an earlier char is bumped.
+        if let Some(next) = (last as u32 + 1..=char::MAX as u32).find_map(char::from_u32) {
+            let len = chars.iter().map(|c| c.len_utf8()).sum::<usize>() + next.len_utf8();
+            if len <= max_len {
+                chars.push(next);
+                return Some(String::from_iter(chars).into_bytes());
+            }
+        }
+    }
+    None
+}
+
+fn is_continuation_byte(byte: u8) -> bool {
+    byte & 0b1100_0000 == 0b1000_0000
 }
 
 #[cfg(test)]
@@ -128,8 +232,9 @@ mod test {
     use vortex_buffer::{Buffer, BufferString};
     use vortex_dtype::{DType, Nullability};
 
+    use crate::array::varbin::stats::{truncate_max, truncate_min};
     use crate::array::varbin::VarBinArray;
-    use crate::stats::{ArrayStatistics, Stat};
+    use crate::stats::{ArrayStatistics, Stat, StatsOptions};
 
     fn array(dtype: DType) -> VarBinArray {
         VarBinArray::from_vec(
@@ -200,4 +305,61 @@ mod test {
         assert!(array.statistics().get(Stat::Min).is_none());
         assert!(array.statistics().get(Stat::Max).is_none());
     }
+
+    #[test]
+    fn truncated_bounds() {
+        let long = "a".repeat(100);
+        let array = VarBinArray::from_vec(
+            vec![long.clone(), format!("{long}z"), "b".to_string()],
+            DType::Utf8(Nullability::NonNullable),
+        );
+        let stats = array.statistics();
+        assert_eq!(
+            stats.compute_min::<BufferString>().unwrap().as_str(),
+            "a".repeat(64)
+        );
+        assert!(!stats.is_exact(Stat::Min));
+        assert_eq!(stats.compute_max::<BufferString>().unwrap().as_str(), "b");
+        assert!(stats.is_exact(Stat::Max));
+    }
+
+    #[test]
+    fn bound_length_option() {
+        let array = VarBinArray::from_vec(
+            vec!["abcdef", "abcxyz"],
+            DType::Utf8(Nullability::NonNullable),
+        );
+        let options = StatsOptions {
+            max_string_bound_length: 4,
+            ..StatsOptions::default()
+        };
+        let stats = array.statistics();
+        let min = stats.compute_with_options(Stat::Min, &options).unwrap();
+        assert_eq!(BufferString::try_from(&min).unwrap().as_str(), "abcd");
+        assert!(!stats.is_exact(Stat::Min));
+        let max = stats.compute_with_options(Stat::Max, &options).unwrap();
+        assert_eq!(BufferString::try_from(&max).unwrap().as_str(), "abcy");
+        assert!(!stats.is_exact(Stat::Max));
+    }
+
+    #[test]
+    fn truncate_utf8() {
+        // 'é' takes two bytes, so it isn't split.
+        assert_eq!(truncate_min("abé".as_bytes(), 3, true), b"ab");
+        assert_eq!(truncate_max("abé".as_bytes(), 3, true).unwrap(), b"ac");
+        // Bumping '\u{7f}' would lengthen it beyond the limit, so the previous char is bumped.
+        assert_eq!(truncate_max("a\u{7f}bc".as_bytes(), 2, true).unwrap(), b"b");
+        assert_eq!(
+            truncate_max("\u{d7ff}bc".as_bytes(), 3, true).unwrap(),
+            "\u{e000}".as_bytes()
+        );
+        assert_eq!(truncate_max("\u{10ffff}a".as_bytes(), 4, true), None);
+    }
+
+    #[test]
+    fn truncate_binary() {
+        assert_eq!(truncate_min(b"abcd", 2, false), b"ab");
+        assert_eq!(truncate_max(b"ab\xffc", 3, false).unwrap(), b"ac");
+        assert_eq!(truncate_max(b"\xff\xffa", 2, false), None);
+    }
 }
@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::sync::Arc;
 
 use arrow_array::cast::AsArray;
@@ -5,17 +6,23 @@ use arrow_array::types::ByteViewType;
 use arrow_array::{Datum, GenericByteViewArray};
 use arrow_ord::cmp;
 use arrow_schema::DataType;
+use num_traits::AsPrimitive;
 use vortex_buffer::Buffer;
+use vortex_dtype::match_each_integer_ptype;
 use vortex_error::{vortex_bail, VortexResult, VortexUnwrap};
 use vortex_scalar::Scalar;
 
-use crate::array::varbin::varbin_scalar;
-use crate::array::varbinview::{VarBinViewArray, VIEW_SIZE_BYTES};
-use crate::array::{varbinview_as_arrow, ConstantArray};
+use crate::array::varbin::{varbin_needle, varbin_scalar};
+use crate::array::varbinview::{BinaryView, VarBinViewArray, VIEW_SIZE_BYTES};
+use crate::array::{ConstantArray, PrimitiveArray};
 use crate::arrow::FromArrowArray;
 use crate::compute::unary::ScalarAtFn;
-use crate::compute::{slice, ArrayCompute, MaybeCompareFn, Operator, SliceFn, TakeFn};
-use crate::{Array, ArrayDType, IntoArray, IntoCanonical};
+use crate::compute::{
+    slice, ArrayCompute, IndexOrd, Len, MaybeCompareFn, Operator, SearchResult, SearchSorted,
+    SearchSortedFn, SearchSortedSide, SliceFn, TakeFn,
+};
+use crate::validity::Validity;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
 
 impl ArrayCompute for VarBinViewArray {
     fn compare(&self, other: &Array, operator: Operator) -> Option<VortexResult<Array>> {
@@ -26,6 +33,10 @@ impl ArrayCompute for VarBinViewArray {
         Some(self)
     }
 
+    fn search_sorted(&self) -> Option<&dyn SearchSortedFn> {
+        Some(self)
+    }
+
     fn slice(&self) -> Option<&dyn SliceFn> {
         Some(self)
     }
@@ -67,12 +78,94 @@ impl SliceFn for VarBinViewArray {
 /// Take involves creating a new array that references the old array, just with the given set of views.
 impl TakeFn for VarBinViewArray {
     fn take(&self, indices: &Array) -> VortexResult<Array> {
-        let array_ref = varbinview_as_arrow(self);
-        let indices_arrow = indices.clone().into_canonical()?.into_arrow()?;
+        let views = self.views().into_primitive()?;
+        let indices = indices.clone().into_primitive()?;
+        let taken_views = match_each_integer_ptype!(indices.ptype(), |$I| {
+            take_views(views.maybe_null_slice::<u8>(), indices.maybe_null_slice::<$I>())?
+        });
 
-        let take_arrow = arrow_select::take::take(&array_ref, &indices_arrow, None)?;
-        let nullable = take_arrow.is_nullable();
-        Ok(Array::from_arrow(take_arrow, nullable))
+        Ok(Self::try_new(
+            PrimitiveArray::from(taken_views).into_array(),
+            self.buffers().collect(),
+            self.dtype().clone(),
+            self.validity().take(indices.as_ref())?,
+        )?
+        .into_array())
+    }
+}
+
+fn take_views<I: AsPrimitive<usize>>(views: &[u8], indices: &[I]) -> VortexResult<Vec<u8>> {
+    let len = views.len() / VIEW_SIZE_BYTES;
+    let mut taken = Vec::with_capacity(indices.len() * VIEW_SIZE_BYTES);
+    for idx in indices {
+        let idx = idx.as_();
+        if idx >= len {
+            vortex_bail!(OutOfBounds: idx, 0, len);
+        }
+        let start = idx * VIEW_SIZE_BYTES;
+        taken.extend_from_slice(&views[start..start + VIEW_SIZE_BYTES]);
+    }
+    Ok(taken)
+}
+
+impl SearchSortedFn for VarBinViewArray {
+    fn search_sorted(&self, value: &Scalar, side: SearchSortedSide) -> VortexResult<SearchResult> {
+        let needle = varbin_needle(&value.cast(self.dtype())?)?;
+        let views = self.views().into_primitive()?;
+        let buffers = self
+            .buffers()
+            .map(|b| b.into_primitive())
+            .collect::<VortexResult<Vec<_>>>()?;
+
+        Ok(SearchSortedVarBinView {
+            views: views.maybe_null_slice::<u8>(),
+            buffers: &buffers,
+            validity: self.validity(),
+        }
+        .search_sorted(&needle.as_slice(), side))
+    }
+}
+
+/// Byte-wise ordering of the values of a [VarBinViewArray], with nulls sorted last.
+///
+/// Inlined values are compared straight from their view, and out-of-line values are first
+/// compared on their 4-byte prefix so the data buffers are only touched on a prefix match.
+struct SearchSortedVarBinView<'a> {
+    views: &'a [u8],
+    buffers: &'a [PrimitiveArray],
+    validity: Validity,
+}
+
+impl IndexOrd<&[u8]> for SearchSortedVarBinView<'_> {
+    fn index_cmp(&self, idx: usize, elem: &&[u8]) -> Option<Ordering> {
+        if self.validity.is_null(idx) {
+            return Some(Ordering::Greater);
+        }
+
+        let mut le_bytes = [0u8; VIEW_SIZE_BYTES];
+        le_bytes.copy_from_slice(&self.views[idx * VIEW_SIZE_BYTES..][..VIEW_SIZE_BYTES]);
+        let view = BinaryView { le_bytes };
+        if view.is_inlined() {
+            return Some(view.as_inlined().value().cmp(elem));
+        }
+
+        let view_ref = view.as_view();
+        let prefix_len = elem.len().min(view_ref.prefix().len());
+        match view_ref.prefix()[..prefix_len].cmp(&elem[..prefix_len]) {
+            Ordering::Equal => {
+                let start = view_ref.offset() as usize;
+                let bytes = &self.buffers[view_ref.buffer_index() as usize]
+                    .maybe_null_slice::<u8>()[start..start + view.len() as usize];
+                Some(bytes.cmp(elem))
+            }
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl Len for SearchSortedVarBinView<'_> {
+    fn len(&self) -> usize {
+        self.views.len() / VIEW_SIZE_BYTES
     }
 }
 
@@ -130,9 +223,10 @@ mod tests {
     use vortex_scalar::Scalar;
 
     use crate::array::varbinview::compute::compare_constant;
-    use crate::array::{ConstantArray, VarBinViewArray};
-    use crate::compute::Operator;
-    use crate::IntoArrayVariant;
+    use crate::array::{ConstantArray, PrimitiveArray, VarBinViewArray};
+    use crate::compute::unary::scalar_at;
+    use crate::compute::{search_sorted, take, Operator, SearchResult, SearchSortedSide};
+    use crate::{IntoArray, IntoArrayVariant};
 
     #[test]
     fn basic_test() {
@@ -156,4 +250,94 @@ mod tests {
 
         assert!(r.boolean_buffer().iter().all(|v| !v));
     }
+
+    #[test]
+    fn take_views() {
+        let arr = VarBinViewArray::from_iter_nullable_str([
+            Some("short"),
+            None,
+            Some("a string that is longer than twelve bytes"),
+            Some("x"),
+        ]);
+        let indices = PrimitiveArray::from(vec![2u32, 0, 1, 2]).into_array();
+
+        let taken = take(arr.as_ref(), &indices).unwrap();
+        let taken_view = VarBinViewArray::try_from(&taken).unwrap();
+        assert_eq!(taken_view.buffer_count(), arr.buffer_count());
+        assert_eq!(
+            scalar_at(&taken, 0).unwrap(),
+            Scalar::utf8(
+                "a string that is longer than twelve bytes".to_string(),
+                Nullability::Nullable
+            )
+        );
+        assert_eq!(
+            scalar_at(&taken, 1).unwrap(),
+            Scalar::utf8("short".to_string(), Nullability::Nullable)
+        );
+        assert!(scalar_at(&taken, 2).unwrap().is_null());
+        assert_eq!(
+            scalar_at(&taken, 3).unwrap(),
+            Scalar::utf8(
+                "a string that is longer than twelve bytes".to_string(),
+                Nullability::Nullable
+            )
+        );
+
+        let out_of_bounds = PrimitiveArray::from(vec![0u32, 4]).into_array();
+        assert!(take(arr.as_ref(), &out_of_bounds).is_err());
+    }
+
+    #[test]
+    fn search_sorted_views() {
+        let arr = VarBinViewArray::from_iter_nullable_str([
+            Some("Zebra"),
+            Some("a string that is longer than twelve bytes"),
+            Some("a string that is longer than twelve bytes"),
+            Some("a string that is much longer than twelve bytes"),
+            Some("apple"),
+            Some("banana"),
+            None,
+        ])
+        .into_array();
+
+        assert_eq!(
+            search_sorted(
+                &arr,
+                "a string that is longer than twelve bytes",
+                SearchSortedSide::Left
+            )
+            .unwrap(),
+            SearchResult::Found(1)
+        );
+        assert_eq!(
+            search_sorted(
+                &arr,
+                "a string that is longer than twelve bytes",
+                SearchSortedSide::Right
+            )
+            .unwrap(),
+            SearchResult::Found(3)
+        );
+        assert_eq!(
+            search_sorted(&arr, "a string", SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(1)
+        );
+        assert_eq!(
+            search_sorted(&arr, "a", SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(1)
+        );
+        assert_eq!(
+            search_sorted(&arr, "A", SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(0)
+        );
+        assert_eq!(
+            search_sorted(&arr, "banana", SearchSortedSide::Right).unwrap(),
+            SearchResult::Found(6)
+        );
+        assert_eq!(
+            search_sorted(&arr, "cherry", SearchSortedSide::Left).unwrap(),
+            SearchResult::NotFound(6)
+        );
+    }
 }
@@ -1,16 +1,33 @@
 use vortex_error::VortexResult;
 
 use crate::accessor::ArrayAccessor;
-use crate::array::varbin::compute_stats;
+use crate::array::varbin::{compute_approx_distinct, compute_bloom_filter, compute_stats};
 use crate::array::varbinview::VarBinViewArray;
-use crate::stats::{ArrayStatisticsCompute, Stat, StatsSet};
-use crate::ArrayDType;
+use crate::stats::{ArrayStatisticsCompute, Stat, StatsOptions, StatsSet};
+use crate::{ArrayDType, ArrayTrait};
 
 impl ArrayStatisticsCompute for VarBinViewArray {
-    fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
+    fn compute_statistics(&self, stat: Stat) -> VortexResult<StatsSet> {
+        self.compute_statistics_with_options(stat, &StatsOptions::default())
+    }
+
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        if stat == Stat::UncompressedSizeInBytes {
+            return Ok(StatsSet::of(stat, self.nbytes().into()));
+        }
         if self.is_empty() {
             return Ok(StatsSet::new());
         }
-        self.with_iterator(|iter| compute_stats(iter, self.dtype()))
+        if stat == Stat::ApproxDistinct {
+            return self.with_iterator(compute_approx_distinct);
+        }
+        if stat == Stat::BloomFilter {
+            return self.with_iterator(|iter| compute_bloom_filter(iter, self.len()));
+        }
+        self.with_iterator(|iter| compute_stats(iter, self.dtype(), options))
     }
 }
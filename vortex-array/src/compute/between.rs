@@ -0,0 +1,347 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
+use vortex_dtype::{
+    match_each_integer_ptype, match_each_native_ptype, DType, NativePType, Nullability,
+};
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::array::{BoolArray, ConstantArray, PrimitiveArray};
+use crate::compute::{and, compare, search_sorted, Operator, SearchSortedSide};
+use crate::stats::{ArrayStatistics, Stat};
+use crate::validity::Validity;
+use crate::{Array, ArrayDType, Canonical, IntoArray, IntoCanonical};
+
+/// Which ends of the range are included by [between].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Inclusivity {
+    Both,
+    Lower,
+    Upper,
+    Neither,
+}
+
+impl Display for Inclusivity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let display = match self {
+            Inclusivity::Both => "[]",
+            Inclusivity::Lower => "[)",
+            Inclusivity::Upper => "(]",
+            Inclusivity::Neither => "()",
+        };
+        Display::fmt(display, f)
+    }
+}
+
+impl Inclusivity {
+    pub fn new(lower: bool, upper: bool) -> Self {
+        match (lower, upper) {
+            (true, true) => Inclusivity::Both,
+            (true, false) => Inclusivity::Lower,
+            (false, true) => Inclusivity::Upper,
+            (false, false) => Inclusivity::Neither,
+        }
+    }
+
+    pub fn includes_lower(self) -> bool {
+        matches!(self, Inclusivity::Both | Inclusivity::Lower)
+    }
+
+    pub fn includes_upper(self) -> bool {
+        matches!(self, Inclusivity::Both | Inclusivity::Upper)
+    }
+
+    /// The operator comparing values against the lower bound.
+    pub fn lower_operator(self) -> Operator {
+        if self.includes_lower() {
+            Operator::Gte
+        } else {
+            Operator::Gt
+        }
+    }
+
+    /// The operator comparing values against the upper bound.
+    pub fn upper_operator(self) -> Operator {
+        if self.includes_upper() {
+            Operator::Lte
+        } else {
+            Operator::Lt
+        }
+    }
+}
+
+pub trait BetweenFn {
+    /// Return a boolean array marking which elements of the receiver lie between the bounds.
+    fn between(
+        &self,
+        lower: &Scalar,
+        upper: &Scalar,
+        inclusivity: Inclusivity,
+    ) -> VortexResult<Array>;
+}
+
+/// Test whether each element of `array` lies between `lower` and `upper`.
+///
+/// This is equivalent to comparing against both bounds and combining the results with [and], but
+/// encodings can evaluate the whole range in a single pass. Sorted arrays are answered with two
+/// binary searches. Null elements of `array` produce null results, and a null bound makes every
+/// result null.
+pub fn between(
+    array: impl AsRef<Array>,
+    lower: &Scalar,
+    upper: &Scalar,
+    inclusivity: Inclusivity,
+) -> VortexResult<Array> {
+    let array = array.as_ref();
+    for bound in [lower, upper] {
+        if !array.dtype().eq_ignore_nullability(bound.dtype()) {
+            vortex_bail!(MismatchedTypes: array.dtype(), bound.dtype());
+        }
+    }
+
+    if lower.is_null() || upper.is_null() {
+        return Ok(ConstantArray::new(
+            Scalar::null(DType::Bool(Nullability::Nullable)),
+            array.len(),
+        )
+        .into_array());
+    }
+
+    if let Some(result) =
+        array.with_dyn(|a| a.between().map(|f| f.between(lower, upper, inclusivity)))
+    {
+        return result;
+    }
+
+    if is_sorted(array) {
+        return between_sorted(array, lower, upper, inclusivity);
+    }
+
+    match array.clone().into_canonical()? {
+        Canonical::Primitive(p) => match_each_native_ptype!(p.ptype(), |$T| {
+            between_primitive::<$T>(&p, lower, upper, inclusivity)
+        }),
+        canonical => {
+            let array = Array::from(canonical);
+            and(
+                compare(
+                    &array,
+                    ConstantArray::new(lower.clone(), array.len()),
+                    inclusivity.lower_operator(),
+                )?,
+                compare(
+                    &array,
+                    ConstantArray::new(upper.clone(), array.len()),
+                    inclusivity.upper_operator(),
+                )?,
+            )
+        }
+    }
+}
+
+/// Whether the array is known to be sorted, without nulls, and can be binary searched.
+fn is_sorted(array: &Array) -> bool {
+    // Float arrays are sorted ignoring NaNs, which still take part in comparisons.
+    let stats = array.statistics();
+    !array.dtype().is_float()
+        && stats.get_as::<bool>(Stat::IsSorted) == Some(true)
+        && (!array.dtype().is_nullable() || stats.get_as::<u64>(Stat::NullCount) == Some(0))
+        && array.with_dyn(|a| a.search_sorted().is_some())
+}
+
+/// Select the range of a sorted array between two binary searches.
+fn between_sorted(
+    array: &Array,
+    lower: &Scalar,
+    upper: &Scalar,
+    inclusivity: Inclusivity,
+) -> VortexResult<Array> {
+    let lower_side = if inclusivity.includes_lower() {
+        SearchSortedSide::Left
+    } else {
+        SearchSortedSide::Right
+    };
+    let upper_side = if inclusivity.includes_upper() {
+        SearchSortedSide::Right
+    } else {
+        SearchSortedSide::Left
+    };
+
+    let start = search_sorted(array, lower.clone(), lower_side)?.to_index();
+    let end = search_sorted(array, upper.clone(), upper_side)?
+        .to_index()
+        .max(start);
+
+    let mut buffer = BooleanBufferBuilder::new(array.len());
+    buffer.append_n(start, false);
+    buffer.append_n(end - start, true);
+    buffer.append_n(array.len() - end, false);
+    let validity = match array.dtype().nullability() {
+        Nullability::NonNullable => Validity::NonNullable,
+        Nullability::Nullable => Validity::AllValid,
+    };
+    BoolArray::try_new(buffer.finish(), validity).map(IntoArray::into_array)
+}
+
+fn between_primitive<T>(
+    array: &PrimitiveArray,
+    lower: &Scalar,
+    upper: &Scalar,
+    inclusivity: Inclusivity,
+) -> VortexResult<Array>
+where
+    T: NativePType + for<'a> TryFrom<&'a Scalar, Error = VortexError>,
+{
+    let lower = T::try_from(lower)?;
+    let upper = T::try_from(upper)?;
+    // Use the same total order as the comparison kernels, so that NaN sorts above every number.
+    let above_lower = |v: T| match v.compare(lower) {
+        Ordering::Greater => true,
+        Ordering::Equal => inclusivity.includes_lower(),
+        Ordering::Less => false,
+    };
+    let below_upper = |v: T| match v.compare(upper) {
+        Ordering::Less => true,
+        Ordering::Equal => inclusivity.includes_upper(),
+        Ordering::Greater => false,
+    };
+
+    let values = array.maybe_null_slice::<T>();
+    let buffer = BooleanBuffer::collect_bool(values.len(), |i| {
+        above_lower(values[i]) && below_upper(values[i])
+    });
+    BoolArray::try_new(buffer, array.validity()).map(IntoArray::into_array)
+}
+
+/// The integers selected by an integer range, as an inclusive range that is empty when no value
+/// can match.
+///
+/// This lets encodings of integers translate the bounds into their encoded domain without
+/// worrying about overflow or about which ends are included.
+pub fn integer_bounds(
+    lower: &Scalar,
+    upper: &Scalar,
+    inclusivity: Inclusivity,
+) -> VortexResult<RangeInclusive<i128>> {
+    let as_i128 = |bound: &Scalar| -> VortexResult<i128> {
+        let DType::Primitive(ptype, _) = bound.dtype() else {
+            vortex_bail!("Expected an integer bound, got {}", bound.dtype());
+        };
+        if !ptype.is_int() {
+            vortex_bail!("Expected an integer bound, got {}", bound.dtype());
+        }
+        match_each_integer_ptype!(ptype, |$T| {
+            Ok(i128::from($T::try_from(bound)?))
+        })
+    };
+
+    let lower = as_i128(lower)? + i128::from(!inclusivity.includes_lower());
+    let upper = as_i128(upper)? - i128::from(!inclusivity.includes_upper());
+    Ok(lower..=upper)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::accessor::ArrayAccessor;
+    use crate::array::{PrimitiveArray, VarBinViewArray};
+    use crate::compute::{between, integer_bounds, Inclusivity};
+    use crate::stats::ArrayStatistics;
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn between_primitive() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), Some(5), None, Some(9), Some(10)])
+                .into_array();
+        let (lower, upper) = (Scalar::from(5i32), Scalar::from(10i32));
+
+        assert_eq!(
+            between(&array, &lower, &upper, Inclusivity::Both)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .with_iterator(|iter| iter.map(|v| v.copied()).collect::<Vec<_>>())
+                .unwrap(),
+            vec![Some(false), Some(true), None, Some(true), Some(true)]
+        );
+        assert_eq!(
+            between(&array, &lower, &upper, Inclusivity::Neither)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .with_iterator(|iter| iter.map(|v| v.copied()).collect::<Vec<_>>())
+                .unwrap(),
+            vec![Some(false), Some(false), None, Some(true), Some(false)]
+        );
+    }
+
+    #[test]
+    fn between_sorted() {
+        let array = PrimitiveArray::from(vec![1u64, 3, 3, 5, 7, 7, 9]).into_array();
+        assert!(array.statistics().compute_is_sorted().unwrap());
+
+        assert_eq!(
+            between(&array, &3u64.into(), &7u64.into(), Inclusivity::Lower)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            [false, true, true, true, false, false, false]
+        );
+        assert_eq!(
+            between(&array, &3u64.into(), &7u64.into(), Inclusivity::Upper)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            [false, false, false, true, true, true, false]
+        );
+        assert_eq!(
+            between(&array, &8u64.into(), &2u64.into(), Inclusivity::Both)
+                .unwrap()
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            [false; 7]
+        );
+    }
+
+    #[test]
+    fn between_strings() {
+        let array = VarBinViewArray::from_iter_str(["apple", "fig", "kiwi", "pear"]).into_array();
+        let result = between(&array, &"banana".into(), &"kiwi".into(), Inclusivity::Both).unwrap();
+        assert_eq!(
+            result
+                .into_bool()
+                .unwrap()
+                .boolean_buffer()
+                .iter()
+                .collect::<Vec<_>>(),
+            [false, true, true, false]
+        );
+    }
+
+    #[test]
+    fn integer_bounds_inclusivity() {
+        let (lower, upper) = (Scalar::from(u64::MAX - 1), Scalar::from(u64::MAX));
+        assert_eq!(
+            integer_bounds(&lower, &upper, Inclusivity::Neither).unwrap(),
+            i128::from(u64::MAX)..=i128::from(u64::MAX) - 1
+        );
+        assert_eq!(
+            integer_bounds(&Scalar::from(-3i8), &Scalar::from(4i8), Inclusivity::Lower).unwrap(),
+            -3..=3
+        );
+    }
+}
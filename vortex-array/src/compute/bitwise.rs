@@ -0,0 +1,193 @@
+use core::fmt;
+use std::fmt::{Display, Formatter};
+
+use num_traits::{AsPrimitive, PrimInt, WrappingShl, WrappingShr};
+use vortex_dtype::match_each_integer_ptype;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::PrimitiveArray;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BitwiseOperator {
+    And,
+    Or,
+    Xor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl Display for BitwiseOperator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let display = match &self {
+            BitwiseOperator::And => "&",
+            BitwiseOperator::Or => "|",
+            BitwiseOperator::Xor => "^",
+            BitwiseOperator::ShiftLeft => "<<",
+            BitwiseOperator::ShiftRight => ">>",
+        };
+        Display::fmt(display, f)
+    }
+}
+
+impl BitwiseOperator {
+    /// Whether the result is unchanged when the operands are swapped.
+    pub fn is_commutative(self) -> bool {
+        matches!(
+            self,
+            BitwiseOperator::And | BitwiseOperator::Or | BitwiseOperator::Xor
+        )
+    }
+
+    /// Apply the operator to two native integers.
+    ///
+    /// Shift amounts are taken modulo the bit width of `T`, matching Arrow's shift kernels.
+    pub fn apply<T>(self, lhs: T, rhs: T) -> T
+    where
+        T: PrimInt + WrappingShl + WrappingShr + AsPrimitive<u32>,
+    {
+        match self {
+            BitwiseOperator::And => lhs & rhs,
+            BitwiseOperator::Or => lhs | rhs,
+            BitwiseOperator::Xor => lhs ^ rhs,
+            BitwiseOperator::ShiftLeft => lhs.wrapping_shl(rhs.as_()),
+            BitwiseOperator::ShiftRight => lhs.wrapping_shr(rhs.as_()),
+        }
+    }
+}
+
+/// Apply a bitwise operator element-wise over two integer arrays of the same type.
+///
+/// The result is null wherever either input is null.
+pub fn bitwise(
+    lhs: impl AsRef<Array>,
+    rhs: impl AsRef<Array>,
+    operator: BitwiseOperator,
+) -> VortexResult<Array> {
+    let lhs = lhs.as_ref();
+    let rhs = rhs.as_ref();
+
+    if lhs.len() != rhs.len() {
+        vortex_bail!("Bitwise operations aren't supported on arrays of different lengths")
+    }
+    if !lhs.dtype().is_int() || !lhs.dtype().eq_ignore_nullability(rhs.dtype()) {
+        vortex_bail!(
+            "Bitwise operations are only supported on integer arrays of the same type, got {} and {}",
+            lhs.dtype(),
+            rhs.dtype()
+        )
+    }
+
+    if let Some(result) = lhs.with_dyn(|a| a.bitwise(rhs, operator)) {
+        return result;
+    }
+
+    if operator.is_commutative() {
+        if let Some(result) = rhs.with_dyn(|a| a.bitwise(lhs, operator)) {
+            return result;
+        }
+    }
+
+    let lhs = lhs.clone().into_primitive()?;
+    let rhs = rhs.clone().into_primitive()?;
+    let validity = lhs.validity().and(rhs.validity())?;
+    match_each_integer_ptype!(lhs.ptype(), |$T| {
+        let values = lhs
+            .maybe_null_slice::<$T>()
+            .iter()
+            .zip(rhs.maybe_null_slice::<$T>())
+            .map(|(&l, &r)| operator.apply(l, r))
+            .collect::<Vec<_>>();
+        Ok(PrimitiveArray::from_vec(values, validity).into_array())
+    })
+}
+
+/// Count the number of set bits in each element of an integer array, returned as `u8`.
+pub fn popcount(array: impl AsRef<Array>) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if !array.dtype().is_int() {
+        vortex_bail!(
+            "popcount is only supported on integer arrays, got {}",
+            array.dtype()
+        )
+    }
+
+    let array = array.clone().into_primitive()?;
+    let counts = match_each_integer_ptype!(array.ptype(), |$T| {
+        array
+            .maybe_null_slice::<$T>()
+            .iter()
+            .map(|v| v.count_ones() as u8)
+            .collect::<Vec<_>>()
+    });
+    Ok(PrimitiveArray::from_vec(counts, array.validity()).into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::accessor::ArrayAccessor;
+    use crate::array::{ConstantArray, PrimitiveArray};
+    use crate::compute::{bitwise, popcount, BitwiseOperator};
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn bitwise_arrays() {
+        let lhs = PrimitiveArray::from_nullable_vec(vec![Some(0b1100i32), None, Some(-1)]);
+        let rhs = PrimitiveArray::from(vec![0b1010i32, 1, 4]);
+        let cases = [
+            (BitwiseOperator::And, vec![Some(0b1000), None, Some(4)]),
+            (BitwiseOperator::Or, vec![Some(0b1110), None, Some(-1)]),
+            (BitwiseOperator::Xor, vec![Some(0b0110), None, Some(-5)]),
+            (
+                BitwiseOperator::ShiftLeft,
+                vec![Some(0b1100 << 10), None, Some(-16)],
+            ),
+            (
+                BitwiseOperator::ShiftRight,
+                vec![Some(0b1100 >> 10), None, Some(-1)],
+            ),
+        ];
+        for (op, expected) in cases {
+            let result = bitwise(lhs.as_ref(), rhs.as_ref(), op)
+                .unwrap()
+                .into_primitive()
+                .unwrap();
+            assert_eq!(
+                ArrayAccessor::<i32>::with_iterator(&result, |iter| iter
+                    .map(|v| v.copied())
+                    .collect::<Vec<_>>())
+                .unwrap(),
+                expected,
+                "{op}"
+            );
+        }
+    }
+
+    #[test]
+    fn bitwise_constants() {
+        let lhs = ConstantArray::new(0b0110i32, 3).into_array();
+        let rhs = ConstantArray::new(0b0011i32, 3).into_array();
+        let result = bitwise(&lhs, &rhs, BitwiseOperator::Xor).unwrap();
+        assert!(ConstantArray::try_from(&result).is_ok());
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[0b0101; 3]
+        );
+    }
+
+    #[test]
+    fn bitwise_mismatched() {
+        let lhs = PrimitiveArray::from(vec![1i32]);
+        let rhs = PrimitiveArray::from(vec![1u32]);
+        assert!(bitwise(lhs.as_ref(), rhs.as_ref(), BitwiseOperator::And).is_err());
+    }
+
+    #[test]
+    fn popcount_values() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(0u64), None, Some(u64::MAX), Some(5)]);
+        let result = popcount(array.as_ref()).unwrap().into_primitive().unwrap();
+        assert_eq!(result.maybe_null_slice::<u8>()[2..], [64, 2]);
+        assert!(result.validity().is_null(1));
+    }
+}
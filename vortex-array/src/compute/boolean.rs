@@ -1,6 +1,11 @@
+use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
 
-use crate::{Array, ArrayDType, IntoArrayVariant};
+use crate::array::{Bool, ConstantArray};
+use crate::compute::unary::scalar_at;
+use crate::stats::{ArrayStatistics, Stat};
+use crate::{Array, ArrayDType, ArrayDef, IntoArray, IntoArrayVariant};
 
 pub trait AndFn {
     fn and(&self, array: &Array) -> VortexResult<Array>;
@@ -22,6 +27,16 @@ pub fn and(lhs: impl AsRef<Array>, rhs: impl AsRef<Array>) -> VortexResult<Array
         vortex_bail!("Boolean operations are only supported on boolean arrays")
     }
 
+    if let Some(result) = short_circuit(lhs, rhs, false)? {
+        return Ok(result);
+    }
+
+    // A canonical left-hand side would decode the other side, so let an encoded right-hand side
+    // try its own implementation first.
+    if lhs.is_encoding(Bool::ID) && !rhs.is_encoding(Bool::ID) {
+        return and(rhs, lhs);
+    }
+
     if let Some(selection) = lhs.with_dyn(|lhs| lhs.and().map(|lhs| lhs.and(rhs))) {
         return selection;
     }
@@ -48,6 +63,16 @@ pub fn or(lhs: impl AsRef<Array>, rhs: impl AsRef<Array>) -> VortexResult<Array>
         vortex_bail!("Boolean operations are only supported on boolean arrays")
     }
 
+    if let Some(result) = short_circuit(lhs, rhs, true)? {
+        return Ok(result);
+    }
+
+    // A canonical left-hand side would decode the other side, so let an encoded right-hand side
+    // try its own implementation first.
+    if lhs.is_encoding(Bool::ID) && !rhs.is_encoding(Bool::ID) {
+        return or(rhs, lhs);
+    }
+
     if let Some(selection) = lhs.with_dyn(|lhs| lhs.or().map(|lhs| lhs.or(rhs))) {
         return selection;
     }
@@ -62,6 +87,51 @@ pub fn or(lhs: impl AsRef<Array>, rhs: impl AsRef<Array>) -> VortexResult<Array>
     lhs.or(rhs)
 }
 
+/// Skip evaluating a boolean operation when either side is constant.
+///
+/// A side holding the `absorbing` value (`false` for `and`, `true` for `or`) decides the result
+/// wherever the other side is valid, a side holding the other value leaves the other side
+/// unchanged, and a null side makes the whole result null.
+fn short_circuit(lhs: &Array, rhs: &Array, absorbing: bool) -> VortexResult<Option<Array>> {
+    let nullability = Nullability::from(lhs.dtype().is_nullable() || rhs.dtype().is_nullable());
+    for (constant, other) in [(lhs, rhs), (rhs, lhs)] {
+        let Some(value) = constant_value(constant)? else {
+            continue;
+        };
+
+        let result = match value.value().as_bool()? {
+            None => {
+                ConstantArray::new(Scalar::null(DType::Bool(nullability)), lhs.len()).into_array()
+            }
+            Some(value) if value == absorbing => {
+                let other_nulls = other.dtype().is_nullable()
+                    && other.statistics().get_as::<u64>(Stat::NullCount) != Some(0);
+                if other_nulls {
+                    continue;
+                }
+                ConstantArray::new(Scalar::bool(absorbing, nullability), lhs.len()).into_array()
+            }
+            // Keep the nullability of the result consistent with the other kernels.
+            Some(_) if other.dtype().nullability() == nullability => other.clone(),
+            Some(_) => continue,
+        };
+        return Ok(Some(result));
+    }
+    Ok(None)
+}
+
+/// The value of every element of a constant array, using the cached `IsConstant` statistic for
+/// encodings other than [ConstantArray].
+fn constant_value(array: &Array) -> VortexResult<Option<Scalar>> {
+    if let Ok(constant) = ConstantArray::try_from(array) {
+        return Ok(Some(constant.owned_scalar()));
+    }
+    if array.is_empty() || array.statistics().get_as::<bool>(Stat::IsConstant) != Some(true) {
+        return Ok(None);
+    }
+    scalar_at(array, 0).map(Some)
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -112,4 +182,32 @@ mod tests {
         assert!(!v2.unwrap());
         assert!(!v3.unwrap());
     }
+
+    #[test]
+    fn constant_short_circuit() {
+        let values = BoolArray::from(vec![true, false, true]).into_array();
+
+        let all_false = and(&values, ConstantArray::new(false, 3)).unwrap();
+        let all_false = ConstantArray::try_from(all_false).unwrap();
+        assert_eq!(all_false.scalar_value().as_bool().unwrap(), Some(false));
+
+        let unchanged = or(ConstantArray::new(false, 3), &values).unwrap();
+        assert!(unchanged.is_encoding(Bool::ID));
+        assert_eq!(
+            unchanged.into_bool().unwrap().boolean_buffer(),
+            values.clone().into_bool().unwrap().boolean_buffer()
+        );
+
+        let nulls = or(
+            &values,
+            ConstantArray::new(Scalar::null(DType::Bool(Nullability::Nullable)), 3),
+        )
+        .unwrap();
+        assert!(scalar_at(&nulls, 0).unwrap().is_null());
+
+        // An absorbing constant can't hide nulls on the other side.
+        let nullable = BoolArray::from_iter([Some(true), None, Some(false)]).into_array();
+        let result = and(&nullable, ConstantArray::new(false, 3)).unwrap();
+        assert!(scalar_at(&result, 1).unwrap().is_null());
+    }
 }
@@ -6,9 +6,10 @@ use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, VortexResult};
 use vortex_scalar::Scalar;
 
-use crate::array::Constant;
+use crate::array::{Constant, ConstantArray};
 use crate::arrow::FromArrowArray;
-use crate::{Array, ArrayDType, ArrayDef, IntoCanonical};
+use crate::stats::{ArrayStatistics, Stat};
+use crate::{Array, ArrayDType, ArrayDef, IntoArray, IntoCanonical};
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd)]
 pub enum Operator {
@@ -99,6 +100,15 @@ pub fn compare(
         return compare(right, left, operator.swap());
     }
 
+    if let Ok(constant) = ConstantArray::try_from(right) {
+        if let Some(result) = compare_to_bounds(left, &constant.owned_scalar(), operator) {
+            let nullability = (left.dtype().is_nullable() || right.dtype().is_nullable()).into();
+            return Ok(
+                ConstantArray::new(Scalar::bool(result, nullability), left.len()).into_array(),
+            );
+        }
+    }
+
     if let Some(selection) = left.with_dyn(|lhs| lhs.compare(right, operator)) {
         return selection;
     }
@@ -123,6 +133,51 @@ pub fn compare(
     Ok(Array::from_arrow(&array, true))
 }
 
+/// The result of comparing every value of `array` to `value`, if the cached min and max
+/// statistics of the array decide it without looking at the values.
+fn compare_to_bounds(array: &Array, value: &Scalar, operator: Operator) -> Option<bool> {
+    // NaNs are excluded from the min and max of float arrays, but still take part in comparisons.
+    if value.is_null() || array.dtype().is_float() {
+        return None;
+    }
+
+    let stats = array.statistics();
+    if array.dtype().is_nullable() && stats.get_as::<u64>(Stat::NullCount) != Some(0) {
+        return None;
+    }
+    let min = stats.get(Stat::Min).filter(Scalar::is_valid)?;
+    let max = stats.get(Stat::Max).filter(Scalar::is_valid)?;
+    let to_min = value.partial_cmp(&min)?;
+    let to_max = value.partial_cmp(&max)?;
+
+    match operator {
+        Operator::Eq | Operator::NotEq => {
+            let eq = if to_min.is_lt() || to_max.is_gt() {
+                false
+            } else if to_min.is_eq()
+                && to_max.is_eq()
+                && stats.is_exact(Stat::Min)
+                && stats.is_exact(Stat::Max)
+            {
+                // Only exact bounds can show that every value is equal.
+                true
+            } else {
+                return None;
+            };
+            Some(eq == (operator == Operator::Eq))
+        }
+        Operator::Gt if to_min.is_lt() => Some(true),
+        Operator::Gt if to_max.is_ge() => Some(false),
+        Operator::Gte if to_min.is_le() => Some(true),
+        Operator::Gte if to_max.is_gt() => Some(false),
+        Operator::Lt if to_max.is_gt() => Some(true),
+        Operator::Lt if to_min.is_le() => Some(false),
+        Operator::Lte if to_max.is_ge() => Some(true),
+        Operator::Lte if to_min.is_lt() => Some(false),
+        _ => None,
+    }
+}
+
 pub fn scalar_cmp(lhs: &Scalar, rhs: &Scalar, operator: Operator) -> Scalar {
     if lhs.is_null() | rhs.is_null() {
         Scalar::null(DType::Bool(Nullability::Nullable))
@@ -146,7 +201,7 @@ mod tests {
     use vortex_scalar::ScalarValue;
 
     use super::*;
-    use crate::array::{BoolArray, ConstantArray};
+    use crate::array::{BoolArray, ConstantArray, PrimitiveArray};
     use crate::validity::Validity;
     use crate::{IntoArray, IntoArrayVariant};
 
@@ -231,4 +286,32 @@ mod tests {
         assert_eq!(res.scalar_value(), &ScalarValue::Bool(false));
         assert_eq!(res.len(), 10);
     }
+
+    #[test]
+    fn compare_outside_bounds() {
+        let array = PrimitiveArray::from(vec![10i32, 15, 20]).into_array();
+        array.statistics().compute_min::<i32>().unwrap();
+        array.statistics().compute_max::<i32>().unwrap();
+
+        let below = ConstantArray::new(5i32, 3).into_array();
+        let above = ConstantArray::new(25i32, 3).into_array();
+        for (rhs, operator, expected) in [
+            (&below, Operator::Eq, false),
+            (&below, Operator::NotEq, true),
+            (&below, Operator::Gt, true),
+            (&below, Operator::Lte, false),
+            (&above, Operator::Gte, false),
+            (&above, Operator::Lt, true),
+        ] {
+            let result = ConstantArray::try_from(compare(&array, rhs, operator).unwrap()).unwrap();
+            assert_eq!(result.scalar_value(), &ScalarValue::Bool(expected));
+            assert_eq!(result.len(), 3);
+        }
+
+        // Values inside the bounds still need the full comparison.
+        let inside = ConstantArray::new(15i32, 3).into_array();
+        let result = compare(&array, &inside, Operator::Eq).unwrap();
+        assert!(!result.is_encoding(Constant::ID));
+        assert_eq!(to_int_indices(result.into_bool().unwrap()), [1u64]);
+    }
 }
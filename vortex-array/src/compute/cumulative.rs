@@ -0,0 +1,325 @@
+use std::cmp::Ordering;
+
+use arrow_buffer::{BooleanBuffer, NullBuffer};
+use num_traits::ToPrimitive;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::PrimitiveArray;
+use crate::compute::unary::WrappingArith;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+pub trait CumsumFn {
+    /// Compute the running sum of the array.
+    fn cumsum(&self) -> VortexResult<Array>;
+}
+
+pub trait DiffFn {
+    /// Compute the differences between adjacent elements of the array.
+    fn diff(&self) -> VortexResult<Array>;
+}
+
+/// Running sum of a primitive array.
+///
+/// Integer sums wrap on overflow. Null elements do not contribute to the sum and remain null in
+/// the output.
+pub fn cumsum(array: impl AsRef<Array>) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if let Some(result) = array.with_dyn(|a| a.cumsum().map(|f| f.cumsum())) {
+        return result;
+    }
+
+    let array = into_primitive(array, "cumsum")?;
+    match_each_native_ptype!(array.ptype(), |$T| {
+        accumulate::<$T>(&array, |acc, v| acc.wrapping_add(v))
+    })
+}
+
+/// Running maximum of a primitive array, skipping nulls.
+pub fn cummax(array: impl AsRef<Array>) -> VortexResult<Array> {
+    let array = into_primitive(array.as_ref(), "cummax")?;
+    match_each_native_ptype!(array.ptype(), |$T| {
+        accumulate::<$T>(&array, |acc, v| {
+            if v.compare(acc) == Ordering::Greater { v } else { acc }
+        })
+    })
+}
+
+/// Running minimum of a primitive array, skipping nulls.
+pub fn cummin(array: impl AsRef<Array>) -> VortexResult<Array> {
+    let array = into_primitive(array.as_ref(), "cummin")?;
+    match_each_native_ptype!(array.ptype(), |$T| {
+        accumulate::<$T>(&array, |acc, v| {
+            if v.compare(acc) == Ordering::Less { v } else { acc }
+        })
+    })
+}
+
+/// Differences between adjacent elements, `array[i + 1] - array[i]`.
+///
+/// The result has one element fewer than the input, and is null wherever either operand is null.
+/// Integer differences wrap on overflow.
+pub fn diff(array: impl AsRef<Array>) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if let Some(result) = array.with_dyn(|a| a.diff().map(|f| f.diff())) {
+        return result;
+    }
+
+    let array = into_primitive(array, "diff")?;
+    match_each_native_ptype!(array.ptype(), |$T| {
+        diff_primitive::<$T>(&array)
+    })
+}
+
+/// Sum over a sliding window of `window` elements ending at each position.
+///
+/// Positions before the first full window, and windows containing a null, produce null.
+pub fn rolling_sum(array: impl AsRef<Array>, window: usize) -> VortexResult<Array> {
+    let array = into_primitive(array.as_ref(), "rolling_sum")?;
+    match_each_native_ptype!(array.ptype(), |$T| {
+        rolling::<$T, $T>(
+            &array,
+            window,
+            |sum, v| sum.wrapping_add(v),
+            |sum, v| sum.wrapping_sub(v),
+            |sum| sum,
+        )
+    })
+}
+
+/// Mean over a sliding window of `window` elements ending at each position, as `f64`.
+///
+/// Positions before the first full window, and windows containing a null, produce null.
+pub fn rolling_mean(array: impl AsRef<Array>, window: usize) -> VortexResult<Array> {
+    let array = into_primitive(array.as_ref(), "rolling_mean")?;
+    match_each_native_ptype!(array.ptype(), |$T| {
+        rolling::<$T, f64>(
+            &array,
+            window,
+            |sum, v| sum + ToPrimitive::to_f64(&v).unwrap_or_default(),
+            |sum, v| sum - ToPrimitive::to_f64(&v).unwrap_or_default(),
+            |sum| sum / window as f64,
+        )
+    })
+}
+
+fn into_primitive(array: &Array, name: &str) -> VortexResult<PrimitiveArray> {
+    if !matches!(array.dtype(), DType::Primitive(..)) {
+        vortex_bail!(NotImplemented: name.to_string(), array.dtype());
+    }
+    array.clone().into_primitive()
+}
+
+fn accumulate<T: WrappingArith>(
+    array: &PrimitiveArray,
+    f: impl Fn(T, T) -> T,
+) -> VortexResult<Array> {
+    let nulls = array.logical_validity().to_null_buffer()?;
+    let mut acc: Option<T> = None;
+    let values = array
+        .maybe_null_slice::<T>()
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| {
+            if is_valid(nulls.as_ref(), i) {
+                let next = acc.map_or(v, |acc| f(acc, v));
+                acc = Some(next);
+                next
+            } else {
+                acc.unwrap_or_default()
+            }
+        })
+        .collect::<Vec<_>>();
+    Ok(PrimitiveArray::from_vec(values, array.validity()).into_array())
+}
+
+fn diff_primitive<T: WrappingArith>(array: &PrimitiveArray) -> VortexResult<Array> {
+    if array.is_empty() {
+        return Ok(PrimitiveArray::from_vec(Vec::<T>::new(), array.validity()).into_array());
+    }
+
+    let diffs = array
+        .maybe_null_slice::<T>()
+        .windows(2)
+        .map(|pair| pair[1].wrapping_sub(pair[0]))
+        .collect::<Vec<_>>();
+    let validity = match array.logical_validity().to_null_buffer()? {
+        None => array.validity().slice(1, array.len())?,
+        Some(nulls) => {
+            let n = diffs.len();
+            Validity::from(&nulls.inner().slice(0, n) & &nulls.inner().slice(1, n))
+        }
+    };
+    Ok(PrimitiveArray::from_vec(diffs, validity).into_array())
+}
+
+/// Slide a window over the array, adding each value as it enters the window and subtracting it
+/// as it leaves, so every value is visited twice whatever the window size.
+fn rolling<T: NativePType, R: NativePType>(
+    array: &PrimitiveArray,
+    window: usize,
+    add: impl Fn(R, T) -> R,
+    sub: impl Fn(R, T) -> R,
+    finish: impl Fn(R) -> R,
+) -> VortexResult<Array> {
+    if window == 0 {
+        vortex_bail!("rolling window must be non-empty");
+    }
+
+    let nulls = array.logical_validity().to_null_buffer()?;
+    let values = array.maybe_null_slice::<T>();
+    let mut null_count = 0;
+    let mut sum = R::default();
+    let mut valid = Vec::with_capacity(values.len());
+    let mut result = Vec::with_capacity(values.len());
+    for (i, &value) in values.iter().enumerate() {
+        if is_valid(nulls.as_ref(), i) {
+            sum = add(sum, value);
+        } else {
+            null_count += 1;
+        }
+        if i >= window {
+            if is_valid(nulls.as_ref(), i - window) {
+                sum = sub(sum, values[i - window]);
+            } else {
+                null_count -= 1;
+            }
+        }
+
+        let full = i + 1 >= window && null_count == 0;
+        valid.push(full);
+        result.push(if full { finish(sum) } else { R::default() });
+    }
+    Ok(PrimitiveArray::from_vec(result, Validity::from(BooleanBuffer::from(valid))).into_array())
+}
+
+#[inline]
+fn is_valid(nulls: Option<&NullBuffer>, index: usize) -> bool {
+    nulls.map_or(true, |n| n.is_valid(index))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::accessor::ArrayAccessor;
+    use crate::array::PrimitiveArray;
+    use crate::compute::{cummax, cummin, cumsum, diff, rolling_mean, rolling_sum};
+    use crate::{ArrayDType, IntoArrayVariant};
+
+    #[test]
+    fn cumulative() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(-1), Some(5), Some(2)]);
+        let sum = cumsum(array.as_ref()).unwrap().into_primitive().unwrap();
+        assert_eq!(
+            ArrayAccessor::<i32>::with_iterator(&sum, |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            vec![Some(3), None, Some(2), Some(7), Some(9)]
+        );
+        let max = cummax(array.as_ref()).unwrap().into_primitive().unwrap();
+        assert_eq!(
+            ArrayAccessor::<i32>::with_iterator(&max, |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            vec![Some(3), None, Some(3), Some(5), Some(5)]
+        );
+        let min = cummin(array.as_ref()).unwrap().into_primitive().unwrap();
+        assert_eq!(
+            ArrayAccessor::<i32>::with_iterator(&min, |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            vec![Some(3), None, Some(-1), Some(-1), Some(-1)]
+        );
+    }
+
+    #[test]
+    fn cumsum_wraps() {
+        let array = PrimitiveArray::from(vec![200u8, 100]);
+        assert_eq!(
+            cumsum(array.as_ref())
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u8>(),
+            &[200, 44]
+        );
+    }
+
+    #[test]
+    fn diff_values() {
+        let array = PrimitiveArray::from(vec![1.5f64, 4.0, 3.0]);
+        let result = diff(array.as_ref()).unwrap();
+        assert!(!result.dtype().is_nullable());
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<f64>(),
+            &[2.5, -1.0]
+        );
+
+        let nullable = PrimitiveArray::from_nullable_vec(vec![Some(1u32), Some(4), None, Some(10)]);
+        let result = diff(nullable.as_ref()).unwrap().into_primitive().unwrap();
+        assert_eq!(
+            ArrayAccessor::<u32>::with_iterator(&result, |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            vec![Some(3), None, None]
+        );
+        assert!(diff(PrimitiveArray::from(Vec::<u32>::new()).as_ref())
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn rolling() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(1i64), Some(2), Some(3), None, Some(5)]);
+        let sum = rolling_sum(array.as_ref(), 2)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(
+            ArrayAccessor::<i64>::with_iterator(&sum, |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            vec![None, Some(3), Some(5), None, None]
+        );
+        let mean = rolling_mean(array.as_ref(), 3)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(
+            ArrayAccessor::<f64>::with_iterator(&mean, |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            vec![None, None, Some(2.0), None, None]
+        );
+        assert!(rolling_sum(array.as_ref(), 0).is_err());
+
+        // The window sum recovers once the null slides out of it.
+        let array = PrimitiveArray::from_nullable_vec(vec![
+            Some(1u8),
+            Some(2),
+            None,
+            Some(4),
+            Some(200),
+            Some(100),
+        ]);
+        let sum = rolling_sum(array.as_ref(), 2)
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(
+            ArrayAccessor::<u8>::with_iterator(&sum, |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            vec![None, Some(3), None, None, Some(204), Some(44)]
+        );
+    }
+}
@@ -1,52 +1,256 @@
-use arrow_array::cast::AsArray;
-use vortex_dtype::{DType, Nullability};
-use vortex_error::{vortex_bail, VortexResult};
+use std::sync::OnceLock;
 
+use arrow_array::BooleanArray;
+use arrow_buffer::{BooleanBuffer, BooleanBufferBuilder};
+use vortex_dtype::{match_each_integer_ptype, DType, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+
+use crate::array::PrimitiveArray;
 use crate::arrow::FromArrowArray;
-use crate::{Array, ArrayDType, IntoCanonical};
+use crate::compute::take;
+use crate::stats::{ArrayStatistics, Stat};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
+
+/// The selectivity above which selected ranges are cheaper to iterate than selected rows.
+// This is modeled after the constant with the equivalent name in arrow-rs.
+const FILTER_SLICES_SELECTIVITY_THRESHOLD: f64 = 0.8;
+
+/// The selectivity below which materializing the selected indices is worthwhile.
+const FILTER_INDICES_SELECTIVITY_THRESHOLD: f64 = 0.1;
 
 pub trait FilterFn {
-    /// Filter an array by the provided predicate.
-    fn filter(&self, predicate: &Array) -> VortexResult<Array>;
+    /// Filter an array by the provided mask.
+    fn filter(&self, mask: &FilterMask) -> VortexResult<Array>;
 }
 
-/// Return a new array by applying a boolean predicate to select items from a base Array.
-///
-/// # Performance
-///
-/// This function attempts to amortize the cost of copying
+/// A row selection over an array, decoded once from a boolean predicate.
 ///
-/// # Panics
-///
-/// The `predicate` must receive an Array with type non-nullable bool, and will panic if this is
-/// not the case.
-pub fn filter(array: impl AsRef<Array>, predicate: impl AsRef<Array>) -> VortexResult<Array> {
-    let array = array.as_ref();
-    let predicate = predicate.as_ref();
+/// The true count, the selected indices and the contiguous selected ranges are each computed
+/// lazily and cached, so that the same mask can be applied to many arrays (e.g. every field of a
+/// struct, or every column of a chunk) without re-deriving them.
+#[derive(Debug, Clone)]
+pub struct FilterMask {
+    buffer: BooleanBuffer,
+    true_count: OnceLock<usize>,
+    indices: OnceLock<Vec<usize>>,
+    slices: OnceLock<Vec<(usize, usize)>>,
+}
 
-    if predicate.dtype() != &DType::Bool(Nullability::NonNullable) {
-        vortex_bail!(
-            "predicate must be non-nullable bool, has dtype {}",
-            predicate.dtype(),
+/// The representation of a [FilterMask] best suited to its selectivity.
+#[derive(Debug, Clone, Copy)]
+pub enum FilterIter<'a> {
+    /// Sorted indices of the selected rows, for sparse selections.
+    Indices(&'a [usize]),
+    /// A bitmap of the selected rows, for selections that are neither sparse nor dense.
+    Bitmap(&'a BooleanBuffer),
+    /// Sorted, disjoint `[start, end)` ranges of selected rows, for dense selections.
+    Slices(&'a [(usize, usize)]),
+}
+
+impl FilterMask {
+    /// Create a mask of `len` rows selecting the given sorted, distinct indices.
+    ///
+    /// The indices are cached as given, so they must be strictly ascending. Use
+    /// [try_from_indices_array](Self::try_from_indices_array) for indices that are unchecked.
+    ///
+    /// # Panics
+    ///
+    /// If an index is not less than `len`.
+    pub fn from_indices(len: usize, indices: impl IntoIterator<Item = usize>) -> Self {
+        let indices = indices.into_iter().collect::<Vec<_>>();
+        debug_assert!(
+            indices.windows(2).all(|w| w[0] < w[1]),
+            "FilterMask indices must be sorted and distinct"
         );
+        let mut buffer = BooleanBufferBuilder::new(len);
+        buffer.append_n(len, false);
+        for &idx in &indices {
+            assert!(
+                idx < len,
+                "FilterMask index {idx} out of bounds for {len} rows"
+            );
+            buffer.set_bit(idx, true);
+        }
+
+        let mask = Self::from(buffer.finish());
+        let _ = mask.true_count.set(indices.len());
+        let _ = mask.indices.set(indices);
+        mask
+    }
+
+    /// Create a mask of `len` rows selecting the rows at the given integer indices, as would be
+    /// passed to [take].
+    ///
+    /// The indices may be in any order and contain duplicates, but a mask selects each row at most
+    /// once and in ascending order.
+    pub fn try_from_indices_array(len: usize, indices: &Array) -> VortexResult<Self> {
+        if !indices.dtype().is_int() || indices.dtype().is_nullable() {
+            vortex_bail!(
+                "Indices must be a non-nullable integer type, got {}",
+                indices.dtype()
+            );
+        }
+
+        let indices = indices.clone().into_primitive()?;
+        let mut selected = match_each_integer_ptype!(indices.ptype(), |$I| {
+            indices
+                .maybe_null_slice::<$I>()
+                .iter()
+                .map(|&idx| {
+                    usize::try_from(idx)
+                        .ok()
+                        .filter(|&idx| idx < len)
+                        .ok_or_else(|| vortex_err!(OutOfBounds: idx as usize, 0, len))
+                })
+                .collect::<VortexResult<Vec<_>>>()?
+        });
+        selected.sort_unstable();
+        selected.dedup();
+        Ok(Self::from_indices(len, selected))
+    }
+
+    /// The number of rows the mask applies to.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buffer.is_empty()
+    }
+
+    /// The number of selected rows.
+    pub fn true_count(&self) -> usize {
+        *self.true_count.get_or_init(|| self.buffer.count_set_bits())
     }
-    if predicate.len() != array.len() {
+
+    /// The fraction of rows that are selected.
+    pub fn selectivity(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        self.true_count() as f64 / self.len() as f64
+    }
+
+    /// The mask as a bitmap.
+    #[inline]
+    pub fn boolean_buffer(&self) -> &BooleanBuffer {
+        &self.buffer
+    }
+
+    /// The indices of the selected rows, in ascending order.
+    pub fn indices(&self) -> &[usize] {
+        self.indices
+            .get_or_init(|| self.buffer.set_indices().collect())
+    }
+
+    /// The `[start, end)` ranges of selected rows, in ascending order.
+    pub fn slices(&self) -> &[(usize, usize)] {
+        self.slices
+            .get_or_init(|| self.buffer.set_slices().collect())
+    }
+
+    /// The cheapest representation of the selection to iterate, given its selectivity.
+    pub fn iter(&self) -> FilterIter<'_> {
+        let selectivity = self.selectivity();
+        if selectivity > FILTER_SLICES_SELECTIVITY_THRESHOLD {
+            FilterIter::Slices(self.slices())
+        } else if selectivity < FILTER_INDICES_SELECTIVITY_THRESHOLD {
+            FilterIter::Indices(self.indices())
+        } else {
+            FilterIter::Bitmap(&self.buffer)
+        }
+    }
+
+    /// The indices of the selected rows as an array, suitable for [take].
+    pub fn to_indices_array(&self) -> Array {
+        PrimitiveArray::from(
+            self.indices()
+                .iter()
+                .map(|&idx| idx as u64)
+                .collect::<Vec<_>>(),
+        )
+        .into_array()
+    }
+
+    /// The mask restricted to the rows `[offset, offset + len)`.
+    pub fn slice(&self, offset: usize, len: usize) -> Self {
+        Self::from(self.buffer.slice(offset, len))
+    }
+}
+
+impl From<BooleanBuffer> for FilterMask {
+    fn from(buffer: BooleanBuffer) -> Self {
+        Self {
+            buffer,
+            true_count: OnceLock::new(),
+            indices: OnceLock::new(),
+            slices: OnceLock::new(),
+        }
+    }
+}
+
+impl TryFrom<Array> for FilterMask {
+    type Error = VortexError;
+
+    fn try_from(predicate: Array) -> VortexResult<Self> {
+        if predicate.dtype() != &DType::Bool(Nullability::NonNullable) {
+            vortex_bail!(
+                "predicate must be non-nullable bool, has dtype {}",
+                predicate.dtype(),
+            );
+        }
+
+        let true_count = predicate.statistics().get_as::<u64>(Stat::TrueCount);
+        let mask = Self::from(predicate.into_bool()?.boolean_buffer());
+        if let Some(true_count) = true_count {
+            let _ = mask.true_count.set(true_count as usize);
+        }
+        Ok(mask)
+    }
+}
+
+/// Return a new array containing the rows of `array` selected by the mask.
+///
+/// # Performance
+///
+/// The mask caches its decoded forms, so filtering several arrays with the same mask only decodes
+/// the predicate once. Masks that select every row or no rows return the array itself or an empty
+/// slice of it without filtering. Encodings without their own filter implementation use [take] for sparse
+/// masks, and are otherwise decoded and filtered with Arrow.
+pub fn filter(array: impl AsRef<Array>, mask: &FilterMask) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if mask.len() != array.len() {
         vortex_bail!(
-            "predicate.len() is {}, does not equal array.len() of {}",
-            predicate.len(),
+            "mask.len() is {}, does not equal array.len() of {}",
+            mask.len(),
             array.len()
         );
     }
 
+    if mask.true_count() == array.len() {
+        return Ok(array.clone());
+    }
+
     array.with_dyn(|a| {
+        if mask.true_count() == 0 {
+            if let Some(slice_fn) = a.slice() {
+                return slice_fn.slice(0, 0);
+            }
+        }
+
         if let Some(filter_fn) = a.filter() {
-            filter_fn.filter(predicate)
+            filter_fn.filter(mask)
+        } else if let FilterIter::Indices(_) = mask.iter() {
+            take(array, mask.to_indices_array())
         } else {
             // Fallback: implement using Arrow kernels.
             let array_ref = array.clone().into_canonical()?.into_arrow()?;
-            let predicate_ref = predicate.clone().into_canonical()?.into_arrow()?;
-            let filtered =
-                arrow_select::filter::filter(array_ref.as_ref(), predicate_ref.as_boolean())?;
+            let filtered = arrow_select::filter::filter(
+                array_ref.as_ref(),
+                &BooleanArray::new(mask.boolean_buffer().clone(), None),
+            )?;
 
             Ok(Array::from_arrow(filtered, array.dtype().is_nullable()))
         }
@@ -55,10 +259,13 @@ pub fn filter(array: impl AsRef<Array>, predicate: impl AsRef<Array>) -> VortexR
 
 #[cfg(test)]
 mod test {
+    use arrow_buffer::BooleanBuffer;
+
     use crate::array::{BoolArray, PrimitiveArray};
     use crate::compute::filter::filter;
+    use crate::compute::{FilterIter, FilterMask};
     use crate::validity::Validity;
-    use crate::{IntoArray, IntoCanonical};
+    use crate::{ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
 
     #[test]
     fn test_filter() {
@@ -69,7 +276,7 @@ mod test {
             BoolArray::from_vec(vec![true, false, true, false, true], Validity::NonNullable)
                 .into_array();
 
-        let filtered = filter(&items, &predicate).unwrap();
+        let filtered = filter(&items, &FilterMask::try_from(predicate).unwrap()).unwrap();
         assert_eq!(
             filtered
                 .into_canonical()
@@ -80,4 +287,82 @@ mod test {
             vec![0i32, 1i32, 2i32]
         );
     }
+
+    #[test]
+    fn mask_representations() {
+        let mask = FilterMask::from(BooleanBuffer::from(vec![
+            false, true, true, false, true, true, true, false, false, false,
+        ]));
+        assert_eq!(mask.true_count(), 5);
+        assert_eq!(mask.indices(), &[1, 2, 4, 5, 6]);
+        assert_eq!(mask.slices(), &[(1, 3), (4, 7)]);
+        assert!(matches!(mask.iter(), FilterIter::Bitmap(_)));
+
+        let sparse = FilterMask::from_indices(100, [3, 50]);
+        assert_eq!(sparse.true_count(), 2);
+        assert!(matches!(sparse.iter(), FilterIter::Indices(&[3, 50])));
+
+        let dense = FilterMask::from(BooleanBuffer::from(vec![true; 10]));
+        assert!(matches!(dense.iter(), FilterIter::Slices(&[(0, 10)])));
+
+        let from_take = FilterMask::try_from_indices_array(
+            10,
+            PrimitiveArray::from(vec![7u8, 2, 7, 0]).as_ref(),
+        )
+        .unwrap();
+        assert_eq!(from_take.indices(), &[0, 2, 7]);
+        assert!(
+            FilterMask::try_from_indices_array(10, PrimitiveArray::from(vec![10u8]).as_ref())
+                .is_err()
+        );
+        assert!(
+            FilterMask::try_from_indices_array(10, PrimitiveArray::from(vec![-1i32]).as_ref())
+                .is_err()
+        );
+
+        let sliced = mask.slice(2, 5);
+        assert_eq!(sliced.indices(), &[0, 2, 3, 4]);
+    }
+
+    #[test]
+    fn filter_none_or_all() {
+        let items = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        let none = filter(&items, &FilterMask::from_indices(3, None)).unwrap();
+        assert_eq!(none.len(), 0);
+        assert_eq!(none.dtype(), items.dtype());
+
+        let all = filter(&items, &FilterMask::from(BooleanBuffer::new_set(3))).unwrap();
+        assert_eq!(
+            all.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn mask_from_nullable() {
+        let predicate = BoolArray::from_iter([Some(true), None]).into_array();
+        assert!(FilterMask::try_from(predicate).is_err());
+    }
+
+    #[test]
+    fn filter_by_indices() {
+        let items = PrimitiveArray::from((0..100).collect::<Vec<i32>>()).into_array();
+        let mask = FilterMask::from_indices(100, [7, 42, 99]);
+        let filtered = filter(&items, &mask).unwrap();
+        assert_eq!(
+            filtered
+                .into_canonical()
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .into_maybe_null_slice::<i32>(),
+            vec![7, 42, 99]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn from_indices_out_of_range() {
+        FilterMask::from_indices(4, [1, 4]);
+    }
 }
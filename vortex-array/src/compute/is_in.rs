@@ -0,0 +1,173 @@
+use std::collections::HashSet;
+
+use arrow_buffer::BooleanBuffer;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability};
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::accessor::ArrayAccessor;
+use crate::array::{BoolArray, ConstantArray, PrimitiveArray, VarBinViewArray};
+use crate::{Array, ArrayDType, Canonical, IntoArray, IntoArrayVariant, IntoCanonical};
+
+pub trait IsInFn {
+    /// Return a boolean array marking which elements of the receiver are contained in `values`.
+    fn is_in(&self, values: &Array) -> VortexResult<Array>;
+}
+
+/// Test each element of `array` for membership in the set of `values`.
+///
+/// The result is a boolean array with the same length and nullability as `array`. Null elements
+/// of `array` produce null results, and null entries in `values` never match.
+pub fn is_in(array: impl AsRef<Array>, values: impl AsRef<Array>) -> VortexResult<Array> {
+    let array = array.as_ref();
+    let values = values.as_ref();
+
+    if !array.dtype().eq_ignore_nullability(values.dtype()) {
+        vortex_bail!(MismatchedTypes: array.dtype(), values.dtype());
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.is_in().map(|f| f.is_in(values))) {
+        return result;
+    }
+
+    is_in_canonical(array.clone().into_canonical()?, values)
+}
+
+/// Hash-set based membership test over a canonical array.
+pub(crate) fn is_in_canonical(array: Canonical, values: &Array) -> VortexResult<Array> {
+    match array {
+        Canonical::Null(a) => Ok(ConstantArray::new(
+            Scalar::null(DType::Bool(Nullability::Nullable)),
+            a.len(),
+        )
+        .into_array()),
+        Canonical::Bool(a) => {
+            let values = values.clone().into_bool()?;
+            let needles = values.validity();
+            let (mut has_true, mut has_false) = (false, false);
+            for (i, v) in values.boolean_buffer().iter().enumerate() {
+                if needles.is_valid(i) {
+                    has_true |= v;
+                    has_false |= !v;
+                }
+            }
+            let buffer = a
+                .boolean_buffer()
+                .iter()
+                .map(|v| if v { has_true } else { has_false })
+                .collect::<BooleanBuffer>();
+            BoolArray::try_new(buffer, a.validity()).map(IntoArray::into_array)
+        }
+        Canonical::Primitive(a) => {
+            let values = values.clone().into_primitive()?;
+            match_each_native_ptype!(a.ptype(), |$T| {
+                is_in_primitive::<$T>(&a, &values).map(IntoArray::into_array)
+            })
+        }
+        Canonical::VarBinView(a) => is_in_varbinview(&a, values).map(IntoArray::into_array),
+        Canonical::Extension(a) => is_in(a.storage(), values.clone().into_extension()?.storage()),
+        Canonical::Struct(a) => vortex_bail!(NotImplemented: "is_in", a.dtype()),
+    }
+}
+
+fn is_in_primitive<T: NativePType>(
+    array: &PrimitiveArray,
+    values: &PrimitiveArray,
+) -> VortexResult<BoolArray> {
+    let validity = values.validity();
+    // Hash the little-endian bytes so that floats get bitwise (total) equality semantics.
+    let set: HashSet<&[u8]> = values
+        .maybe_null_slice::<T>()
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| validity.is_valid(*i))
+        .map(|(_, v)| v.to_le_bytes())
+        .collect();
+
+    let buffer = array
+        .maybe_null_slice::<T>()
+        .iter()
+        .map(|v| set.contains(v.to_le_bytes()))
+        .collect::<BooleanBuffer>();
+    BoolArray::try_new(buffer, array.validity())
+}
+
+fn is_in_varbinview(array: &VarBinViewArray, values: &Array) -> VortexResult<BoolArray> {
+    let values = values.clone().into_varbinview()?;
+    let set: HashSet<Vec<u8>> =
+        values.with_iterator(|iter| iter.flatten().map(<[u8]>::to_vec).collect())?;
+
+    let buffer = array.with_iterator(|iter| {
+        iter.map(|v| v.is_some_and(|v| set.contains(v)))
+            .collect::<BooleanBuffer>()
+    })?;
+    BoolArray::try_new(buffer, array.validity())
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability};
+
+    use crate::accessor::ArrayAccessor;
+    use crate::array::{BoolArray, PrimitiveArray, VarBinViewArray};
+    use crate::compute::is_in;
+    use crate::{ArrayDType, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn is_in_primitive() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(3), Some(4)]);
+        let values = PrimitiveArray::from_nullable_vec(vec![Some(4i32), None, Some(1)]);
+        let result = is_in(array.as_ref(), values.as_ref()).unwrap();
+        assert_eq!(result.dtype(), &DType::Bool(Nullability::Nullable));
+        assert_eq!(
+            result
+                .into_bool()
+                .unwrap()
+                .with_iterator(|iter| iter.map(|v| v.copied()).collect::<Vec<_>>())
+                .unwrap(),
+            vec![Some(true), None, Some(false), Some(true)]
+        );
+    }
+
+    #[test]
+    fn is_in_bool() {
+        let array = BoolArray::from(vec![true, false, true]).into_array();
+        let values = BoolArray::from(vec![false]).into_array();
+        let result = is_in(&array, &values).unwrap().into_bool().unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, false]
+        );
+    }
+
+    #[test]
+    fn is_in_strings() {
+        let array = VarBinViewArray::from_iter_nullable_str([
+            Some("US"),
+            Some("FR"),
+            None,
+            Some("a string that is long enough to not be inlined"),
+        ]);
+        let values = VarBinViewArray::from_iter_str([
+            "CA",
+            "US",
+            "a string that is long enough to not be inlined",
+        ]);
+        let result = is_in(array.as_ref(), values.as_ref()).unwrap();
+        assert_eq!(
+            result
+                .into_bool()
+                .unwrap()
+                .with_iterator(|iter| iter.map(|v| v.copied()).collect::<Vec<_>>())
+                .unwrap(),
+            vec![Some(true), Some(false), None, Some(true)]
+        );
+    }
+
+    #[test]
+    fn is_in_mismatched_types() {
+        let array = PrimitiveArray::from(vec![1u8, 2]);
+        let values = PrimitiveArray::from(vec![1u16]);
+        assert!(is_in(array.as_ref(), values.as_ref()).is_err());
+    }
+}
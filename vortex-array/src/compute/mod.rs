@@ -7,23 +7,37 @@
 //! implementations of these operators, else we will decode, and perform the equivalent operator
 //! from Arrow.
 
+pub use between::{between, integer_bounds, BetweenFn, Inclusivity};
+pub use bitwise::{bitwise, popcount, BitwiseOperator};
 pub use boolean::{and, or, AndFn, OrFn};
 pub use compare::{compare, scalar_cmp, CompareFn, MaybeCompareFn, Operator};
-pub use filter::{filter, FilterFn};
+pub use cumulative::{cummax, cummin, cumsum, diff, rolling_mean, rolling_sum, CumsumFn, DiffFn};
+pub use filter::{filter, FilterFn, FilterIter, FilterMask};
+pub(crate) use is_in::is_in_canonical;
+pub use is_in::{is_in, IsInFn};
 pub use search_sorted::*;
 pub use slice::{slice, SliceFn};
-pub use take::{take, TakeFn};
-use unary::{CastFn, FillForwardFn, ScalarAtFn, SubtractScalarFn};
+pub use take::{take, take_mask, TakeFn};
+pub(crate) use top_k::top_k_canonical;
+pub use top_k::{top_k, TopKFn};
+use unary::{CastFn, FillForwardFn, MathOp, ScalarAtFn, SubtractScalarFn};
 use vortex_error::VortexResult;
+pub use zip::{coalesce, zip};
 
 use crate::Array;
 
+mod between;
+mod bitwise;
 mod boolean;
 mod compare;
+mod cumulative;
 mod filter;
+mod is_in;
 mod search_sorted;
 mod slice;
 mod take;
+mod top_k;
+mod zip;
 
 pub mod unary;
 
@@ -36,6 +50,20 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Range predicate over the array, testing both bounds in a single pass.
+    ///
+    /// See: [BetweenFn].
+    fn between(&self) -> Option<&dyn BetweenFn> {
+        None
+    }
+
+    /// Bitwise operator implementation for integer arrays against other arrays.
+    ///
+    /// See: [bitwise].
+    fn bitwise(&self, _other: &Array, _operator: BitwiseOperator) -> Option<VortexResult<Array>> {
+        None
+    }
+
     /// Binary operator implementation for arrays against other arrays.
     ///
     ///See: [CompareFn].
@@ -43,6 +71,20 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Running sum of an array.
+    ///
+    /// See: [CumsumFn].
+    fn cumsum(&self) -> Option<&dyn CumsumFn> {
+        None
+    }
+
+    /// Differences between adjacent elements of an array.
+    ///
+    /// See: [DiffFn].
+    fn diff(&self) -> Option<&dyn DiffFn> {
+        None
+    }
+
     /// Array function that returns new arrays a non-null value is repeated across runs of nulls.
     ///
     /// See: [FillForwardFn].
@@ -57,6 +99,13 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Set membership test of array elements against a list of values.
+    ///
+    /// See: [IsInFn].
+    fn is_in(&self) -> Option<&dyn IsInFn> {
+        None
+    }
+
     /// Single item indexing on Vortex arrays.
     ///
     /// See: [ScalarAtFn].
@@ -93,6 +142,20 @@ pub trait ArrayCompute {
         None
     }
 
+    /// Select the indices of the first elements of the array in sorted order.
+    ///
+    /// See: [TopKFn].
+    fn top_k(&self) -> Option<&dyn TopKFn> {
+        None
+    }
+
+    /// Element-wise numeric function over the array, if the encoding can compute it directly.
+    ///
+    /// See: [unary_math][unary::unary_math].
+    fn unary_math(&self, _op: MathOp) -> Option<VortexResult<Array>> {
+        None
+    }
+
     /// Perform a boolean AND operation over two arrays
     ///
     /// See: [AndFn].
@@ -1,6 +1,7 @@
 use log::info;
 use vortex_error::{vortex_bail, vortex_err, VortexResult};
 
+use crate::compute::{filter, FilterMask};
 use crate::{Array, ArrayDType as _, IntoCanonical as _};
 
 pub trait TakeFn {
@@ -32,3 +33,46 @@ pub fn take(array: impl AsRef<Array>, indices: impl AsRef<Array>) -> VortexResul
         })
     })
 }
+
+/// Take the rows selected by a mask, i.e. the rows at its [indices](FilterMask::indices).
+///
+/// Unlike [filter], this prefers the array's [TakeFn] over its filter, and reuses the indices
+/// cached by the mask rather than deriving them from its bitmap again.
+pub fn take_mask(array: impl AsRef<Array>, mask: &FilterMask) -> VortexResult<Array> {
+    let array = array.as_ref();
+    if mask.len() != array.len() {
+        vortex_bail!(
+            "mask.len() is {}, does not equal array.len() of {}",
+            mask.len(),
+            array.len()
+        );
+    }
+
+    if array.with_dyn(|a| a.take().is_some()) {
+        take(array, mask.to_indices_array())
+    } else {
+        filter(array, mask)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::PrimitiveArray;
+    use crate::compute::{take_mask, FilterMask};
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn take_with_mask() {
+        let array = PrimitiveArray::from(vec![10u32, 11, 12, 13, 14]).into_array();
+        let mask =
+            FilterMask::try_from_indices_array(5, PrimitiveArray::from(vec![4u8, 1]).as_ref())
+                .unwrap();
+        let taken = take_mask(&array, &mask).unwrap();
+        assert_eq!(
+            taken.into_primitive().unwrap().maybe_null_slice::<u32>(),
+            [11, 14]
+        );
+
+        assert!(take_mask(&array, &FilterMask::from_indices(4, [1])).is_err());
+    }
+}
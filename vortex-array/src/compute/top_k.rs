@@ -0,0 +1,149 @@
+use arrow_ord::sort::sort_to_indices;
+use arrow_schema::SortOptions;
+use vortex_error::VortexResult;
+
+use crate::array::PrimitiveArray;
+use crate::stats::{ArrayStatistics, Stat};
+use crate::{Array, ArrayDType, IntoArray, IntoCanonical};
+
+pub trait TopKFn {
+    /// Return the indices of the `k` first elements of the receiver in the requested order.
+    fn top_k(&self, k: usize, descending: bool) -> VortexResult<Array>;
+}
+
+/// Return the indices of the `k` largest elements of `array` if `descending`, or of the `k`
+/// smallest elements otherwise, ordered from first to last.
+///
+/// This answers `ORDER BY x LIMIT k` without sorting the whole array. Null elements are ranked after
+/// every valid element, and ties are broken arbitrarily. The result is a non-nullable `u64` array of
+/// `min(k, array.len())` indices.
+pub fn top_k(array: impl AsRef<Array>, k: usize, descending: bool) -> VortexResult<Array> {
+    let array = array.as_ref();
+    let k = k.min(array.len());
+    if k == 0 {
+        return Ok(PrimitiveArray::from(Vec::<u64>::new()).into_array());
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.top_k().map(|f| f.top_k(k, descending))) {
+        return result;
+    }
+
+    // Float arrays are sorted ignoring NaNs, which still take part in the ordering.
+    let stats = array.statistics();
+    if !array.dtype().is_float()
+        && stats.get_as::<bool>(Stat::IsSorted) == Some(true)
+        && (!array.dtype().is_nullable() || stats.get_as::<u64>(Stat::NullCount) == Some(0))
+    {
+        let len = array.len() as u64;
+        let indices = if descending {
+            (len - k as u64..len).rev().collect::<Vec<_>>()
+        } else {
+            (0..k as u64).collect()
+        };
+        return Ok(PrimitiveArray::from(indices).into_array());
+    }
+
+    top_k_canonical(array, k, descending)
+}
+
+/// Select the first `k` indices of a decoded array with a partial sort.
+pub(crate) fn top_k_canonical(array: &Array, k: usize, descending: bool) -> VortexResult<Array> {
+    let arrow = array.clone().into_canonical()?.into_arrow()?;
+    let options = SortOptions {
+        descending,
+        nulls_first: false,
+    };
+    let indices = sort_to_indices(arrow.as_ref(), Some(options), Some(k))?;
+    Ok(PrimitiveArray::from(
+        indices
+            .values()
+            .iter()
+            .map(|&idx| u64::from(idx))
+            .collect::<Vec<_>>(),
+    )
+    .into_array())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::array::{PrimitiveArray, VarBinViewArray};
+    use crate::compute::top_k;
+    use crate::stats::ArrayStatistics;
+    use crate::{IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn top_k_primitive() {
+        let array =
+            PrimitiveArray::from_nullable_vec(vec![Some(3i32), None, Some(9), Some(-1), Some(5)])
+                .into_array();
+        assert_eq!(
+            top_k(&array, 2, true)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[2, 4]
+        );
+        assert_eq!(
+            top_k(&array, 3, false)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[3, 0, 4]
+        );
+        // Nulls come last.
+        assert_eq!(
+            top_k(&array, 10, true)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[2, 4, 0, 3, 1]
+        );
+        assert!(top_k(&array, 0, true).unwrap().is_empty());
+    }
+
+    #[test]
+    fn top_k_sorted() {
+        let array = PrimitiveArray::from(vec![1u16, 2, 4, 8, 16]).into_array();
+        assert!(array.statistics().compute_is_sorted().unwrap());
+        assert_eq!(
+            top_k(&array, 2, true)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[4, 3]
+        );
+        assert_eq!(
+            top_k(&array, 2, false)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[0, 1]
+        );
+    }
+
+    #[test]
+    fn top_k_strings() {
+        let array = VarBinViewArray::from_iter_str(["pear", "apple", "fig"]).into_array();
+        assert_eq!(
+            top_k(&array, 1, true)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[0]
+        );
+        assert_eq!(
+            top_k(&array, 2, false)
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            &[1, 2]
+        );
+    }
+}
@@ -0,0 +1,323 @@
+use core::fmt;
+use std::fmt::{Display, Formatter};
+
+use num_traits::Float;
+use vortex_dtype::half::f16;
+use vortex_dtype::{match_each_float_ptype, match_each_integer_ptype, DType, NativePType, PType};
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::array::PrimitiveArray;
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
+
+/// Element-wise numeric functions of a single argument.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MathOp {
+    Abs,
+    Negate,
+    Round,
+    Floor,
+    Ceil,
+    Sqrt,
+    Log,
+}
+
+impl Display for MathOp {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let display = match &self {
+            MathOp::Abs => "abs",
+            MathOp::Negate => "negate",
+            MathOp::Round => "round",
+            MathOp::Floor => "floor",
+            MathOp::Ceil => "ceil",
+            MathOp::Sqrt => "sqrt",
+            MathOp::Log => "log",
+        };
+        Display::fmt(display, f)
+    }
+}
+
+impl MathOp {
+    /// Apply the function to a native float.
+    ///
+    /// Rounding is half away from zero and `Log` is the natural logarithm.
+    pub fn apply<T: Float>(self, value: T) -> T {
+        match self {
+            MathOp::Abs => value.abs(),
+            MathOp::Negate => -value,
+            MathOp::Round => value.round(),
+            MathOp::Floor => value.floor(),
+            MathOp::Ceil => value.ceil(),
+            MathOp::Sqrt => value.sqrt(),
+            MathOp::Log => value.ln(),
+        }
+    }
+}
+
+/// Apply a numeric function element-wise over a primitive array.
+///
+/// Nulls are propagated. Float arrays keep their type. For integer arrays, the rounding functions
+/// are the identity, `abs` and `negate` wrap on overflow (`negate` is an error for unsigned types),
+/// and `sqrt` and `log` produce `f64`.
+pub fn unary_math(array: impl AsRef<Array>, op: MathOp) -> VortexResult<Array> {
+    let array = array.as_ref();
+    let DType::Primitive(ptype, _) = array.dtype() else {
+        vortex_bail!(NotImplemented: op.to_string(), array.dtype());
+    };
+
+    if ptype.is_int() {
+        match op {
+            MathOp::Round | MathOp::Floor | MathOp::Ceil => return Ok(array.clone()),
+            MathOp::Abs if ptype.is_unsigned_int() => return Ok(array.clone()),
+            MathOp::Negate if ptype.is_unsigned_int() => {
+                vortex_bail!(
+                    "Cannot negate unsigned integer array of type {}",
+                    array.dtype()
+                )
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(result) = array.with_dyn(|a| a.unary_math(op)) {
+        return result;
+    }
+
+    let array = array.clone().into_primitive()?;
+    let ptype = array.ptype();
+    if ptype.is_float() {
+        return match_each_float_ptype!(ptype, |$T| {
+            Ok(map::<$T, $T>(&array, |v| op.apply(v)))
+        });
+    }
+    match op {
+        MathOp::Abs | MathOp::Negate => match ptype {
+            PType::I8 => Ok(signed::<i8>(&array, op)),
+            PType::I16 => Ok(signed::<i16>(&array, op)),
+            PType::I32 => Ok(signed::<i32>(&array, op)),
+            PType::I64 => Ok(signed::<i64>(&array, op)),
+            _ => vortex_bail!(NotImplemented: op.to_string(), array.dtype()),
+        },
+        _ => match_each_integer_ptype!(ptype, |$T| {
+            Ok(map::<$T, f64>(&array, |v| op.apply(v as f64)))
+        }),
+    }
+}
+
+/// Absolute value of each element.
+pub fn abs(array: impl AsRef<Array>) -> VortexResult<Array> {
+    unary_math(array, MathOp::Abs)
+}
+
+/// Negation of each element.
+pub fn negate(array: impl AsRef<Array>) -> VortexResult<Array> {
+    unary_math(array, MathOp::Negate)
+}
+
+/// Round each element to the nearest integer, with halves rounded away from zero.
+pub fn round(array: impl AsRef<Array>) -> VortexResult<Array> {
+    unary_math(array, MathOp::Round)
+}
+
+/// Largest integer less than or equal to each element.
+pub fn floor(array: impl AsRef<Array>) -> VortexResult<Array> {
+    unary_math(array, MathOp::Floor)
+}
+
+/// Smallest integer greater than or equal to each element.
+pub fn ceil(array: impl AsRef<Array>) -> VortexResult<Array> {
+    unary_math(array, MathOp::Ceil)
+}
+
+/// Square root of each element.
+pub fn sqrt(array: impl AsRef<Array>) -> VortexResult<Array> {
+    unary_math(array, MathOp::Sqrt)
+}
+
+/// Natural logarithm of each element.
+pub fn log(array: impl AsRef<Array>) -> VortexResult<Array> {
+    unary_math(array, MathOp::Log)
+}
+
+/// Arithmetic that wraps on overflow for integers and follows IEEE 754 for floats.
+pub(crate) trait WrappingArith: NativePType {
+    fn wrapping_add(self, other: Self) -> Self;
+
+    fn wrapping_sub(self, other: Self) -> Self;
+
+    fn wrapping_abs(self) -> Self;
+
+    fn wrapping_neg(self) -> Self;
+}
+
+macro_rules! wrapping_signed {
+    ($($T:ty),*) => {
+        $(impl WrappingArith for $T {
+            fn wrapping_add(self, other: Self) -> Self {
+                <$T>::wrapping_add(self, other)
+            }
+
+            fn wrapping_sub(self, other: Self) -> Self {
+                <$T>::wrapping_sub(self, other)
+            }
+
+            fn wrapping_abs(self) -> Self {
+                <$T>::wrapping_abs(self)
+            }
+
+            fn wrapping_neg(self) -> Self {
+                <$T>::wrapping_neg(self)
+            }
+        })*
+    };
+}
+
+macro_rules! wrapping_unsigned {
+    ($($T:ty),*) => {
+        $(impl WrappingArith for $T {
+            fn wrapping_add(self, other: Self) -> Self {
+                <$T>::wrapping_add(self, other)
+            }
+
+            fn wrapping_sub(self, other: Self) -> Self {
+                <$T>::wrapping_sub(self, other)
+            }
+
+            fn wrapping_abs(self) -> Self {
+                self
+            }
+
+            fn wrapping_neg(self) -> Self {
+                <$T>::wrapping_neg(self)
+            }
+        })*
+    };
+}
+
+macro_rules! wrapping_float {
+    ($($T:ty),*) => {
+        $(impl WrappingArith for $T {
+            fn wrapping_add(self, other: Self) -> Self {
+                self + other
+            }
+
+            fn wrapping_sub(self, other: Self) -> Self {
+                self - other
+            }
+
+            fn wrapping_abs(self) -> Self {
+                self.abs()
+            }
+
+            fn wrapping_neg(self) -> Self {
+                -self
+            }
+        })*
+    };
+}
+
+wrapping_signed!(i8, i16, i32, i64);
+wrapping_unsigned!(u8, u16, u32, u64);
+wrapping_float!(f16, f32, f64);
+
+fn signed<T: WrappingArith>(array: &PrimitiveArray, op: MathOp) -> Array {
+    if op == MathOp::Abs {
+        map::<T, T>(array, T::wrapping_abs)
+    } else {
+        map::<T, T>(array, T::wrapping_neg)
+    }
+}
+
+fn map<T: NativePType, R: NativePType>(array: &PrimitiveArray, f: impl Fn(T) -> R) -> Array {
+    let values = array
+        .maybe_null_slice::<T>()
+        .iter()
+        .map(|&v| f(v))
+        .collect::<Vec<_>>();
+    PrimitiveArray::from_vec(values, array.validity()).into_array()
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::accessor::ArrayAccessor;
+    use crate::array::PrimitiveArray;
+    use crate::compute::unary::{floor, log, negate, sqrt, unary_math, MathOp};
+    use crate::{ArrayDType, IntoArrayVariant};
+
+    #[test]
+    fn float_math() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(-2.5f64), None, Some(1.25)]);
+        let cases = [
+            (MathOp::Abs, vec![Some(2.5), None, Some(1.25)]),
+            (MathOp::Negate, vec![Some(2.5), None, Some(-1.25)]),
+            (MathOp::Round, vec![Some(-3.0), None, Some(1.0)]),
+            (MathOp::Floor, vec![Some(-3.0), None, Some(1.0)]),
+            (MathOp::Ceil, vec![Some(-2.0), None, Some(2.0)]),
+        ];
+        for (op, expected) in cases {
+            let result = unary_math(array.as_ref(), op)
+                .unwrap()
+                .into_primitive()
+                .unwrap();
+            assert_eq!(
+                ArrayAccessor::<f64>::with_iterator(&result, |iter| iter
+                    .map(|v| v.copied())
+                    .collect::<Vec<_>>())
+                .unwrap(),
+                expected,
+                "{op}"
+            );
+        }
+    }
+
+    #[test]
+    fn integer_math() {
+        let array = PrimitiveArray::from_nullable_vec(vec![Some(-4i32), None, Some(i32::MIN)]);
+        for op in [MathOp::Abs, MathOp::Negate] {
+            let result = unary_math(array.as_ref(), op)
+                .unwrap()
+                .into_primitive()
+                .unwrap();
+            assert_eq!(
+                ArrayAccessor::<i32>::with_iterator(&result, |iter| iter
+                    .map(|v| v.copied())
+                    .collect::<Vec<_>>())
+                .unwrap(),
+                vec![Some(4), None, Some(i32::MIN)],
+                "{op}"
+            );
+        }
+        assert_eq!(
+            floor(array.as_ref())
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<i32>(),
+            array.maybe_null_slice::<i32>()
+        );
+        assert!(negate(PrimitiveArray::from(vec![1u8]).as_ref()).is_err());
+    }
+
+    #[test]
+    fn integer_sqrt_log() {
+        let array = PrimitiveArray::from(vec![4u32, 1]);
+        let result = sqrt(array.as_ref()).unwrap();
+        assert_eq!(
+            result.dtype(),
+            &DType::Primitive(PType::F64, Nullability::NonNullable)
+        );
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<f64>(),
+            &[2.0, 1.0]
+        );
+        assert_eq!(
+            log(array.as_ref())
+                .unwrap()
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<f64>(),
+            &[4f64.ln(), 0.0]
+        );
+    }
+}
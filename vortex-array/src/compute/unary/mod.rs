@@ -1,9 +1,12 @@
 pub use cast::{try_cast, CastFn};
 pub use fill_forward::{fill_forward, FillForwardFn};
+pub(crate) use math::WrappingArith;
+pub use math::{abs, ceil, floor, log, negate, round, sqrt, unary_math, MathOp};
 pub use scalar_at::{scalar_at, scalar_at_unchecked, ScalarAtFn};
 pub use scalar_subtract::{subtract_scalar, SubtractScalarFn};
 
 mod cast;
 mod fill_forward;
+mod math;
 mod scalar_at;
 mod scalar_subtract;
@@ -0,0 +1,243 @@
+use arrow_array::{make_array, Array as _, BooleanArray};
+use arrow_buffer::BooleanBuffer;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, VortexResult};
+use vortex_scalar::ScalarValue;
+
+use crate::array::{ConstantArray, PrimitiveArray, SparseArray};
+use crate::arrow::FromArrowArray;
+use crate::compute::take;
+use crate::compute::unary::scalar_at;
+use crate::stats::ArrayStatistics;
+use crate::validity::{ArrayValidity, Validity};
+use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant, IntoCanonical};
+
+/// Select values per row from `if_true` where `mask` is true, and from `if_false` otherwise.
+///
+/// Null entries in the mask select from `if_false`. The result is nullable if either of the
+/// inputs is nullable.
+pub fn zip(
+    mask: impl AsRef<Array>,
+    if_true: impl AsRef<Array>,
+    if_false: impl AsRef<Array>,
+) -> VortexResult<Array> {
+    let mask = mask.as_ref();
+    let if_true = if_true.as_ref();
+    let if_false = if_false.as_ref();
+
+    if !matches!(mask.dtype(), DType::Bool(_)) {
+        vortex_bail!("mask must be a boolean array, has dtype {}", mask.dtype());
+    }
+    if !if_true.dtype().eq_ignore_nullability(if_false.dtype()) {
+        vortex_bail!(MismatchedTypes: if_true.dtype(), if_false.dtype());
+    }
+    if mask.len() != if_true.len() || mask.len() != if_false.len() {
+        vortex_bail!(
+            "zip requires equal lengths, got mask {}, if_true {} and if_false {}",
+            mask.len(),
+            if_true.len(),
+            if_false.len()
+        );
+    }
+
+    let result_dtype = if_true
+        .dtype()
+        .with_nullability((if_true.dtype().is_nullable() || if_false.dtype().is_nullable()).into());
+    zip_as(mask, if_true, if_false, &result_dtype)
+}
+
+/// Return the first non-null value per row across the given arrays.
+///
+/// All arrays must have the same length and logical type. The result is only nullable if every
+/// input is nullable.
+pub fn coalesce(arrays: &[Array]) -> VortexResult<Array> {
+    let Some((first, rest)) = arrays.split_first() else {
+        vortex_bail!("coalesce requires at least one array");
+    };
+
+    let mut result = first.clone();
+    for next in rest {
+        if !result.dtype().is_nullable() {
+            break;
+        }
+        if !result.dtype().eq_ignore_nullability(next.dtype()) {
+            vortex_bail!(MismatchedTypes: result.dtype(), next.dtype());
+        }
+        if next.len() != result.len() {
+            vortex_bail!(
+                "coalesce requires equal lengths, got {} and {}",
+                result.len(),
+                next.len()
+            );
+        }
+
+        let valid = result.with_dyn(|a| a.logical_validity());
+        if valid.all_valid() {
+            break;
+        }
+
+        // Rows that are still null after this step are exactly the null rows of `next`.
+        let result_dtype = result.dtype().with_nullability(next.dtype().nullability());
+        result = zip_as(&valid.into_array(), &result, next, &result_dtype)?;
+    }
+    Ok(result)
+}
+
+fn zip_as(mask: &Array, if_true: &Array, if_false: &Array, dtype: &DType) -> VortexResult<Array> {
+    // If the mask selects a single side we can return it as-is, provided the types line up.
+    if let Some(all_true) = constant_selection(mask) {
+        let selected = if all_true { if_true } else { if_false };
+        if selected.dtype() == dtype {
+            return Ok(selected.clone());
+        }
+    }
+
+    let selection = selection(mask)?;
+
+    // A constant on one side becomes the fill value of a sparse array over the other.
+    if if_true.dtype() == dtype {
+        if let Ok(fill) = ConstantArray::try_from(if_false) {
+            return sparse_zip(&selection, if_true, fill.scalar_value().clone());
+        }
+    }
+    if if_false.dtype() == dtype {
+        if let Ok(fill) = ConstantArray::try_from(if_true) {
+            return sparse_zip(&!&selection, if_false, fill.scalar_value().clone());
+        }
+    }
+
+    let zipped = arrow_select::zip::zip(
+        &BooleanArray::new(selection, None),
+        &if_true.clone().into_canonical()?.into_arrow()?,
+        &if_false.clone().into_canonical()?.into_arrow()?,
+    )?;
+    // The arrow kernel always materializes a null buffer, which a non-nullable result must drop.
+    let zipped = if !dtype.is_nullable() && zipped.null_count() == 0 {
+        make_array(zipped.into_data().into_builder().nulls(None).build()?)
+    } else {
+        zipped
+    };
+    Ok(Array::from_arrow(zipped, dtype.is_nullable()))
+}
+
+/// Returns whether the mask selects only `if_true` or only `if_false`, if known from statistics.
+fn constant_selection(mask: &Array) -> Option<bool> {
+    if mask.is_empty() {
+        return None;
+    }
+    if mask.statistics().compute_is_constant() == Some(true) {
+        return Some(scalar_at(mask, 0).ok()?.value().as_bool().ok()? == Some(true));
+    }
+    match mask.statistics().compute_true_count()? {
+        0 => Some(false),
+        n if n == mask.len() => Some(true),
+        _ => None,
+    }
+}
+
+/// The rows selected by the mask, treating nulls as false.
+fn selection(mask: &Array) -> VortexResult<BooleanBuffer> {
+    let mask = mask.clone().into_bool()?;
+    let buffer = mask.boolean_buffer();
+    Ok(match mask.logical_validity().to_null_buffer()? {
+        Some(nulls) => &buffer & nulls.inner(),
+        None => buffer,
+    })
+}
+
+fn sparse_zip(selection: &BooleanBuffer, values: &Array, fill: ScalarValue) -> VortexResult<Array> {
+    let indices = PrimitiveArray::from_vec(
+        selection
+            .set_indices()
+            .map(|i| i as u64)
+            .collect::<Vec<_>>(),
+        Validity::NonNullable,
+    )
+    .into_array();
+    SparseArray::try_new(indices.clone(), take(values, &indices)?, values.len(), fill)
+        .map(IntoArray::into_array)
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::accessor::ArrayAccessor;
+    use crate::array::{BoolArray, ConstantArray, PrimitiveArray, SparseArray};
+    use crate::compute::{coalesce, zip};
+    use crate::{ArrayDType, IntoArray, IntoArrayVariant};
+
+    #[test]
+    fn zip_arrays() {
+        let mask = BoolArray::from_iter([Some(true), Some(false), None, Some(true)]).into_array();
+        let if_true = PrimitiveArray::from(vec![1i32, 2, 3, 4]).into_array();
+        let if_false =
+            PrimitiveArray::from_nullable_vec(vec![Some(10i32), None, Some(30), Some(40)])
+                .into_array();
+        let result = zip(&mask, &if_true, &if_false).unwrap();
+        assert_eq!(
+            result.dtype(),
+            &DType::Primitive(PType::I32, Nullability::Nullable)
+        );
+        let result = result.into_primitive().unwrap();
+        assert_eq!(
+            ArrayAccessor::<i32>::with_iterator(&result, |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            vec![Some(1), None, Some(30), Some(4)]
+        );
+    }
+
+    #[test]
+    fn zip_constant_mask() {
+        let mask = ConstantArray::new(true, 3).into_array();
+        let if_true = PrimitiveArray::from(vec![1i32, 2, 3]).into_array();
+        let if_false = PrimitiveArray::from(vec![4i32, 5, 6]).into_array();
+        let result = zip(&mask, &if_true, &if_false).unwrap();
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn zip_constant_side() {
+        let mask = BoolArray::from(vec![false, true, false, false]).into_array();
+        let if_true = PrimitiveArray::from(vec![1i32, 2, 3, 4]).into_array();
+        let if_false = ConstantArray::new(0i32, 4).into_array();
+        let result = zip(&mask, &if_true, &if_false).unwrap();
+        assert!(SparseArray::try_from(&result).is_ok());
+        assert_eq!(
+            result.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[0, 2, 0, 0]
+        );
+    }
+
+    #[test]
+    fn coalesce_arrays() {
+        let first =
+            PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, None, Some(4)]).into_array();
+        let second =
+            PrimitiveArray::from_nullable_vec(vec![None, Some(20i32), None, Some(40)]).into_array();
+        let third = PrimitiveArray::from(vec![100i32, 200, 300, 400]).into_array();
+
+        let partial = coalesce(&[first.clone(), second.clone()]).unwrap();
+        assert!(partial.dtype().is_nullable());
+        let partial = partial.into_primitive().unwrap();
+        assert_eq!(
+            ArrayAccessor::<i32>::with_iterator(&partial, |iter| iter
+                .map(|v| v.copied())
+                .collect::<Vec<_>>())
+            .unwrap(),
+            vec![Some(1), Some(20), None, Some(4)]
+        );
+
+        let full = coalesce(&[first, second, third]).unwrap();
+        assert!(!full.dtype().is_nullable());
+        assert_eq!(
+            full.into_primitive().unwrap().maybe_null_slice::<i32>(),
+            &[1, 20, 300, 4]
+        );
+    }
+}
@@ -6,7 +6,7 @@ use vortex_error::{vortex_bail, vortex_panic, VortexResult};
 use vortex_scalar::Scalar;
 
 use crate::encoding::EncodingRef;
-use crate::stats::{Stat, Statistics, StatsSet};
+use crate::stats::{compute_statistics, Stat, Statistics, StatsOptions, StatsSet};
 use crate::{Array, ArrayDType, ArrayMetadata, ToArray};
 
 /// Owned [`Array`] with serialized metadata, backed by heap-allocated memory.
@@ -151,6 +151,18 @@ impl Statistics for ArrayData {
             .cloned()
     }
 
+    fn is_exact(&self, stat: Stat) -> bool {
+        self.stats_map
+            .read()
+            .unwrap_or_else(|_| {
+                vortex_panic!(
+                    "Failed to acquire read lock on stats map while getting {}",
+                    stat
+                )
+            })
+            .is_exact(stat)
+    }
+
     fn to_set(&self) -> StatsSet {
         self.stats_map
             .read()
@@ -171,7 +183,7 @@ impl Statistics for ArrayData {
             .set(stat, value);
     }
 
-    fn compute(&self, stat: Stat) -> Option<Scalar> {
+    fn compute_with_options(&self, stat: Stat, options: &StatsOptions) -> Option<Scalar> {
         if let Some(s) = self.get(stat) {
             return Some(s);
         }
@@ -181,11 +193,7 @@ impl Statistics for ArrayData {
             .unwrap_or_else(|_| {
                 vortex_panic!("Failed to write to stats map while computing {}", stat)
             })
-            .extend(
-                self.to_array()
-                    .with_dyn(|a| a.compute_statistics(stat))
-                    .ok()?,
-            );
+            .update(compute_statistics(&self.to_array(), stat, options).ok()?);
         self.get(stat)
     }
 }
@@ -144,6 +144,11 @@ pub mod ids {
     pub const RUN_END_BOOL: u16 = 28;
     pub const ZIGZAG: u16 = 29;
     pub const ALP_RD: u16 = 30;
+    pub const FL_RLE: u16 = 31;
+    pub const GORILLA: u16 = 32;
+    pub const BYTE_STREAM_SPLIT: u16 = 33;
+    pub const SEQUENCE: u16 = 34;
+    pub const LAZY: u16 = 35;
 }
 
 #[cfg(test)]
@@ -185,6 +190,12 @@ mod tests {
             ids::RUN_END,
             ids::RUN_END_BOOL,
             ids::ZIGZAG,
+            ids::ALP_RD,
+            ids::FL_RLE,
+            ids::GORILLA,
+            ids::BYTE_STREAM_SPLIT,
+            ids::SEQUENCE,
+            ids::LAZY,
         ];
 
         let mut ids_set = HashSet::with_capacity(all_ids.len());
@@ -0,0 +1,379 @@
+//! A registry of named scalar functions that can be dispatched over arrays of any encoding.
+//!
+//! Functions are registered under a name with one or more [Signature]s. Each signature has a
+//! default implementation that operates on canonical arrays, and may additionally have
+//! encoding-specific overrides that are tried first and can decline by returning `None`.
+
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::Arc;
+
+use itertools::Itertools;
+use vortex_dtype::{DType, NativePType, Nullability};
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+
+use crate::elementwise::{BinaryFn, UnaryFn};
+use crate::encoding::EncodingId;
+use crate::{Array, ArrayDType, IntoArrayVariant, IntoCanonical};
+
+/// The argument types accepted by a scalar function and the type it returns.
+///
+/// Arguments are matched ignoring nullability. The result is nullable if any argument is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    arguments: Vec<DType>,
+    return_type: DType,
+}
+
+impl Signature {
+    pub fn new(arguments: Vec<DType>, return_type: DType) -> Self {
+        Self {
+            arguments,
+            return_type,
+        }
+    }
+
+    pub fn arguments(&self) -> &[DType] {
+        &self.arguments
+    }
+
+    pub fn return_type(&self) -> &DType {
+        &self.return_type
+    }
+
+    /// Whether the signature accepts arguments of the given types.
+    pub fn matches(&self, arguments: &[DType]) -> bool {
+        self.arguments.len() == arguments.len()
+            && self
+                .arguments
+                .iter()
+                .zip(arguments)
+                .all(|(expected, actual)| expected.eq_ignore_nullability(actual))
+    }
+
+    /// The type of the result when invoked with arguments of the given types.
+    pub fn result_dtype(&self, arguments: &[DType]) -> DType {
+        let nullable = self.return_type.is_nullable() || arguments.iter().any(DType::is_nullable);
+        self.return_type
+            .with_nullability(Nullability::from(nullable))
+    }
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "({}) -> {}",
+            self.arguments.iter().join(", "),
+            self.return_type
+        )
+    }
+}
+
+/// The default implementation of a scalar function, invoked with canonical arguments.
+pub trait ScalarFunction: Send + Sync {
+    fn invoke(&self, args: &[Array]) -> VortexResult<Array>;
+}
+
+impl<F> ScalarFunction for F
+where
+    F: Fn(&[Array]) -> VortexResult<Array> + Send + Sync,
+{
+    fn invoke(&self, args: &[Array]) -> VortexResult<Array> {
+        self(args)
+    }
+}
+
+/// An encoding-specific implementation of a scalar function, invoked with the original arguments.
+///
+/// Returning `None` falls back to the default implementation.
+pub trait MaybeScalarFunction: Send + Sync {
+    fn maybe_invoke(&self, args: &[Array]) -> Option<VortexResult<Array>>;
+}
+
+impl<F> MaybeScalarFunction for F
+where
+    F: Fn(&[Array]) -> Option<VortexResult<Array>> + Send + Sync,
+{
+    fn maybe_invoke(&self, args: &[Array]) -> Option<VortexResult<Array>> {
+        self(args)
+    }
+}
+
+#[derive(Clone)]
+struct Overload {
+    signature: Signature,
+    function: Arc<dyn ScalarFunction>,
+    overrides: HashMap<EncodingId, Arc<dyn MaybeScalarFunction>>,
+}
+
+/// A mapping from function names to their overloads.
+#[derive(Clone, Default)]
+pub struct FunctionRegistry {
+    functions: HashMap<String, Vec<Overload>>,
+}
+
+impl Debug for FunctionRegistry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.functions.iter().map(|(name, overloads)| {
+                (
+                    name,
+                    overloads
+                        .iter()
+                        .map(|o| o.signature.to_string())
+                        .collect::<Vec<_>>(),
+                )
+            }))
+            .finish()
+    }
+}
+
+impl FunctionRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the default implementation of `name` for the given signature.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        signature: Signature,
+        function: impl ScalarFunction + 'static,
+    ) -> VortexResult<()> {
+        let name = name.into();
+        let overloads = self.functions.entry(name.clone()).or_default();
+        if overloads
+            .iter()
+            .any(|o| o.signature.matches(signature.arguments()))
+        {
+            vortex_bail!("Function {name} is already registered for {signature}");
+        }
+        overloads.push(Overload {
+            signature,
+            function: Arc::new(function),
+            overrides: HashMap::new(),
+        });
+        Ok(())
+    }
+
+    /// Register a function of one primitive argument from a native closure.
+    pub fn register_unary<I, O, F>(&mut self, name: impl Into<String>, f: F) -> VortexResult<()>
+    where
+        I: NativePType,
+        O: NativePType,
+        F: Fn(I) -> O + Copy + Send + Sync + 'static,
+    {
+        self.register(
+            name,
+            Signature::new(vec![DType::from(I::PTYPE)], DType::from(O::PTYPE)),
+            move |args: &[Array]| args[0].clone().into_primitive()?.unary(f),
+        )
+    }
+
+    /// Register a function of two primitive arguments from a native closure.
+    pub fn register_binary<I, U, O, F>(&mut self, name: impl Into<String>, f: F) -> VortexResult<()>
+    where
+        I: NativePType,
+        U: NativePType,
+        O: NativePType,
+        F: Fn(I, U) -> O + Copy + Send + Sync + 'static,
+    {
+        self.register(
+            name,
+            Signature::new(
+                vec![DType::from(I::PTYPE), DType::from(U::PTYPE)],
+                DType::from(O::PTYPE),
+            ),
+            move |args: &[Array]| args[0].clone().into_primitive()?.binary(args[1].clone(), f),
+        )
+    }
+
+    /// Register an override of `name` for arguments of the given types, used when any argument
+    /// has the given encoding.
+    pub fn register_override(
+        &mut self,
+        name: &str,
+        arguments: &[DType],
+        encoding: EncodingId,
+        function: impl MaybeScalarFunction + 'static,
+    ) -> VortexResult<()> {
+        let overload = self
+            .functions
+            .get_mut(name)
+            .and_then(|overloads| {
+                overloads
+                    .iter_mut()
+                    .find(|o| o.signature.matches(arguments))
+            })
+            .ok_or_else(|| {
+                vortex_err!(
+                    "Function {name} has no signature for ({})",
+                    arguments.iter().join(", ")
+                )
+            })?;
+        overload.overrides.insert(encoding, Arc::new(function));
+        Ok(())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.functions.keys().map(String::as_str)
+    }
+
+    /// The signature of `name` that accepts arguments of the given types, if any.
+    pub fn resolve(&self, name: &str, arguments: &[DType]) -> Option<&Signature> {
+        self.overload(name, arguments).map(|o| &o.signature)
+    }
+
+    fn overload(&self, name: &str, arguments: &[DType]) -> Option<&Overload> {
+        self.functions
+            .get(name)?
+            .iter()
+            .find(|o| o.signature.matches(arguments))
+    }
+
+    /// Invoke `name` element-wise over the given arguments.
+    ///
+    /// Overrides registered for the encodings of the arguments are tried in argument order, before
+    /// falling back to the default implementation over canonicalized arguments.
+    pub fn invoke(&self, name: &str, args: &[Array]) -> VortexResult<Array> {
+        let dtypes = args.iter().map(|a| a.dtype().clone()).collect::<Vec<_>>();
+        let overload = self.overload(name, &dtypes).ok_or_else(|| {
+            if self.contains(name) {
+                vortex_err!(
+                    "No signature of function {name} accepts ({})",
+                    dtypes.iter().join(", ")
+                )
+            } else {
+                vortex_err!("Unknown function {name}")
+            }
+        })?;
+        let len = args.first().map(|a| a.len()).unwrap_or_default();
+        if args.iter().any(|a| a.len() != len) {
+            vortex_bail!("Arguments to function {name} must have the same length");
+        }
+
+        let result = args
+            .iter()
+            .filter_map(|a| overload.overrides.get(&a.encoding().id()))
+            .find_map(|f| f.maybe_invoke(args));
+        let result = match result {
+            Some(result) => result?,
+            None => {
+                let canonical = args
+                    .iter()
+                    .map(|a| a.clone().into_canonical().map(Array::from))
+                    .collect::<VortexResult<Vec<_>>>()?;
+                overload.function.invoke(&canonical)?
+            }
+        };
+
+        let expected = overload.signature.result_dtype(&dtypes);
+        if result.len() != len || !result.dtype().eq_ignore_nullability(&expected) {
+            vortex_bail!(
+                "Function {name} returned {} values of type {}, expected {len} values of type {expected}",
+                result.len(),
+                result.dtype()
+            );
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+
+    use crate::array::{Constant, ConstantArray, PrimitiveArray};
+    use crate::function::{FunctionRegistry, Signature};
+    use crate::{Array, ArrayDType, ArrayDef, IntoArray, IntoArrayVariant};
+
+    fn registry() -> FunctionRegistry {
+        let mut registry = FunctionRegistry::new();
+        registry
+            .register_unary("double", |v: i32| v.wrapping_mul(2))
+            .unwrap();
+        registry
+            .register_binary("hypot", |x: f64, y: f64| x.hypot(y))
+            .unwrap();
+        registry
+    }
+
+    #[test]
+    fn invoke_native() {
+        let registry = registry();
+        let values = PrimitiveArray::from_nullable_vec(vec![Some(1i32), None, Some(-4)]);
+        let doubled = registry
+            .invoke("double", &[values.into_array()])
+            .unwrap()
+            .into_primitive()
+            .unwrap();
+        assert_eq!(doubled.maybe_null_slice::<i32>()[..1], [2]);
+        assert_eq!(doubled.maybe_null_slice::<i32>()[2], -8);
+        assert!(doubled.validity().is_null(1));
+
+        let x = PrimitiveArray::from(vec![3.0f64, 5.0]).into_array();
+        let y = PrimitiveArray::from(vec![4.0f64, 12.0]).into_array();
+        let hypot = registry.invoke("hypot", &[x, y]).unwrap();
+        assert_eq!(
+            hypot.into_primitive().unwrap().maybe_null_slice::<f64>(),
+            &[5.0, 13.0]
+        );
+    }
+
+    #[test]
+    fn encoding_override() {
+        let mut registry = registry();
+        registry
+            .register_override(
+                "double",
+                &[DType::Primitive(PType::I32, Nullability::NonNullable)],
+                Constant::ID,
+                |args: &[Array]| {
+                    let value =
+                        i32::try_from(ConstantArray::try_from(&args[0]).ok()?.owned_scalar())
+                            .ok()?;
+                    Some(Ok(ConstantArray::new(value * 2, args[0].len()).into_array()))
+                },
+            )
+            .unwrap();
+
+        let result = registry
+            .invoke("double", &[ConstantArray::new(21i32, 3).into_array()])
+            .unwrap();
+        assert!(result.is_encoding(Constant::ID));
+        assert_eq!(result.len(), 3);
+
+        // Other encodings still use the default implementation.
+        let result = registry
+            .invoke("double", &[PrimitiveArray::from(vec![1i32]).into_array()])
+            .unwrap();
+        assert_eq!(
+            result.dtype(),
+            &DType::Primitive(PType::I32, Nullability::NonNullable)
+        );
+    }
+
+    #[test]
+    fn resolution_errors() {
+        let mut registry = registry();
+        let ints = PrimitiveArray::from(vec![1u8]).into_array();
+        assert!(registry.invoke("missing", &[ints.clone()]).is_err());
+        assert!(registry.invoke("double", &[ints]).is_err());
+        assert!(registry.register_unary("double", |v: i32| v + v).is_err());
+        assert!(registry
+            .register(
+                "broken",
+                Signature::new(vec![DType::from(PType::I32)], DType::from(PType::I64)),
+                |args: &[Array]| Ok(args[0].clone()),
+            )
+            .is_ok());
+        assert!(registry
+            .invoke("broken", &[PrimitiveArray::from(vec![1i32]).into_array()])
+            .is_err());
+    }
+}
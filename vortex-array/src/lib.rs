@@ -44,6 +44,7 @@ mod context;
 mod data;
 pub mod elementwise;
 pub mod encoding;
+pub mod function;
 mod implementation;
 pub mod iter;
 mod metadata;
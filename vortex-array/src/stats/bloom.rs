@@ -0,0 +1,221 @@
+use vortex_buffer::Buffer;
+use vortex_dtype::{match_each_native_ptype, DType, NativePType, Nullability};
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+use crate::stats::hll::hash_bytes;
+
+/// A split-block bloom filter over the byte representation of the non-null values of an array.
+///
+/// Each value sets one bit in each of the eight words of a single 256-bit block, so a lookup
+/// touches one cache line. A filter never reports a value that was inserted as absent, which makes
+/// it suitable for skipping chunks that cannot contain a key.
+///
+/// Floating point values are normalized before they are hashed so that `-0.0` matches `0.0` and
+/// every NaN matches every other NaN.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BloomFilter {
+    blocks: Vec<[u32; 8]>,
+}
+
+const SALT: [u32; 8] = [
+    0x47b6137b, 0x44974d91, 0x8824ad5b, 0xa2b7289d, 0x705495c7, 0x2df1424b, 0x9efc4947, 0x5c6bfb31,
+];
+
+const BLOCK_BYTES: usize = 32;
+
+const NAN_KEY: &[u8] = b"NaN";
+
+impl BloomFilter {
+    /// Bits allotted to each value, giving a false positive rate of about 1%.
+    pub const BITS_PER_VALUE: usize = 10;
+
+    /// The largest filter built for a single array, 2MiB.
+    pub const MAX_BLOCKS: usize = 1 << 16;
+
+    /// An empty filter sized for `num_values` values.
+    pub fn with_capacity(num_values: usize) -> Self {
+        let num_blocks = (num_values * Self::BITS_PER_VALUE)
+            .div_ceil(BLOCK_BYTES * 8)
+            .next_power_of_two()
+            .min(Self::MAX_BLOCKS);
+        Self {
+            blocks: vec![[0; 8]; num_blocks],
+        }
+    }
+
+    pub fn insert(&mut self, value: &[u8]) {
+        let (block, mask) = self.block_mask(value);
+        for (word, bit) in self.blocks[block].iter_mut().zip(mask) {
+            *word |= bit;
+        }
+    }
+
+    /// Whether the value may have been inserted. `false` means it certainly wasn't.
+    pub fn might_contain(&self, value: &[u8]) -> bool {
+        let (block, mask) = self.block_mask(value);
+        self.blocks[block]
+            .iter()
+            .zip(mask)
+            .all(|(word, bit)| word & bit != 0)
+    }
+
+    pub fn insert_native<T: NativePType>(&mut self, value: T) {
+        if value.is_nan() {
+            self.insert(NAN_KEY)
+        } else if value.is_zero() {
+            self.insert(T::zero().to_le_bytes().as_ref())
+        } else {
+            self.insert(value.to_le_bytes().as_ref())
+        }
+    }
+
+    pub fn might_contain_native<T: NativePType>(&self, value: T) -> bool {
+        if value.is_nan() {
+            self.might_contain(NAN_KEY)
+        } else if value.is_zero() {
+            self.might_contain(T::zero().to_le_bytes().as_ref())
+        } else {
+            self.might_contain(value.to_le_bytes().as_ref())
+        }
+    }
+
+    /// Whether a filter built from values of `dtype` may contain the scalar.
+    ///
+    /// The scalar is cast to `dtype` first, and anything that cannot be looked up is assumed to be
+    /// present.
+    pub fn might_contain_scalar(&self, value: &Scalar, dtype: &DType) -> bool {
+        if value.is_null() {
+            return true;
+        }
+        let Ok(value) = value.cast(dtype) else {
+            return true;
+        };
+        match dtype {
+            DType::Primitive(ptype, _) => match_each_native_ptype!(ptype, |$T| {
+                <$T>::try_from(&value).map_or(true, |v| self.might_contain_native(v))
+            }),
+            DType::Utf8(_) | DType::Binary(_) => {
+                Buffer::try_from(&value).map_or(true, |v| self.might_contain(v.as_slice()))
+            }
+            _ => true,
+        }
+    }
+
+    /// Combine with a filter of the same values' type, folding the larger filter down to the size
+    /// of the smaller one.
+    pub fn merge(&mut self, other: &Self) {
+        let num_blocks = self.blocks.len().min(other.blocks.len());
+        let mut blocks = vec![[0u32; 8]; num_blocks];
+        for (i, block) in self.blocks.iter().chain(other.blocks.iter()).enumerate() {
+            let folded = &mut blocks[i % num_blocks];
+            for (word, bits) in folded.iter_mut().zip(block) {
+                *word |= bits;
+            }
+        }
+        self.blocks = blocks;
+    }
+
+    /// The block is chosen by the high half of the hash, which works modulo the power of two
+    /// number of blocks so that filters can be folded, and the low half sets one bit per word.
+    fn block_mask(&self, value: &[u8]) -> (usize, [u32; 8]) {
+        let hash = hash_bytes(value);
+        let block = ((hash >> 32) as usize) & (self.blocks.len() - 1);
+        let key = hash as u32;
+        let mut mask = [0u32; 8];
+        for (bit, salt) in mask.iter_mut().zip(SALT) {
+            *bit = 1 << (key.wrapping_mul(salt) >> 27);
+        }
+        (block, mask)
+    }
+}
+
+/// The filter is stored as the little-endian words of its blocks.
+impl From<BloomFilter> for Scalar {
+    fn from(value: BloomFilter) -> Self {
+        let bytes = value
+            .blocks
+            .iter()
+            .flatten()
+            .flat_map(|w| w.to_le_bytes())
+            .collect::<Vec<_>>();
+        Scalar::binary(Buffer::from(bytes), Nullability::NonNullable)
+    }
+}
+
+impl TryFrom<&Scalar> for BloomFilter {
+    type Error = VortexError;
+
+    fn try_from(value: &Scalar) -> VortexResult<Self> {
+        let buffer = Buffer::try_from(value)?;
+        let num_blocks = buffer.len() / BLOCK_BYTES;
+        if buffer.len() % BLOCK_BYTES != 0 || !num_blocks.is_power_of_two() {
+            vortex_bail!("Invalid bloom filter of {} bytes", buffer.len());
+        }
+        let blocks = buffer
+            .chunks_exact(BLOCK_BYTES)
+            .map(|block| {
+                let mut words = [0u32; 8];
+                for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+                    *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                }
+                words
+            })
+            .collect();
+        Ok(Self { blocks })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_dtype::{DType, Nullability, PType};
+    use vortex_scalar::Scalar;
+
+    use crate::stats::BloomFilter;
+
+    #[test]
+    fn no_false_negatives() {
+        let mut bloom = BloomFilter::with_capacity(10_000);
+        for i in 0u64..10_000 {
+            bloom.insert_native(i * 2);
+        }
+        assert!((0u64..10_000).all(|i| bloom.might_contain_native(i * 2)));
+
+        let false_positives = (0u64..10_000)
+            .filter(|i| bloom.might_contain_native(i * 2 + 1))
+            .count();
+        assert!(false_positives < 300, "{false_positives} false positives");
+
+        let roundtrip = BloomFilter::try_from(&Scalar::from(bloom.clone())).unwrap();
+        assert_eq!(roundtrip, bloom);
+    }
+
+    #[test]
+    fn merge_folds_to_smaller() {
+        let mut small = BloomFilter::with_capacity(100);
+        let mut large = BloomFilter::with_capacity(10_000);
+        small.insert(b"small");
+        large.insert(b"large");
+
+        large.merge(&small);
+        assert_eq!(large, {
+            let mut expected = small.clone();
+            expected.insert(b"large");
+            expected
+        });
+        assert!(large.might_contain(b"small") && large.might_contain(b"large"));
+    }
+
+    #[test]
+    fn normalized_floats_and_casts() {
+        let mut bloom = BloomFilter::with_capacity(4);
+        bloom.insert_native(-0.0f64);
+        bloom.insert_native(f64::NAN);
+        bloom.insert_native(5f64);
+
+        let dtype = DType::Primitive(PType::F64, Nullability::Nullable);
+        assert!(bloom.might_contain_scalar(&0.0f64.into(), &dtype));
+        assert!(bloom.might_contain_scalar(&(-f64::NAN).into(), &dtype));
+        assert!(bloom.might_contain_scalar(&5i32.into(), &dtype));
+    }
+}
@@ -1,5 +1,6 @@
 use flatbuffers::{FlatBufferBuilder, WIPOffset};
 use itertools::Itertools;
+use vortex_buffer::Buffer;
 use vortex_flatbuffers::WriteFlatBuffer;
 
 use crate::stats::{Stat, Statistics};
@@ -29,6 +30,22 @@ impl WriteFlatBuffer for &dyn Statistics {
             .get(Stat::Max)
             .map(|max| max.value().write_flatbuffer(fbb));
 
+        let approx_distinct = self
+            .get_as::<Buffer>(Stat::ApproxDistinct)
+            .map(|v| fbb.create_vector(v.as_slice()));
+
+        let approx_quantiles = self
+            .get_as::<Buffer>(Stat::ApproxQuantiles)
+            .map(|v| fbb.create_vector(v.as_slice()));
+
+        let histogram = self
+            .get_as::<Buffer>(Stat::Histogram)
+            .map(|v| fbb.create_vector(v.as_slice()));
+
+        let sum = self
+            .get(Stat::Sum)
+            .map(|sum| sum.value().write_flatbuffer(fbb));
+
         let stat_args = &crate::flatbuffers::ArrayStatsArgs {
             min,
             max,
@@ -40,6 +57,14 @@ impl WriteFlatBuffer for &dyn Statistics {
             null_count: self.get_as_cast::<u64>(Stat::NullCount),
             bit_width_freq,
             trailing_zero_freq,
+            approx_distinct,
+            approx_quantiles,
+            sum,
+            nan_count: self.get_as_cast::<u64>(Stat::NaNCount),
+            uncompressed_size_in_bytes: self.get_as_cast::<u64>(Stat::UncompressedSizeInBytes),
+            min_is_bound: (min.is_some() && !self.is_exact(Stat::Min)).then_some(true),
+            max_is_bound: (max.is_some() && !self.is_exact(Stat::Max)).then_some(true),
+            histogram,
         };
 
         crate::flatbuffers::ArrayStats::create(fbb, stat_args)
@@ -0,0 +1,264 @@
+use vortex_buffer::Buffer;
+use vortex_dtype::Nullability;
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+/// An equi-depth histogram of the non-null, non-NaN values of a numeric array.
+///
+/// Each bucket covers an inclusive range of values and holds roughly the same number of them, so
+/// skewed data gets narrow buckets where values are dense. Runs of equal values are never split
+/// across buckets, which keeps equality estimates for heavy hitters accurate.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Histogram {
+    buckets: Vec<Bucket>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Bucket {
+    lower: f64,
+    upper: f64,
+    count: u64,
+    distinct: u64,
+}
+
+const BUCKET_BYTES: usize = 32;
+
+impl Histogram {
+    /// Build a histogram of at most `num_buckets` buckets, ignoring NaN values.
+    pub fn from_values<I: IntoIterator<Item = f64>>(values: I, num_buckets: usize) -> Self {
+        let mut values = values
+            .into_iter()
+            .filter(|v| !v.is_nan())
+            .collect::<Vec<_>>();
+        values.sort_by(f64::total_cmp);
+
+        let total = values.len() as u64;
+        let num_buckets = num_buckets.max(1);
+        let depth = total.div_ceil(num_buckets as u64);
+        let mut buckets: Vec<Bucket> = Vec::new();
+        let mut current: Option<Bucket> = None;
+        let mut seen = 0u64;
+        let mut iter = values.into_iter().peekable();
+        while let Some(value) = iter.next() {
+            let mut run = 1;
+            while iter.next_if(|next| *next == value).is_some() {
+                run += 1;
+            }
+            seen += run;
+
+            // Values that fill a bucket on their own get one.
+            if run >= depth {
+                buckets.extend(current.take());
+            }
+            let bucket = current.get_or_insert(Bucket {
+                lower: value,
+                upper: value,
+                count: 0,
+                distinct: 0,
+            });
+            bucket.upper = value;
+            bucket.count += run;
+            bucket.distinct += 1;
+
+            // Close the bucket once it reaches its share of the values seen so far.
+            let boundary = total * (buckets.len() as u64 + 1) / num_buckets as u64;
+            if seen >= boundary {
+                buckets.extend(current.take());
+            }
+        }
+        buckets.extend(current);
+        shrink(&mut buckets, num_buckets);
+        Self { buckets }
+    }
+
+    /// The number of values in the histogram.
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().map(|b| b.count).sum()
+    }
+
+    pub fn num_buckets(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Combine with the histogram of other values, keeping as many buckets as the larger of the
+    /// two.
+    pub fn merge(&mut self, other: &Self) {
+        let max_buckets = self.buckets.len().max(other.buckets.len());
+        let mut buckets = self.buckets.clone();
+        buckets.extend_from_slice(&other.buckets);
+        buckets.sort_by(|a, b| {
+            a.lower
+                .total_cmp(&b.lower)
+                .then(a.upper.total_cmp(&b.upper))
+        });
+        shrink(&mut buckets, max_buckets);
+        self.buckets = buckets;
+    }
+
+    /// The estimated fraction of values equal to `value`, or `None` if the histogram is empty.
+    pub fn fraction_eq(&self, value: f64) -> Option<f64> {
+        let total = self.count();
+        if total == 0 {
+            return None;
+        }
+        let matching = self
+            .buckets
+            .iter()
+            .filter(|b| b.lower <= value && value <= b.upper)
+            .map(|b| b.count as f64 / b.distinct.max(1) as f64)
+            .sum::<f64>();
+        Some((matching / total as f64).min(1.0))
+    }
+
+    /// The estimated fraction of values less than or equal to `value`, or `None` if the histogram
+    /// is empty.
+    ///
+    /// Values are assumed to be spread evenly within a bucket.
+    pub fn fraction_le(&self, value: f64) -> Option<f64> {
+        let total = self.count();
+        if total == 0 {
+            return None;
+        }
+        let below = self
+            .buckets
+            .iter()
+            .map(|b| {
+                if value >= b.upper {
+                    b.count as f64
+                } else if value < b.lower {
+                    0.0
+                } else {
+                    let spread = (value - b.lower) / (b.upper - b.lower);
+                    let spread = if spread.is_finite() { spread } else { 0.5 };
+                    // At least the values equal to the lower bound lie below `value`.
+                    (b.count as f64 * spread).max(b.count as f64 / b.distinct.max(1) as f64)
+                }
+            })
+            .sum::<f64>();
+        Some((below / total as f64).min(1.0))
+    }
+
+    /// The estimated fraction of values strictly less than `value`, or `None` if the histogram is
+    /// empty.
+    pub fn fraction_lt(&self, value: f64) -> Option<f64> {
+        let le = self.fraction_le(value)?;
+        let eq = self.fraction_eq(value)?;
+        Some((le - eq).max(0.0))
+    }
+}
+
+/// Join the neighbouring buckets that hold the fewest values until at most `max_buckets` remain.
+fn shrink(buckets: &mut Vec<Bucket>, max_buckets: usize) {
+    while buckets.len() > max_buckets.max(1) {
+        let Some(smallest) =
+            (0..buckets.len() - 1).min_by_key(|&i| buckets[i].count + buckets[i + 1].count)
+        else {
+            return;
+        };
+        let next = buckets.remove(smallest + 1);
+        let bucket = &mut buckets[smallest];
+        // Buckets of different arrays may overlap, in which case their values may be shared.
+        bucket.distinct = if bucket.upper < next.lower {
+            bucket.distinct + next.distinct
+        } else {
+            bucket.distinct.max(next.distinct)
+        };
+        bucket.lower = bucket.lower.min(next.lower);
+        bucket.upper = bucket.upper.max(next.upper);
+        bucket.count += next.count;
+    }
+}
+
+/// The histogram is stored as the little-endian lower bound, upper bound, count and distinct
+/// count of each bucket.
+impl From<Histogram> for Scalar {
+    fn from(value: Histogram) -> Self {
+        let mut bytes = Vec::with_capacity(BUCKET_BYTES * value.buckets.len());
+        for b in &value.buckets {
+            bytes.extend_from_slice(&b.lower.to_le_bytes());
+            bytes.extend_from_slice(&b.upper.to_le_bytes());
+            bytes.extend_from_slice(&b.count.to_le_bytes());
+            bytes.extend_from_slice(&b.distinct.to_le_bytes());
+        }
+        Scalar::binary(Buffer::from(bytes), Nullability::NonNullable)
+    }
+}
+
+impl TryFrom<&Scalar> for Histogram {
+    type Error = VortexError;
+
+    fn try_from(value: &Scalar) -> VortexResult<Self> {
+        let buffer = Buffer::try_from(value)?;
+        if buffer.len() % BUCKET_BYTES != 0 {
+            vortex_bail!("Invalid histogram of {} bytes", buffer.len());
+        }
+        let buckets = buffer
+            .chunks_exact(BUCKET_BYTES)
+            .map(|bucket| {
+                let word = |i: usize| {
+                    let mut bytes = [0u8; 8];
+                    bytes.copy_from_slice(&bucket[i * 8..(i + 1) * 8]);
+                    bytes
+                };
+                Bucket {
+                    lower: f64::from_le_bytes(word(0)),
+                    upper: f64::from_le_bytes(word(1)),
+                    count: u64::from_le_bytes(word(2)),
+                    distinct: u64::from_le_bytes(word(3)),
+                }
+            })
+            .collect();
+        Ok(Self { buckets })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::stats::Histogram;
+
+    #[test]
+    fn equi_depth() {
+        let histogram = Histogram::from_values((0..1000).map(f64::from), 10);
+        assert_eq!(histogram.num_buckets(), 10);
+        assert_eq!(histogram.count(), 1000);
+        assert!(histogram.buckets.iter().all(|b| b.count == 100));
+
+        let le = histogram.fraction_le(249.0).unwrap();
+        assert!((le - 0.25).abs() < 0.01, "{le}");
+        assert_eq!(histogram.fraction_le(-1.0), Some(0.0));
+        assert_eq!(histogram.fraction_le(5000.0), Some(1.0));
+        assert_eq!(histogram.fraction_eq(500.0), Some(0.001));
+        assert_eq!(histogram.fraction_eq(5000.0), Some(0.0));
+
+        let roundtrip = Histogram::try_from(&Scalar::from(histogram.clone())).unwrap();
+        assert_eq!(roundtrip, histogram);
+        assert_eq!(Histogram::default().fraction_le(0.0), None);
+    }
+
+    #[test]
+    fn heavy_hitters_are_not_split() {
+        let values = (0..100)
+            .map(f64::from)
+            .chain(std::iter::repeat(7.0).take(900))
+            .chain([f64::NAN]);
+        let histogram = Histogram::from_values(values, 10);
+        assert_eq!(histogram.count(), 1000);
+        let eq = histogram.fraction_eq(7.0).unwrap();
+        assert!((eq - 0.901).abs() < 0.01, "{eq}");
+        let lt = histogram.fraction_lt(7.0).unwrap();
+        assert!((lt - 0.007).abs() < 0.01, "{lt}");
+    }
+
+    #[test]
+    fn merge_keeps_bucket_count() {
+        let mut histogram = Histogram::from_values((0..500).map(f64::from), 10);
+        histogram.merge(&Histogram::from_values((500..1000).map(f64::from), 10));
+        histogram.merge(&Histogram::default());
+        assert_eq!(histogram.num_buckets(), 10);
+        assert_eq!(histogram.count(), 1000);
+        let le = histogram.fraction_le(749.0).unwrap();
+        assert!((le - 0.75).abs() < 0.02, "{le}");
+    }
+}
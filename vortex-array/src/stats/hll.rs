@@ -0,0 +1,147 @@
+use vortex_buffer::Buffer;
+use vortex_dtype::Nullability;
+use vortex_error::{vortex_bail, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+/// A HyperLogLog sketch estimating the number of distinct values inserted into it.
+///
+/// Values are hashed from their bytes, so sketches are only comparable when they were built from
+/// values of the same type. Two sketches are merged by taking the maximum of each register, which
+/// makes the estimate of a union independent of how its values were split across chunks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// The number of hash bits used to pick a register, giving `2^12` registers and a standard
+    /// error of about 1.6%.
+    pub const PRECISION: u8 = 12;
+
+    pub fn new() -> Self {
+        Self {
+            registers: vec![0; 1 << Self::PRECISION],
+        }
+    }
+
+    pub fn insert(&mut self, value: &[u8]) {
+        let hash = hash_bytes(value);
+        let index = (hash >> (64 - Self::PRECISION)) as usize;
+        // Set a guard bit so that the rank is bounded by the remaining hash bits.
+        let remaining = (hash << Self::PRECISION) | (1 << (Self::PRECISION - 1));
+        let rank = remaining.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        for (own, other) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *own = (*own).max(*other);
+        }
+    }
+
+    /// The estimated number of distinct values inserted into the sketch.
+    pub fn estimate(&self) -> u64 {
+        let m = self.registers.len() as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|&r| 2f64.powi(-i32::from(r)))
+            .sum();
+        let estimate = alpha * m * m / sum;
+
+        // Fall back to linear counting while many registers are still empty.
+        let zeros = self.registers.iter().filter(|&&r| r == 0).count();
+        if estimate <= 2.5 * m && zeros > 0 {
+            (m * (m / zeros as f64).ln()).round() as u64
+        } else {
+            estimate.round() as u64
+        }
+    }
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// FNV-1a followed by the MurmurHash3 finalizer, which is stable across platforms and releases so
+/// that persisted sketches remain mergeable.
+pub(super) fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for &b in bytes {
+        hash ^= u64::from(b);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xff51afd7ed558ccd);
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(0xc4ceb9fe1a85ec53);
+    hash ^ (hash >> 33)
+}
+
+/// The sketch is stored as its precision followed by one byte per register.
+impl From<HyperLogLog> for Scalar {
+    fn from(value: HyperLogLog) -> Self {
+        let mut bytes = Vec::with_capacity(value.registers.len() + 1);
+        bytes.push(HyperLogLog::PRECISION);
+        bytes.extend_from_slice(&value.registers);
+        Scalar::binary(Buffer::from(bytes), Nullability::NonNullable)
+    }
+}
+
+impl TryFrom<&Scalar> for HyperLogLog {
+    type Error = VortexError;
+
+    fn try_from(value: &Scalar) -> VortexResult<Self> {
+        let buffer = Buffer::try_from(value)?;
+        let Some((&precision, registers)) = buffer.split_first() else {
+            vortex_bail!("Empty HyperLogLog sketch");
+        };
+        if precision != Self::PRECISION || registers.len() != 1 << precision {
+            vortex_bail!(
+                "Unsupported HyperLogLog sketch with precision {} and {} registers",
+                precision,
+                registers.len()
+            );
+        }
+        Ok(Self {
+            registers: registers.to_vec(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::stats::HyperLogLog;
+
+    #[test]
+    fn estimate_and_merge() {
+        let mut first = HyperLogLog::new();
+        let mut second = HyperLogLog::new();
+        for i in 0u64..20_000 {
+            first.insert(&i.to_le_bytes());
+            second.insert(&(i + 10_000).to_le_bytes());
+        }
+        assert!(first.estimate().abs_diff(20_000) < 1_000);
+
+        first.merge(&second);
+        assert!(first.estimate().abs_diff(30_000) < 1_500);
+
+        let roundtrip = HyperLogLog::try_from(&Scalar::from(first.clone())).unwrap();
+        assert_eq!(roundtrip, first);
+    }
+
+    #[test]
+    fn small_cardinality() {
+        let mut hll = HyperLogLog::new();
+        assert_eq!(hll.estimate(), 0);
+        for v in ["a", "b", "c", "a", "b"] {
+            hll.insert(v.as_bytes());
+        }
+        assert_eq!(hll.estimate(), 3);
+    }
+}
@@ -3,18 +3,26 @@
 use std::fmt::{Display, Formatter};
 use std::hash::Hash;
 
+pub use bloom::BloomFilter;
 use enum_iterator::Sequence;
+pub use histogram::Histogram;
+pub use hll::HyperLogLog;
 use itertools::Itertools;
 pub use statsset::*;
+pub use tdigest::TDigest;
 use vortex_dtype::Nullability::NonNullable;
-use vortex_dtype::{DType, NativePType};
+use vortex_dtype::{DType, NativePType, PType};
 use vortex_error::{vortex_panic, VortexError, VortexResult};
 use vortex_scalar::Scalar;
 
-use crate::Array;
+use crate::{Array, ArrayDType, IntoCanonical};
 
+mod bloom;
 pub mod flatbuffers;
+mod histogram;
+mod hll;
 mod statsset;
+mod tdigest;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
 pub enum Stat {
@@ -28,6 +36,22 @@ pub enum Stat {
     RunCount,
     TrueCount,
     NullCount,
+    /// A [HyperLogLog] sketch of the distinct non-null values.
+    ApproxDistinct,
+    /// A [TDigest] of the non-null, non-NaN values of a numeric array.
+    ApproxQuantiles,
+    /// The sum of the non-null values of a numeric array, in the type given by [sum_ptype]. Absent
+    /// if an integer sum overflows.
+    Sum,
+    /// The number of NaN values of a floating point array.
+    NaNCount,
+    /// The number of bytes the array occupies once it has been canonicalized.
+    UncompressedSizeInBytes,
+    /// A [BloomFilter] of the non-null values of a primitive, utf8 or binary array.
+    BloomFilter,
+    /// An equi-depth [Histogram] of the non-null, non-NaN values of a numeric array, with
+    /// [StatsOptions::histogram_buckets] buckets.
+    Histogram,
 }
 
 impl Display for Stat {
@@ -43,6 +67,13 @@ impl Display for Stat {
             Self::RunCount => write!(f, "run_count"),
             Self::TrueCount => write!(f, "true_count"),
             Self::NullCount => write!(f, "null_count"),
+            Self::ApproxDistinct => write!(f, "approx_distinct"),
+            Self::ApproxQuantiles => write!(f, "approx_quantiles"),
+            Self::Sum => write!(f, "sum"),
+            Self::NaNCount => write!(f, "nan_count"),
+            Self::UncompressedSizeInBytes => write!(f, "uncompressed_size_in_bytes"),
+            Self::BloomFilter => write!(f, "bloom_filter"),
+            Self::Histogram => write!(f, "histogram"),
         }
     }
 }
@@ -51,13 +82,23 @@ pub trait Statistics {
     /// Returns the value of the statistic only if it's present
     fn get(&self, stat: Stat) -> Option<Scalar>;
 
+    /// Whether the statistic is present and exact rather than only a bound, see [StatsSet].
+    fn is_exact(&self, stat: Stat) -> bool;
+
     /// Get all existing statistics
     fn to_set(&self) -> StatsSet;
 
     fn set(&self, stat: Stat, value: Scalar);
 
     /// Computes the value of the stat if it's not present
-    fn compute(&self, stat: Stat) -> Option<Scalar>;
+    fn compute(&self, stat: Stat) -> Option<Scalar> {
+        self.compute_with_options(stat, &StatsOptions::default())
+    }
+
+    /// Computes the value of the stat with the given options if it's not present.
+    ///
+    /// A stat that is already present is returned as is, whatever options it was computed with.
+    fn compute_with_options(&self, stat: Stat, options: &StatsOptions) -> Option<Scalar>;
 }
 
 pub trait ArrayStatistics {
@@ -69,6 +110,16 @@ pub trait ArrayStatisticsCompute {
     fn compute_statistics(&self, _stat: Stat) -> VortexResult<StatsSet> {
         Ok(StatsSet::new())
     }
+
+    /// Compute the requested statistic with the given options. Only encodings whose statistics
+    /// depend on the options need to implement this.
+    fn compute_statistics_with_options(
+        &self,
+        stat: Stat,
+        _options: &StatsOptions,
+    ) -> VortexResult<StatsSet> {
+        self.compute_statistics(stat)
+    }
 }
 
 impl dyn Statistics + '_ {
@@ -173,6 +224,159 @@ impl dyn Statistics + '_ {
     pub fn compute_trailing_zero_freq(&self) -> Option<Vec<usize>> {
         self.compute_as::<Vec<usize>>(Stat::TrailingZeroFreq)
     }
+
+    pub fn compute_sum<U: for<'a> TryFrom<&'a Scalar, Error = VortexError>>(&self) -> Option<U> {
+        self.compute_as(Stat::Sum)
+    }
+
+    pub fn compute_nan_count(&self) -> Option<usize> {
+        self.compute_as(Stat::NaNCount)
+    }
+
+    pub fn compute_uncompressed_size_in_bytes(&self) -> Option<usize> {
+        self.compute_as(Stat::UncompressedSizeInBytes)
+    }
+
+    /// The estimated number of distinct non-null values.
+    pub fn compute_approx_distinct_count(&self) -> Option<u64> {
+        self.compute_as::<HyperLogLog>(Stat::ApproxDistinct)
+            .map(|hll| hll.estimate())
+    }
+
+    /// The estimated value below which a fraction `q` of the non-null values lie.
+    pub fn compute_approx_quantile(&self, q: f64) -> Option<f64> {
+        self.compute_as::<TDigest>(Stat::ApproxQuantiles)
+            .and_then(|digest| digest.quantile(q))
+    }
+
+    pub fn compute_bloom_filter(&self) -> Option<BloomFilter> {
+        self.compute_as::<BloomFilter>(Stat::BloomFilter)
+    }
+
+    pub fn compute_histogram(&self) -> Option<Histogram> {
+        self.compute_as::<Histogram>(Stat::Histogram)
+    }
+}
+
+/// Compute a statistic using the array's encoding.
+///
+/// The uncompressed size of encodings that don't report it is derived from the dtype, or from their
+/// children, without decompressing them. Bloom filters of primitive, utf8 and binary arrays, and
+/// histograms of primitive arrays, are built from their canonical values.
+pub(crate) fn compute_statistics(
+    array: &Array,
+    stat: Stat,
+    options: &StatsOptions,
+) -> VortexResult<StatsSet> {
+    let mut stats = array.with_dyn(|a| a.compute_statistics_with_options(stat, options))?;
+    if stat == Stat::UncompressedSizeInBytes && stats.get(stat).is_none() {
+        if let Some(size) = uncompressed_size_in_bytes(array) {
+            stats.set(stat, size.into());
+        }
+        return Ok(stats);
+    }
+    let canonical_fallback = match stat {
+        Stat::BloomFilter => matches!(
+            array.dtype(),
+            DType::Primitive(..) | DType::Utf8(_) | DType::Binary(_)
+        ),
+        Stat::Histogram => matches!(array.dtype(), DType::Primitive(..)),
+        _ => false,
+    };
+    if canonical_fallback && stats.get(stat).is_none() {
+        if let Some(value) = compute_canonical_statistics(array, stat, options)?.get(stat) {
+            stats.set(stat, value.clone());
+        }
+    }
+    Ok(stats)
+}
+
+/// Compute a statistic from the canonical form of an array, for the statistics an encoding cannot
+/// derive from its encoded parts. Everything computed along the way is returned with it.
+pub fn compute_canonical_statistics(
+    array: &Array,
+    stat: Stat,
+    options: &StatsOptions,
+) -> VortexResult<StatsSet> {
+    let canonical = Array::from(array.clone().into_canonical()?);
+    canonical.statistics().compute_with_options(stat, options);
+    Ok(canonical.statistics().to_set())
+}
+
+/// The size of the canonical form of a fixed-width array is its width times its length, plus a
+/// validity bitmap when nullable. Other arrays are as large as their children and buffer combined.
+fn uncompressed_size_in_bytes(array: &Array) -> Option<usize> {
+    let len = array.len();
+    let values = match array.dtype() {
+        DType::Null => return Some(0),
+        DType::Bool(_) => Some(len.div_ceil(8)),
+        DType::Primitive(ptype, _) => Some(ptype.byte_width() * len),
+        _ => None,
+    };
+    if let Some(values) = values {
+        let validity = if array.dtype().is_nullable() {
+            len.div_ceil(8)
+        } else {
+            0
+        };
+        return Some(values + validity);
+    }
+
+    let children = array.children();
+    if children.is_empty() && array.buffer().is_none() {
+        return None;
+    }
+    children.iter().try_fold(
+        array.buffer().map_or(0, |buffer| buffer.len()),
+        |size, child| Some(size + child.statistics().compute_uncompressed_size_in_bytes()?),
+    )
+}
+
+/// The type in which [Stat::Sum] of a numeric array is accumulated, which is wide enough that
+/// sums of narrower integers cannot overflow.
+pub fn sum_ptype(ptype: PType) -> PType {
+    if ptype.is_float() {
+        PType::F64
+    } else if ptype.is_unsigned_int() {
+        PType::U64
+    } else {
+        PType::I64
+    }
+}
+
+/// The default of [StatsOptions::max_string_bound_length].
+pub const DEFAULT_MAX_STRING_BOUND_LENGTH: usize = 64;
+
+/// The default of [StatsOptions::histogram_buckets].
+pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 32;
+
+/// Options for computing statistics, see [Statistics::compute_with_options].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatsOptions {
+    /// The longest utf8 or binary [Stat::Min] and [Stat::Max], in bytes, that is computed for an
+    /// array.
+    ///
+    /// Longer values are truncated to a prefix, rounded up for the maximum, and kept as bounds
+    /// rather than exact statistics, which keeps the stats serialized with every array small.
+    pub max_string_bound_length: usize,
+    /// The number of buckets of each [Stat::Histogram] that is computed for an array.
+    ///
+    /// Histograms merged from several arrays keep as many buckets as the largest of them.
+    pub histogram_buckets: usize,
+    /// Whether a [Stat::Histogram] is computed for every numeric chunk that is written to a file.
+    ///
+    /// Off by default, as it takes a pass over the values of each chunk.
+    pub write_histograms: bool,
+}
+
+impl Default for StatsOptions {
+    fn default() -> Self {
+        Self {
+            max_string_bound_length: DEFAULT_MAX_STRING_BOUND_LENGTH,
+            histogram_buckets: DEFAULT_HISTOGRAM_BUCKETS,
+            write_histograms: false,
+        }
+    }
 }
 
 pub fn trailing_zeros(array: &Array) -> u8 {
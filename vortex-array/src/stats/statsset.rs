@@ -1,22 +1,31 @@
 use std::collections::hash_map::{Entry, IntoIter};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use enum_iterator::all;
 use itertools::Itertools;
-use vortex_dtype::DType;
+use vortex_dtype::{DType, PType};
 use vortex_error::{vortex_panic, VortexError, VortexExpect};
 use vortex_scalar::Scalar;
 
-use crate::stats::Stat;
+use crate::stats::{sum_ptype, BloomFilter, Histogram, HyperLogLog, Stat, TDigest};
 
+/// A set of statistics, each of which is either exact or only a bound on the true value.
+///
+/// Bounds are conservative: a [Stat::Min] bound is no greater than the true minimum and a
+/// [Stat::Max] bound is no less than the true maximum, so they can be used to rule values out but
+/// never to prove that a value is present.
 #[derive(Debug, Clone, Default)]
 pub struct StatsSet {
     values: HashMap<Stat, Scalar>,
+    bounds: HashSet<Stat>,
 }
 
 impl From<HashMap<Stat, Scalar>> for StatsSet {
     fn from(value: HashMap<Stat, Scalar>) -> Self {
-        Self { values: value }
+        Self {
+            values: value,
+            bounds: HashSet::new(),
+        }
     }
 }
 
@@ -24,11 +33,14 @@ impl StatsSet {
     pub fn new() -> Self {
         Self {
             values: HashMap::new(),
+            bounds: HashSet::new(),
         }
     }
 
     /// Specialized constructor for the case where the StatsSet represents
     /// an array consisting entirely of [null](vortex_dtype::DType::Null) values.
+    ///
+    /// Sketches are left out, they are only built when asked for.
     pub fn nulls(len: usize, dtype: &DType) -> Self {
         let mut stats = HashMap::from([
             (Stat::Min, Scalar::null(dtype.clone())),
@@ -55,6 +67,10 @@ impl StatsSet {
                     Stat::TrailingZeroFreq,
                     vec![ptype.byte_width() * 8; ptype.byte_width() * 8 + 1].into(),
                 );
+                stats.insert(Stat::Sum, zero_sum(*ptype));
+                if ptype.is_float() {
+                    stats.insert(Stat::NaNCount, 0.into());
+                }
             }
             _ => {}
         }
@@ -83,8 +99,31 @@ impl StatsSet {
         })
     }
 
+    /// Whether the stat is present and exact rather than only a bound.
+    pub fn is_exact(&self, stat: Stat) -> bool {
+        self.values.contains_key(&stat) && !self.bounds.contains(&stat)
+    }
+
     pub fn set(&mut self, stat: Stat, value: Scalar) {
         self.values.insert(stat, value);
+        self.bounds.remove(&stat);
+    }
+
+    /// Set a stat whose value only bounds the true value, see [StatsSet].
+    pub fn set_bound(&mut self, stat: Stat, value: Scalar) {
+        self.values.insert(stat, value);
+        self.bounds.insert(stat);
+    }
+
+    /// Replace stats with those of `other`, keeping whether they are exact.
+    pub fn update(&mut self, other: Self) {
+        for (stat, value) in other.values {
+            if other.bounds.contains(&stat) {
+                self.set_bound(stat, value);
+            } else {
+                self.set(stat, value);
+            }
+        }
     }
 
     pub fn merge(&mut self, other: &Self) -> &Self {
@@ -100,6 +139,13 @@ impl StatsSet {
                 Stat::RunCount => self.merge_run_count(other),
                 Stat::TrueCount => self.merge_true_count(other),
                 Stat::NullCount => self.merge_null_count(other),
+                Stat::ApproxDistinct => self.merge_approx_distinct(other),
+                Stat::ApproxQuantiles => self.merge_approx_quantiles(other),
+                Stat::Sum => self.merge_sum(other),
+                Stat::NaNCount => self.merge_nan_count(other),
+                Stat::UncompressedSizeInBytes => self.merge_uncompressed_size_in_bytes(other),
+                Stat::BloomFilter => self.merge_bloom_filter(other),
+                Stat::Histogram => self.merge_histogram(other),
             }
         }
 
@@ -118,14 +164,24 @@ impl StatsSet {
     /// result set. For example, if we know the minimums of two arrays, the minimum of their union
     /// is the minimum-of-minimums, but if we only know the minimum of one of the two arrays, we
     /// do not know the minimum of their union.
+    ///
+    /// The merged stat is exact if the winning side is, or if an exact side ties with a bound.
     fn merge_ordered<F: Fn(&Scalar, &Scalar) -> bool>(&mut self, stat: Stat, other: &Self, cmp: F) {
         if let Entry::Occupied(mut e) = self.values.entry(stat) {
             if let Some(ov) = other.get(stat) {
                 if cmp(ov, e.get()) {
                     e.insert(ov.clone());
+                    if other.is_exact(stat) {
+                        self.bounds.remove(&stat);
+                    } else {
+                        self.bounds.insert(stat);
+                    }
+                } else if ov == e.get() && other.is_exact(stat) {
+                    self.bounds.remove(&stat);
                 }
             } else {
                 e.remove();
+                self.bounds.remove(&stat);
             }
         }
     }
@@ -133,7 +189,13 @@ impl StatsSet {
     fn merge_is_constant(&mut self, other: &Self) {
         if let Some(is_constant) = self.get_as(Stat::IsConstant) {
             if let Some(other_is_constant) = other.get_as(Stat::IsConstant) {
-                if is_constant && other_is_constant && self.get(Stat::Min) == other.get(Stat::Min) {
+                // Bounds of two constant arrays may be equal while their values differ.
+                if is_constant
+                    && other_is_constant
+                    && self.is_exact(Stat::Min)
+                    && other.is_exact(Stat::Min)
+                    && self.get(Stat::Min) == other.get(Stat::Min)
+                {
                     return;
                 }
             }
@@ -181,6 +243,14 @@ impl StatsSet {
         self.merge_scalar_stat(other, Stat::NullCount)
     }
 
+    fn merge_nan_count(&mut self, other: &Self) {
+        self.merge_scalar_stat(other, Stat::NaNCount)
+    }
+
+    fn merge_uncompressed_size_in_bytes(&mut self, other: &Self) {
+        self.merge_scalar_stat(other, Stat::UncompressedSizeInBytes)
+    }
+
     fn merge_scalar_stat(&mut self, other: &Self, stat: Stat) {
         if let Entry::Occupied(mut e) = self.values.entry(stat) {
             if let Some(other_value) = other.get_as::<usize>(stat) {
@@ -237,12 +307,89 @@ impl StatsSet {
             }
         }
     }
+
+    /// Integer sums that overflow are dropped, as are sums accumulated in different types.
+    fn merge_sum(&mut self, other: &Self) {
+        if let Entry::Occupied(mut e) = self.values.entry(Stat::Sum) {
+            match other.get(Stat::Sum).and_then(|o| add_sums(e.get(), o)) {
+                Some(sum) => {
+                    e.insert(sum);
+                }
+                None => {
+                    e.remove();
+                }
+            }
+        }
+    }
+
+    fn merge_approx_distinct(&mut self, other: &Self) {
+        self.merge_sketch(other, Stat::ApproxDistinct, HyperLogLog::merge)
+    }
+
+    fn merge_approx_quantiles(&mut self, other: &Self) {
+        self.merge_sketch(other, Stat::ApproxQuantiles, TDigest::merge)
+    }
+
+    fn merge_bloom_filter(&mut self, other: &Self) {
+        self.merge_sketch(other, Stat::BloomFilter, BloomFilter::merge)
+    }
+
+    fn merge_histogram(&mut self, other: &Self) {
+        self.merge_sketch(other, Stat::Histogram, Histogram::merge)
+    }
+
+    /// Sketches summarise their values independently of how they were split, so the merged sketch
+    /// describes the union exactly as a sketch of the whole would.
+    fn merge_sketch<T, F>(&mut self, other: &Self, stat: Stat, merge: F)
+    where
+        T: for<'a> TryFrom<&'a Scalar, Error = VortexError> + Into<Scalar>,
+        F: Fn(&mut T, &T),
+    {
+        if let Entry::Occupied(mut e) = self.values.entry(stat) {
+            if let Some(other_value) = other.get_as::<T>(stat) {
+                let mut self_value = T::try_from(e.get()).unwrap_or_else(|err| {
+                    vortex_panic!(err, "Failed to get stat {} as sketch", stat)
+                });
+                merge(&mut self_value, &other_value);
+                e.insert(self_value.into());
+            } else {
+                e.remove();
+            }
+        }
+    }
+}
+
+fn zero_sum(ptype: PType) -> Scalar {
+    match sum_ptype(ptype) {
+        PType::F64 => 0f64.into(),
+        PType::U64 => 0u64.into(),
+        _ => 0i64.into(),
+    }
+}
+
+fn add_sums(sum: &Scalar, other: &Scalar) -> Option<Scalar> {
+    if sum.dtype() != other.dtype() {
+        return None;
+    }
+    match PType::try_from(sum.dtype()).ok()? {
+        PType::I64 => i64::try_from(sum)
+            .ok()?
+            .checked_add(i64::try_from(other).ok()?)
+            .map(Scalar::from),
+        PType::U64 => u64::try_from(sum)
+            .ok()?
+            .checked_add(u64::try_from(other).ok()?)
+            .map(Scalar::from),
+        PType::F64 => Some((f64::try_from(sum).ok()? + f64::try_from(other).ok()?).into()),
+        _ => None,
+    }
 }
 
 impl Extend<(Stat, Scalar)> for StatsSet {
     #[inline]
     fn extend<T: IntoIterator<Item = (Stat, Scalar)>>(&mut self, iter: T) {
-        self.values.extend(iter)
+        iter.into_iter()
+            .for_each(|(stat, value)| self.set(stat, value))
     }
 }
 
@@ -258,8 +405,9 @@ impl IntoIterator for StatsSet {
 #[cfg(test)]
 mod test {
     use itertools::Itertools;
+    use vortex_dtype::{DType, Nullability, PType};
 
-    use crate::stats::{Stat, StatsSet};
+    use crate::stats::{HyperLogLog, Stat, StatsSet, TDigest};
 
     #[test]
     fn merge_into_min() {
@@ -407,4 +555,89 @@ mod test {
         first.merge(&second);
         assert_eq!(first.get(Stat::IsStrictSorted).cloned(), None);
     }
+
+    #[test]
+    fn merge_sketches() {
+        let mut first_hll = HyperLogLog::new();
+        let mut second_hll = HyperLogLog::new();
+        for i in 0u32..100 {
+            first_hll.insert(&i.to_le_bytes());
+            second_hll.insert(&(i + 50).to_le_bytes());
+        }
+        let mut first = StatsSet::of(Stat::ApproxDistinct, first_hll.into());
+        first.set(
+            Stat::ApproxQuantiles,
+            TDigest::from_values((0..100).map(f64::from)).into(),
+        );
+        let mut second = StatsSet::of(Stat::ApproxDistinct, second_hll.into());
+        second.set(
+            Stat::ApproxQuantiles,
+            TDigest::from_values((100..200).map(f64::from)).into(),
+        );
+        first.merge(&second);
+
+        let hll = HyperLogLog::try_from(first.get(Stat::ApproxDistinct).unwrap()).unwrap();
+        assert_eq!(hll.estimate(), 150);
+        let digest = TDigest::try_from(first.get(Stat::ApproxQuantiles).unwrap()).unwrap();
+        assert_eq!(digest.count(), 200);
+        assert_eq!(digest.quantile(1.0), Some(199.0));
+
+        first.merge(&StatsSet::new());
+        assert_eq!(first.get(Stat::ApproxDistinct), None);
+        assert_eq!(first.get(Stat::ApproxQuantiles), None);
+    }
+
+    #[test]
+    fn nulls_without_sketches() {
+        let nulls = StatsSet::nulls(10, &DType::Primitive(PType::I32, Nullability::Nullable));
+        assert_eq!(nulls.get_as::<usize>(Stat::NullCount), Some(10));
+        assert_eq!(nulls.get(Stat::ApproxDistinct), None);
+        assert_eq!(nulls.get(Stat::ApproxQuantiles), None);
+        assert_eq!(nulls.get(Stat::Histogram), None);
+    }
+
+    #[test]
+    fn merge_sums() {
+        let mut first = StatsSet::of(Stat::Sum, 37i64.into());
+        first.set(Stat::NaNCount, 1.into());
+        first.set(Stat::UncompressedSizeInBytes, 64.into());
+        let mut second = StatsSet::of(Stat::Sum, 42i64.into());
+        second.set(Stat::NaNCount, 2.into());
+        second.set(Stat::UncompressedSizeInBytes, 32.into());
+        first.merge(&second);
+        assert_eq!(first.get(Stat::Sum).cloned(), Some(79i64.into()));
+        assert_eq!(first.get(Stat::NaNCount).cloned(), Some(3u64.into()));
+        assert_eq!(
+            first.get(Stat::UncompressedSizeInBytes).cloned(),
+            Some(96u64.into())
+        );
+
+        first.merge(&StatsSet::of(Stat::Sum, i64::MAX.into()));
+        assert_eq!(first.get(Stat::Sum), None);
+    }
+
+    #[test]
+    fn merge_bounds() {
+        let mut first = StatsSet::new();
+        first.set_bound(Stat::Min, 10.into());
+        first.set(Stat::Max, 20.into());
+        let mut second = StatsSet::new();
+        second.set(Stat::Min, 10.into());
+        second.set_bound(Stat::Max, 30.into());
+        first.merge(&second);
+        assert_eq!(first.get(Stat::Min).cloned(), Some(10.into()));
+        assert!(first.is_exact(Stat::Min));
+        assert_eq!(first.get(Stat::Max).cloned(), Some(30.into()));
+        assert!(!first.is_exact(Stat::Max));
+    }
+
+    #[test]
+    fn merge_is_constant_needs_exact_min() {
+        let mut first = StatsSet::of(Stat::IsConstant, true.into());
+        first.set_bound(Stat::Min, 1.into());
+        let mut second = StatsSet::of(Stat::IsConstant, true.into());
+        second.set_bound(Stat::Min, 1.into());
+        first.merge(&second);
+        assert_eq!(first.get(Stat::IsConstant).cloned(), Some(false.into()));
+    }
 }
@@ -0,0 +1,235 @@
+use std::f64::consts::PI;
+
+use vortex_buffer::Buffer;
+use vortex_dtype::Nullability;
+use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
+use vortex_scalar::Scalar;
+
+/// A merging t-digest summarising the distribution of numeric values, from which quantiles can be
+/// estimated.
+///
+/// Values are grouped into weighted centroids that are small near the extremes of the distribution
+/// and larger around the median, which keeps tail quantiles accurate with a bounded number of
+/// centroids. Digests of different chunks are merged by recompressing their centroids together.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TDigest {
+    centroids: Vec<Centroid>,
+    min: f64,
+    max: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: u64,
+}
+
+impl TDigest {
+    /// Bounds the number of centroids to roughly half of this value.
+    pub const COMPRESSION: f64 = 100.0;
+
+    pub fn new() -> Self {
+        Self {
+            centroids: Vec::new(),
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// Build a digest of the given values, skipping NaNs.
+    pub fn from_values(values: impl IntoIterator<Item = f64>) -> Self {
+        let mut digest = Self::new();
+        let centroids = values
+            .into_iter()
+            .filter(|v| !v.is_nan())
+            .map(|mean| Centroid { mean, weight: 1 })
+            .collect();
+        digest.compress(centroids);
+        digest
+    }
+
+    /// The number of values summarised by the digest.
+    pub fn count(&self) -> u64 {
+        self.centroids.iter().map(|c| c.weight).sum()
+    }
+
+    pub fn merge(&mut self, other: &Self) {
+        let mut centroids = std::mem::take(&mut self.centroids);
+        centroids.extend_from_slice(&other.centroids);
+        self.compress(centroids);
+        self.min = self.min.min(other.min);
+        self.max = self.max.max(other.max);
+    }
+
+    /// Estimate the value below which a fraction `q` of the values lie, or `None` if the digest is
+    /// empty or `q` is not within `[0, 1]`.
+    pub fn quantile(&self, q: f64) -> Option<f64> {
+        if self.centroids.is_empty() || !(0.0..=1.0).contains(&q) {
+            return None;
+        }
+
+        // Interpolate linearly between the centres of neighbouring centroids, and between the
+        // outermost centroids and the exact extremes.
+        let target = q * self.count() as f64;
+        let mut prev = (0.0, self.min);
+        let mut cumulative = 0.0;
+        for c in &self.centroids {
+            let centre = cumulative + c.weight as f64 / 2.0;
+            if target < centre {
+                return Some(interpolate(prev, (centre, c.mean), target));
+            }
+            cumulative += c.weight as f64;
+            prev = (centre, c.mean);
+        }
+        Some(interpolate(prev, (cumulative, self.max), target))
+    }
+
+    /// Replace the centroids of the digest with the given ones, merging neighbours while they stay
+    /// within the size limit of the `k1` scale function.
+    fn compress(&mut self, mut centroids: Vec<Centroid>) {
+        centroids.sort_by(|a, b| a.mean.total_cmp(&b.mean));
+        let (Some(first), Some(last)) = (centroids.first(), centroids.last()) else {
+            self.centroids = centroids;
+            return;
+        };
+        self.min = self.min.min(first.mean);
+        self.max = self.max.max(last.mean);
+
+        let total = centroids.iter().map(|c| c.weight).sum::<u64>() as f64;
+        let limit = |weight_before: f64| total * k1_inverse(k1(weight_before / total) + 1.0);
+
+        let mut compressed = Vec::new();
+        let mut iter = centroids.into_iter();
+        let Some(mut current) = iter.next() else {
+            return;
+        };
+        let mut weight_before = 0.0;
+        let mut weight_limit = limit(weight_before);
+        for next in iter {
+            let weight = current.weight + next.weight;
+            if weight_before + weight as f64 <= weight_limit {
+                // Equal means are left alone so that infinite values remain representable.
+                if current.mean != next.mean {
+                    current.mean += (next.mean - current.mean) * next.weight as f64 / weight as f64;
+                }
+                current.weight = weight;
+            } else {
+                weight_before += current.weight as f64;
+                weight_limit = limit(weight_before);
+                compressed.push(current);
+                current = next;
+            }
+        }
+        compressed.push(current);
+        self.centroids = compressed;
+    }
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn k1(q: f64) -> f64 {
+    TDigest::COMPRESSION / (2.0 * PI) * (2.0 * q - 1.0).asin()
+}
+
+fn k1_inverse(k: f64) -> f64 {
+    let angle = (2.0 * PI * k / TDigest::COMPRESSION).min(PI / 2.0);
+    (angle.sin() + 1.0) / 2.0
+}
+
+fn interpolate((x0, y0): (f64, f64), (x1, y1): (f64, f64), x: f64) -> f64 {
+    if x1 <= x0 || y0 == y1 {
+        return y0;
+    }
+    y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+}
+
+/// The digest is stored as little-endian `min` and `max` followed by the mean and weight of each
+/// centroid.
+impl From<TDigest> for Scalar {
+    fn from(value: TDigest) -> Self {
+        let mut bytes = Vec::with_capacity(16 * (value.centroids.len() + 1));
+        bytes.extend_from_slice(&value.min.to_le_bytes());
+        bytes.extend_from_slice(&value.max.to_le_bytes());
+        for c in &value.centroids {
+            bytes.extend_from_slice(&c.mean.to_le_bytes());
+            bytes.extend_from_slice(&c.weight.to_le_bytes());
+        }
+        Scalar::binary(Buffer::from(bytes), Nullability::NonNullable)
+    }
+}
+
+impl TryFrom<&Scalar> for TDigest {
+    type Error = VortexError;
+
+    fn try_from(value: &Scalar) -> VortexResult<Self> {
+        let buffer = Buffer::try_from(value)?;
+        if buffer.len() < 16 || buffer.len() % 16 != 0 {
+            vortex_bail!("Invalid t-digest of {} bytes", buffer.len());
+        }
+
+        let mut words = buffer.chunks_exact(8).map(|word| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(word);
+            bytes
+        });
+        let mut next_word = || {
+            words
+                .next()
+                .ok_or_else(|| vortex_err!("Truncated t-digest"))
+        };
+        let min = f64::from_le_bytes(next_word()?);
+        let max = f64::from_le_bytes(next_word()?);
+        let centroids = (0..buffer.len() / 16 - 1)
+            .map(|_| {
+                Ok(Centroid {
+                    mean: f64::from_le_bytes(next_word()?),
+                    weight: u64::from_le_bytes(next_word()?),
+                })
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        Ok(Self {
+            centroids,
+            min,
+            max,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use vortex_scalar::Scalar;
+
+    use crate::stats::TDigest;
+
+    #[test]
+    fn quantiles() {
+        let digest = TDigest::from_values((0..10_000).map(f64::from));
+        assert_eq!(digest.count(), 10_000);
+        assert_eq!(digest.quantile(0.0), Some(0.0));
+        assert_eq!(digest.quantile(1.0), Some(9_999.0));
+        let median = digest.quantile(0.5).unwrap();
+        assert!((median - 5_000.0).abs() < 100.0, "{median}");
+        let p99 = digest.quantile(0.99).unwrap();
+        assert!((p99 - 9_900.0).abs() < 20.0, "{p99}");
+        assert_eq!(digest.quantile(1.5), None);
+        assert_eq!(TDigest::new().quantile(0.5), None);
+    }
+
+    #[test]
+    fn merge_and_roundtrip() {
+        let mut digest = TDigest::from_values((0..5_000).map(f64::from));
+        digest.merge(&TDigest::from_values(
+            (5_000..10_000).map(f64::from).chain([f64::NAN]),
+        ));
+        assert_eq!(digest.count(), 10_000);
+        let median = digest.quantile(0.5).unwrap();
+        assert!((median - 5_000.0).abs() < 100.0, "{median}");
+
+        let roundtrip = TDigest::try_from(&Scalar::from(digest.clone())).unwrap();
+        assert_eq!(roundtrip, digest);
+    }
+}
@@ -12,7 +12,7 @@ use vortex_error::{
 
 use crate::array::BoolArray;
 use crate::compute::unary::scalar_at_unchecked;
-use crate::compute::{filter, slice, take};
+use crate::compute::{filter, slice, take, FilterMask};
 use crate::stats::ArrayStatistics;
 use crate::{Array, ArrayDType, IntoArray, IntoArrayVariant};
 
@@ -148,12 +148,12 @@ impl Validity {
         }
     }
 
-    pub fn filter(&self, predicate: &Array) -> VortexResult<Self> {
+    pub fn filter(&self, mask: &FilterMask) -> VortexResult<Self> {
         match self {
             v @ (Validity::NonNullable | Validity::AllValid | Validity::AllInvalid) => {
                 Ok(v.clone())
             }
-            Validity::Array(arr) => Ok(Validity::Array(filter(arr, predicate)?)),
+            Validity::Array(arr) => Ok(Validity::Array(filter(arr, mask)?)),
         }
     }
 
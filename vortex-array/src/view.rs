@@ -5,14 +5,14 @@ use enum_iterator::all;
 use itertools::Itertools;
 use log::warn;
 use vortex_buffer::Buffer;
-use vortex_dtype::{DType, Nullability};
+use vortex_dtype::{DType, Nullability, PType};
 use vortex_error::{vortex_err, VortexError, VortexExpect as _, VortexResult};
 use vortex_scalar::{PValue, Scalar, ScalarValue};
 
 use crate::array::visitor::ArrayVisitor;
 use crate::encoding::opaque::OpaqueEncoding;
 use crate::encoding::EncodingRef;
-use crate::stats::{Stat, Statistics, StatsSet};
+use crate::stats::{compute_statistics, sum_ptype, Stat, Statistics, StatsOptions, StatsSet};
 use crate::{flatbuffers as fb, Array, Context, IntoArray, ToArray};
 
 /// Zero-copy view over flatbuffer-encoded array data, created without eager serialization.
@@ -215,21 +215,58 @@ impl Statistics for ArrayView {
                         .map(|v| ScalarValue::Primitive(PValue::U64(v)))
                         .collect_vec()
                 })
-                .map(|v| {
-                    Scalar::list(
-                        DType::Primitive(vortex_dtype::PType::U64, Nullability::NonNullable),
-                        v,
-                    )
-                }),
+                .map(|v| Scalar::list(DType::Primitive(PType::U64, Nullability::NonNullable), v)),
             Stat::TrailingZeroFreq => self
                 .flatbuffer()
                 .stats()?
                 .trailing_zero_freq()
                 .map(|v| v.iter().collect_vec())
                 .map(|v| v.into()),
+            Stat::ApproxDistinct => self
+                .flatbuffer()
+                .stats()?
+                .approx_distinct()
+                .map(|v| Scalar::binary(Buffer::from(v.bytes()), Nullability::NonNullable)),
+            Stat::ApproxQuantiles => self
+                .flatbuffer()
+                .stats()?
+                .approx_quantiles()
+                .map(|v| Scalar::binary(Buffer::from(v.bytes()), Nullability::NonNullable)),
+            Stat::Sum => {
+                let ptype = sum_ptype(PType::try_from(&self.dtype).ok()?);
+                let sum = self.flatbuffer().stats()?.sum();
+                sum.and_then(|v| ScalarValue::try_from(v).ok())
+                    .map(|v| Scalar::new(DType::Primitive(ptype, Nullability::NonNullable), v))
+            }
+            Stat::NaNCount => self.flatbuffer().stats()?.nan_count().map(u64::into),
+            Stat::UncompressedSizeInBytes => self
+                .flatbuffer()
+                .stats()?
+                .uncompressed_size_in_bytes()
+                .map(u64::into),
+            Stat::Histogram => self
+                .flatbuffer()
+                .stats()?
+                .histogram()
+                .map(|v| Scalar::binary(Buffer::from(v.bytes()), Nullability::NonNullable)),
+            // Bloom filters are too large to serialize with every array, layouts that want them
+            // store them alongside their chunk metadata instead.
+            Stat::BloomFilter => None,
         }
     }
 
+    fn is_exact(&self, stat: Stat) -> bool {
+        let Some(stats) = self.flatbuffer().stats() else {
+            return false;
+        };
+        let is_bound = match stat {
+            Stat::Min => stats.min_is_bound(),
+            Stat::Max => stats.max_is_bound(),
+            _ => None,
+        };
+        is_bound != Some(true) && self.get(stat).is_some()
+    }
+
     /// NB: part of the contract for to_set is that it does not do any expensive computation.
     /// In other implementations, this means returning the underlying stats map, but for the flatbuffer
     /// implemetation, we have 'precalculated' stats in the flatbuffer itself, so we need to
@@ -238,7 +275,11 @@ impl Statistics for ArrayView {
         let mut result = StatsSet::new();
         for stat in all::<Stat>() {
             if let Some(value) = self.get(stat) {
-                result.set(stat, value)
+                if self.is_exact(stat) {
+                    result.set(stat, value)
+                } else {
+                    result.set_bound(stat, value)
+                }
             }
         }
         result
@@ -250,13 +291,12 @@ impl Statistics for ArrayView {
         warn!("Cannot write stats to a view")
     }
 
-    fn compute(&self, stat: Stat) -> Option<Scalar> {
+    fn compute_with_options(&self, stat: Stat, options: &StatsOptions) -> Option<Scalar> {
         if let Some(s) = self.get(stat) {
             return Some(s);
         }
 
-        self.to_array()
-            .with_dyn(|a| a.compute_statistics(stat))
+        compute_statistics(&self.to_array(), stat, options)
             .ok()?
             .get(stat)
             .cloned()
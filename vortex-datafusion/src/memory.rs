@@ -20,6 +20,7 @@ use vortex_expr::datafusion::convert_expr_to_vortex;
 use vortex_expr::VortexExpr;
 
 use crate::plans::{RowSelectorExec, TakeRowsExec};
+use crate::statistics::{estimate_selectivity, filtered_df_stats};
 use crate::{can_be_pushed_down, VortexScanExec};
 
 /// A [`TableProvider`] that exposes an existing Vortex Array to the DataFusion SQL engine.
@@ -195,6 +196,8 @@ fn make_filter_then_take_plan(
     output_projection: Vec<usize>,
     _session_state: &dyn Session,
 ) -> DFResult<Arc<dyn ExecutionPlan>> {
+    let selectivity = estimate_selectivity(&chunked_array, &filter_expr);
+    let statistics = filtered_df_stats(&chunked_array, &output_projection, selectivity)?;
     let row_selector_op = Arc::new(RowSelectorExec::try_new(filter_expr, &chunked_array)?);
 
     Ok(Arc::new(TakeRowsExec::new(
@@ -202,6 +205,7 @@ fn make_filter_then_take_plan(
         &output_projection,
         row_selector_op.clone(),
         &chunked_array,
+        statistics,
     )))
 }
 
@@ -10,7 +10,7 @@ use arrow_array::cast::AsArray;
 use arrow_array::types::UInt64Type;
 use arrow_array::{ArrayRef, RecordBatch, RecordBatchOptions, UInt64Array};
 use arrow_schema::{DataType, Schema, SchemaRef};
-use datafusion_common::{DataFusionError, Result as DFResult};
+use datafusion_common::{DataFusionError, Result as DFResult, Statistics};
 use datafusion_execution::{RecordBatchStream, SendableRecordBatchStream, TaskContext};
 use datafusion_physical_expr::{EquivalenceProperties, Partitioning};
 use datafusion_physical_plan::{
@@ -204,6 +204,9 @@ pub(crate) struct TakeRowsExec {
 
     // The original Vortex array holding the fields we have not decoded yet.
     table: ChunkedArray,
+
+    // Estimated statistics of the rows that pass the filter.
+    statistics: Statistics,
 }
 
 impl TakeRowsExec {
@@ -212,6 +215,7 @@ impl TakeRowsExec {
         projection: &[usize],
         row_indices: Arc<dyn ExecutionPlan>,
         table: &ChunkedArray,
+        statistics: Statistics,
     ) -> Self {
         let output_schema = Arc::new(schema_ref.project(projection).unwrap_or_else(|err| {
             vortex_panic!("Failed to project schema: {}", VortexError::from(err))
@@ -228,6 +232,7 @@ impl TakeRowsExec {
             input: row_indices,
             output_schema: output_schema.clone(),
             table: table.clone(),
+            statistics,
         }
     }
 }
@@ -287,6 +292,10 @@ impl ExecutionPlan for TakeRowsExec {
             vortex_array: self.table.clone(),
         }))
     }
+
+    fn statistics(&self) -> DFResult<Statistics> {
+        Ok(self.statistics.clone())
+    }
 }
 
 /// Stream of outputs emitted by the [TakeRowsExec] physical operator.
@@ -1,9 +1,17 @@
+use std::sync::Arc;
+
 use datafusion_common::stats::Precision;
 use datafusion_common::{ColumnStatistics, Result as DFResult, ScalarValue, Statistics};
 use itertools::Itertools;
 use vortex::array::ChunkedArray;
-use vortex::stats::{ArrayStatistics, Stat};
+use vortex::compute::Operator as ArrayOperator;
+use vortex::stats::{ArrayStatistics, Histogram, Stat};
+use vortex::Array;
+use vortex_dtype::field::Field;
+use vortex_dtype::{DType, Nullability, PType};
 use vortex_error::{vortex_err, VortexExpect, VortexResult};
+use vortex_expr::{BetweenExpr, BinaryExpr, Column, Literal, Operator, VortexExpr};
+use vortex_scalar::Scalar;
 
 pub fn chunked_array_df_stats(array: &ChunkedArray, projection: &[usize]) -> DFResult<Statistics> {
     let mut nbytes: usize = 0;
@@ -27,24 +35,8 @@ pub fn chunked_array_df_stats(array: &ChunkedArray, projection: &[usize]) -> DFR
                         .map(|n| n as usize)
                         .map(Precision::Exact)
                         .unwrap_or(Precision::Absent),
-                    max_value: arr
-                        .statistics()
-                        .get(Stat::Max)
-                        .map(|n| {
-                            ScalarValue::try_from(n)
-                                .vortex_expect("cannot convert scalar to df scalar")
-                        })
-                        .map(Precision::Exact)
-                        .unwrap_or(Precision::Absent),
-                    min_value: arr
-                        .statistics()
-                        .get(Stat::Min)
-                        .map(|n| {
-                            ScalarValue::try_from(n)
-                                .vortex_expect("cannot convert scalar to df scalar")
-                        })
-                        .map(Precision::Exact)
-                        .unwrap_or(Precision::Absent),
+                    max_value: stat_precision(&arr, Stat::Max),
+                    min_value: stat_precision(&arr, Stat::Min),
                     distinct_count: Precision::Absent,
                 }
             })
@@ -56,3 +48,268 @@ pub fn chunked_array_df_stats(array: &ChunkedArray, projection: &[usize]) -> DFR
         column_statistics,
     })
 }
+
+/// The value of a stat of the array, which is inexact if the stat is only a bound.
+fn stat_precision(array: &Array, stat: Stat) -> Precision<ScalarValue> {
+    let stats = array.statistics();
+    let Some(value) = stats.get(stat) else {
+        return Precision::Absent;
+    };
+    let value = ScalarValue::try_from(value).vortex_expect("cannot convert scalar to df scalar");
+    if stats.is_exact(stat) {
+        Precision::Exact(value)
+    } else {
+        Precision::Inexact(value)
+    }
+}
+
+/// Statistics of the rows of `array` that pass a filter, given its estimated selectivity.
+pub fn filtered_df_stats(
+    array: &ChunkedArray,
+    projection: &[usize],
+    selectivity: Option<f64>,
+) -> DFResult<Statistics> {
+    let stats = chunked_array_df_stats(array, projection)?.to_inexact();
+    let Some(selectivity) = selectivity else {
+        return Ok(stats);
+    };
+    let scale = |n: usize| (n as f64 * selectivity).round() as usize;
+    Ok(Statistics {
+        num_rows: stats.num_rows.map(scale),
+        total_byte_size: stats.total_byte_size.map(scale),
+        column_statistics: stats.column_statistics,
+    })
+}
+
+/// Estimate the fraction of rows of a struct array for which `expr` is true.
+///
+/// Comparisons of numeric columns with literals are estimated from the histograms the columns
+/// already carry, since computing them would decode the columns while planning. Conjunctions and
+/// disjunctions treat their operands as independent. Returns `None` if no part of the expression
+/// can be estimated.
+pub fn estimate_selectivity(array: &ChunkedArray, expr: &Arc<dyn VortexExpr>) -> Option<f64> {
+    if let Some(between) = expr.as_any().downcast_ref::<BetweenExpr>() {
+        let column = column_array(array, between.column().field())?;
+        let lower = compare_selectivity(
+            &column,
+            between.inclusivity().lower_operator(),
+            between.lower(),
+        )?;
+        let upper = compare_selectivity(
+            &column,
+            between.inclusivity().upper_operator(),
+            between.upper(),
+        )?;
+        // Every non-null value passes at least one of the two comparisons when lower <= upper.
+        return Some((lower + upper - non_null_fraction(&column)?).max(0.0));
+    }
+
+    let binary = expr.as_any().downcast_ref::<BinaryExpr>()?;
+    match binary.op() {
+        Operator::And => {
+            let lhs = estimate_selectivity(array, binary.lhs());
+            let rhs = estimate_selectivity(array, binary.rhs());
+            match (lhs, rhs) {
+                (Some(lhs), Some(rhs)) => Some(lhs * rhs),
+                (lhs, rhs) => lhs.or(rhs),
+            }
+        }
+        Operator::Or => {
+            let lhs = estimate_selectivity(array, binary.lhs())?;
+            let rhs = estimate_selectivity(array, binary.rhs())?;
+            Some(lhs + rhs - lhs * rhs)
+        }
+        op => {
+            let (lhs, rhs) = (binary.lhs().as_any(), binary.rhs().as_any());
+            let (column, op, literal) =
+                match (lhs.downcast_ref::<Column>(), rhs.downcast_ref::<Literal>()) {
+                    (Some(column), Some(literal)) => (column, op, literal),
+                    _ => (
+                        rhs.downcast_ref::<Column>()?,
+                        op.swap()?,
+                        lhs.downcast_ref::<Literal>()?,
+                    ),
+                };
+            let op = match op {
+                Operator::Eq => ArrayOperator::Eq,
+                Operator::NotEq => ArrayOperator::NotEq,
+                Operator::Gt => ArrayOperator::Gt,
+                Operator::Gte => ArrayOperator::Gte,
+                Operator::Lt => ArrayOperator::Lt,
+                Operator::Lte => ArrayOperator::Lte,
+                _ => return None,
+            };
+            let column = column_array(array, column.field())?;
+            compare_selectivity(&column, op, literal.value())
+        }
+    }
+}
+
+fn column_array(array: &ChunkedArray, field: &Field) -> Option<Array> {
+    array.as_ref().with_dyn(|a| {
+        let struct_arr = a.as_struct_array()?;
+        match field {
+            Field::Name(name) => struct_arr.field_by_name(name),
+            Field::Index(idx) => struct_arr.field(*idx),
+        }
+    })
+}
+
+/// The histogram of a column, merged from those of its chunks, if all of them are known.
+fn known_histogram(column: &Array) -> Option<Histogram> {
+    match ChunkedArray::try_from(column) {
+        Ok(chunked) => chunked
+            .chunks()
+            .map(|chunk| known_histogram(&chunk))
+            .reduce(|acc, histogram| {
+                let mut acc = acc?;
+                acc.merge(&histogram?);
+                Some(acc)
+            })?,
+        Err(_) => column.statistics().get_as::<Histogram>(Stat::Histogram),
+    }
+}
+
+/// The fraction of values that the column's histogram covers, i.e. those that are neither null nor
+/// NaN.
+fn non_null_fraction(column: &Array) -> Option<f64> {
+    let histogram = known_histogram(column)?;
+    (!column.is_empty()).then(|| histogram.count() as f64 / column.len() as f64)
+}
+
+/// The estimated fraction of the column's values `v` for which `v op value` holds.
+fn compare_selectivity(column: &Array, op: ArrayOperator, value: &Scalar) -> Option<f64> {
+    if value.is_null() {
+        return Some(0.0);
+    }
+    let histogram = known_histogram(column)?;
+    let value = value
+        .cast(&DType::Primitive(PType::F64, Nullability::NonNullable))
+        .ok()
+        .and_then(|v| f64::try_from(&v).ok())?;
+    let fraction = match op {
+        ArrayOperator::Eq => histogram.fraction_eq(value),
+        ArrayOperator::NotEq => histogram.fraction_eq(value).map(|f| 1.0 - f),
+        ArrayOperator::Lt => histogram.fraction_lt(value),
+        ArrayOperator::Lte => histogram.fraction_le(value),
+        ArrayOperator::Gt => histogram.fraction_le(value).map(|f| 1.0 - f),
+        ArrayOperator::Gte => histogram.fraction_lt(value).map(|f| 1.0 - f),
+    }
+    // Comparisons of an empty histogram only see nulls, which never pass.
+    .unwrap_or(0.0);
+    Some(fraction * non_null_fraction(column)?)
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use datafusion_common::stats::Precision;
+    use datafusion_common::ScalarValue;
+    use vortex::array::{ChunkedArray, PrimitiveArray, StructArray, VarBinViewArray};
+    use vortex::compute::Inclusivity;
+    use vortex::stats::{ArrayStatistics, Stat, DEFAULT_MAX_STRING_BOUND_LENGTH};
+    use vortex::{ArrayDType, IntoArray};
+    use vortex_dtype::field::Field;
+    use vortex_expr::{BetweenExpr, BinaryExpr, Column, Literal, Operator, VortexExpr};
+
+    use crate::statistics::{estimate_selectivity, filtered_df_stats, stat_precision};
+
+    fn table(with_histograms: bool) -> ChunkedArray {
+        let chunks = [0..500, 500..1000]
+            .into_iter()
+            .map(|range| {
+                let a = PrimitiveArray::from(range.collect::<Vec<i32>>()).into_array();
+                if with_histograms {
+                    a.statistics().compute_histogram();
+                }
+                StructArray::from_fields(&[
+                    ("a", a),
+                    ("b", VarBinViewArray::from_iter_str(["x"; 500]).into_array()),
+                ])
+                .unwrap()
+                .into_array()
+            })
+            .collect::<Vec<_>>();
+        let dtype = chunks[0].dtype().clone();
+        ChunkedArray::try_new(chunks, dtype).unwrap()
+    }
+
+    fn compare(column: &str, op: Operator, value: i32) -> Arc<dyn VortexExpr> {
+        Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from(column))),
+            op,
+            Arc::new(Literal::new(value.into())),
+        ))
+    }
+
+    fn assert_close(estimate: Option<f64>, expected: f64) {
+        let estimate = estimate.unwrap();
+        assert!(
+            (estimate - expected).abs() < 0.01,
+            "{estimate} != {expected}"
+        );
+    }
+
+    #[test]
+    fn histogram_selectivity() {
+        // Histograms are only used when they are known, they are never computed while planning.
+        assert_eq!(
+            estimate_selectivity(&table(false), &compare("a", Operator::Lt, 250)),
+            None
+        );
+
+        let table = table(true);
+        assert_close(
+            estimate_selectivity(&table, &compare("a", Operator::Lt, 250)),
+            0.25,
+        );
+        assert_close(
+            estimate_selectivity(&table, &compare("a", Operator::Gte, 900)),
+            0.1,
+        );
+        assert_close(
+            estimate_selectivity(&table, &compare("a", Operator::Eq, 7)),
+            0.001,
+        );
+        let between: Arc<dyn VortexExpr> = Arc::new(BetweenExpr::new(
+            Column::new(Field::from("a")),
+            100.into(),
+            299.into(),
+            Inclusivity::Both,
+        ));
+        assert_close(estimate_selectivity(&table, &between), 0.2);
+        let and: Arc<dyn VortexExpr> = Arc::new(BinaryExpr::new(
+            compare("a", Operator::Lt, 500),
+            Operator::And,
+            compare("a", Operator::Gte, 250),
+        ));
+        assert_close(estimate_selectivity(&table, &and), 0.375);
+        let strings: Arc<dyn VortexExpr> = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("b"))),
+            Operator::Eq,
+            Arc::new(Literal::new("x".into())),
+        ));
+        assert_eq!(estimate_selectivity(&table, &strings), None);
+
+        let stats = filtered_df_stats(&table, &[0, 1], Some(0.25)).unwrap();
+        assert_eq!(stats.num_rows, Precision::Inexact(250));
+    }
+
+    #[test]
+    fn truncated_string_bounds_are_inexact() {
+        let long = "x".repeat(DEFAULT_MAX_STRING_BOUND_LENGTH + 10);
+        let strings = VarBinViewArray::from_iter_str([long.as_str(), "a"]).into_array();
+        assert_eq!(stat_precision(&strings, Stat::Min), Precision::Absent);
+        strings.statistics().compute(Stat::Min);
+        strings.statistics().compute(Stat::Max);
+        assert_eq!(
+            stat_precision(&strings, Stat::Min),
+            Precision::Exact(ScalarValue::Utf8(Some("a".to_string())))
+        );
+        assert!(matches!(
+            stat_precision(&strings, Stat::Max),
+            Precision::Inexact(_)
+        ));
+    }
+}
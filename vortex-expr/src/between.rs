@@ -0,0 +1,239 @@
+use std::any::Any;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use vortex::compute::{between, Inclusivity};
+use vortex::Array;
+use vortex_dtype::field::Field;
+use vortex_error::VortexResult;
+use vortex_scalar::Scalar;
+
+use crate::{unbox_any, BinaryExpr, Column, Literal, Operator, VortexExpr};
+
+/// Tests whether the values of a column lie between two literal bounds.
+#[derive(Debug, Clone)]
+pub struct BetweenExpr {
+    column: Column,
+    lower: Scalar,
+    upper: Scalar,
+    inclusivity: Inclusivity,
+}
+
+impl BetweenExpr {
+    pub fn new(column: Column, lower: Scalar, upper: Scalar, inclusivity: Inclusivity) -> Self {
+        Self {
+            column,
+            lower,
+            upper,
+            inclusivity,
+        }
+    }
+
+    pub fn column(&self) -> &Column {
+        &self.column
+    }
+
+    pub fn lower(&self) -> &Scalar {
+        &self.lower
+    }
+
+    pub fn upper(&self) -> &Scalar {
+        &self.upper
+    }
+
+    pub fn inclusivity(&self) -> Inclusivity {
+        self.inclusivity
+    }
+}
+
+impl VortexExpr for BetweenExpr {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn evaluate(&self, batch: &Array) -> VortexResult<Array> {
+        between(
+            self.column.evaluate(batch)?,
+            &self.lower,
+            &self.upper,
+            self.inclusivity,
+        )
+    }
+
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a Field>) {
+        self.column.collect_references(references);
+    }
+}
+
+impl PartialEq<dyn Any> for BetweenExpr {
+    fn eq(&self, other: &dyn Any) -> bool {
+        unbox_any(other)
+            .downcast_ref::<Self>()
+            .map(|x| {
+                x.column == self.column
+                    && x.lower == self.lower
+                    && x.upper == self.upper
+                    && x.inclusivity == self.inclusivity
+            })
+            .unwrap_or(false)
+    }
+}
+
+/// A comparison between a column and a literal, normalized so the column is on the left.
+struct ColumnComparison<'a> {
+    column: &'a Column,
+    operator: Operator,
+    value: &'a Scalar,
+}
+
+impl<'a> ColumnComparison<'a> {
+    fn try_new(expr: &'a Arc<dyn VortexExpr>) -> Option<Self> {
+        let binary = expr.as_any().downcast_ref::<BinaryExpr>()?;
+        let as_column = |e: &'a Arc<dyn VortexExpr>| e.as_any().downcast_ref::<Column>();
+        let as_literal = |e: &'a Arc<dyn VortexExpr>| e.as_any().downcast_ref::<Literal>();
+
+        let (column, operator, literal) = match (as_column(binary.lhs()), as_column(binary.rhs())) {
+            (Some(column), _) => (column, binary.op(), as_literal(binary.rhs())?),
+            (None, Some(column)) => (column, binary.op().swap()?, as_literal(binary.lhs())?),
+            (None, None) => return None,
+        };
+        Some(Self {
+            column,
+            operator,
+            value: literal.value(),
+        })
+    }
+
+    /// Whether the comparison is a lower bound, or `None` if it doesn't bound the column.
+    fn is_lower_bound(&self) -> Option<bool> {
+        match self.operator {
+            Operator::Gt | Operator::Gte => Some(true),
+            Operator::Lt | Operator::Lte => Some(false),
+            _ => None,
+        }
+    }
+}
+
+/// Replace pairs of lower and upper bound comparisons of the same column in a conjunction with a
+/// single [BetweenExpr].
+///
+/// The fused expression takes the place of the first comparison of each pair, and every other
+/// expression keeps its relative order.
+pub fn fuse_between(conjunction: Vec<Arc<dyn VortexExpr>>) -> Vec<Arc<dyn VortexExpr>> {
+    let comparisons = conjunction
+        .iter()
+        .map(ColumnComparison::try_new)
+        .collect::<Vec<_>>();
+
+    let mut fused = vec![];
+    let mut used = vec![false; conjunction.len()];
+    for (i, expr) in conjunction.iter().enumerate() {
+        if used[i] {
+            continue;
+        }
+
+        let bound = comparisons[i]
+            .as_ref()
+            .and_then(|c| Some((c, c.is_lower_bound()?)));
+        let partner = bound.and_then(|(comparison, is_lower)| {
+            (i + 1..conjunction.len()).find_map(|j| {
+                let other = comparisons[j].as_ref()?;
+                (!used[j]
+                    && other.column == comparison.column
+                    && other.is_lower_bound() == Some(!is_lower))
+                .then_some((j, comparison, other, is_lower))
+            })
+        });
+
+        match partner {
+            Some((j, comparison, other, is_lower)) => {
+                used[j] = true;
+                let (lower, upper) = if is_lower {
+                    (comparison, other)
+                } else {
+                    (other, comparison)
+                };
+                fused.push(Arc::new(BetweenExpr::new(
+                    lower.column.clone(),
+                    lower.value.clone(),
+                    upper.value.clone(),
+                    Inclusivity::new(
+                        lower.operator == Operator::Gte,
+                        upper.operator == Operator::Lte,
+                    ),
+                )) as _);
+            }
+            None => fused.push(expr.clone()),
+        }
+    }
+    fused
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::compute::Inclusivity;
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_dtype::field::Field;
+
+    use crate::{fuse_between, BetweenExpr, BinaryExpr, Column, Literal, Operator, VortexExpr};
+
+    fn cmp(
+        lhs: Arc<dyn VortexExpr>,
+        op: Operator,
+        rhs: Arc<dyn VortexExpr>,
+    ) -> Arc<dyn VortexExpr> {
+        Arc::new(BinaryExpr::new(lhs, op, rhs))
+    }
+
+    fn col(name: &str) -> Arc<dyn VortexExpr> {
+        Arc::new(Column::new(Field::Name(name.to_string())))
+    }
+
+    fn lit(value: i64) -> Arc<dyn VortexExpr> {
+        Arc::new(Literal::new(value.into()))
+    }
+
+    #[test]
+    fn fuse_paired_comparisons() {
+        let conjunction = vec![
+            cmp(col("ts"), Operator::Lt, lit(20)),
+            cmp(col("id"), Operator::Eq, lit(1)),
+            cmp(lit(10), Operator::Lte, col("ts")),
+            cmp(col("other"), Operator::Gt, lit(0)),
+        ];
+        let fused = fuse_between(conjunction.clone());
+        assert_eq!(fused.len(), 3);
+
+        let between = fused[0].as_any().downcast_ref::<BetweenExpr>().unwrap();
+        assert_eq!(between.column().field(), &Field::Name("ts".to_string()));
+        assert_eq!(between.lower(), &10i64.into());
+        assert_eq!(between.upper(), &20i64.into());
+        assert_eq!(between.inclusivity(), Inclusivity::Lower);
+        assert!(fused[1].eq(&conjunction[1]));
+        assert!(fused[2].eq(&conjunction[3]));
+    }
+
+    #[test]
+    fn evaluate_between() {
+        let batch = StructArray::from_fields(&[(
+            "ts",
+            PrimitiveArray::from(vec![5i64, 10, 15, 20]).into_array(),
+        )])
+        .unwrap()
+        .into_array();
+
+        let fused = fuse_between(vec![
+            cmp(col("ts"), Operator::Gt, lit(5)),
+            cmp(col("ts"), Operator::Lte, lit(15)),
+        ]);
+        assert_eq!(fused.len(), 1);
+        let result = fused[0].evaluate(&batch).unwrap().into_bool().unwrap();
+        assert_eq!(
+            result.boolean_buffer().iter().collect::<Vec<_>>(),
+            vec![false, true, true, false]
+        );
+    }
+}
@@ -2,7 +2,7 @@ use std::any::Any;
 use std::collections::HashSet;
 use std::sync::Arc;
 
-use vortex::compute::{and, compare, or, Operator as ArrayOperator};
+use vortex::compute::{and, bitwise, compare, or, BitwiseOperator, Operator as ArrayOperator};
 use vortex::Array;
 use vortex_dtype::field::Field;
 use vortex_error::VortexResult;
@@ -52,6 +52,11 @@ impl VortexExpr for BinaryExpr {
             Operator::Gte => compare(lhs, rhs, ArrayOperator::Gte),
             Operator::And => and(lhs, rhs),
             Operator::Or => or(lhs, rhs),
+            Operator::BitwiseAnd => bitwise(lhs, rhs, BitwiseOperator::And),
+            Operator::BitwiseOr => bitwise(lhs, rhs, BitwiseOperator::Or),
+            Operator::BitwiseXor => bitwise(lhs, rhs, BitwiseOperator::Xor),
+            Operator::ShiftLeft => bitwise(lhs, rhs, BitwiseOperator::ShiftLeft),
+            Operator::ShiftRight => bitwise(lhs, rhs, BitwiseOperator::ShiftRight),
         }
     }
 
@@ -69,3 +74,33 @@ impl PartialEq<dyn Any> for BinaryExpr {
             .unwrap_or(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_dtype::field::Field;
+
+    use crate::{BinaryExpr, Column, Literal, Operator, VortexExpr};
+
+    #[test]
+    fn evaluate_bitwise() {
+        let batch = StructArray::from_fields(&[(
+            "flags",
+            PrimitiveArray::from(vec![0b0001, 0b0110, 0b1111]).into_array(),
+        )])
+        .unwrap()
+        .into_array();
+        let column = Arc::new(Column::new(Field::Name("flags".to_string()))) as _;
+
+        let masked = BinaryExpr::new(
+            column,
+            Operator::BitwiseAnd,
+            Arc::new(Literal::new(6.into())),
+        );
+        let result = masked.evaluate(&batch).unwrap().into_primitive().unwrap();
+        assert_eq!(result.maybe_null_slice::<i32>(), &[0, 0b0110, 0b0110]);
+    }
+}
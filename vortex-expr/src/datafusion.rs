@@ -7,7 +7,7 @@ use datafusion_physical_expr::{expressions, PhysicalExpr};
 use vortex_error::{vortex_bail, vortex_err, VortexError, VortexResult};
 use vortex_scalar::Scalar;
 
-use crate::{BinaryExpr, Column, Literal, Operator, VortexExpr};
+use crate::{fuse_between, BinaryExpr, Column, Literal, Operator, VortexExpr};
 
 pub fn convert_expr_to_vortex(
     physical_expr: Arc<dyn PhysicalExpr>,
@@ -18,9 +18,16 @@ pub fn convert_expr_to_vortex(
     {
         let left = convert_expr_to_vortex(binary_expr.left().clone())?;
         let right = convert_expr_to_vortex(binary_expr.right().clone())?;
-        let operator = *binary_expr.op();
+        let operator = (*binary_expr.op()).try_into()?;
 
-        return Ok(Arc::new(BinaryExpr::new(left, operator.try_into()?, right)) as _);
+        // Range predicates on a single column are evaluated in one pass.
+        if operator == Operator::And {
+            if let [between] = fuse_between(vec![left.clone(), right.clone()]).as_slice() {
+                return Ok(between.clone());
+            }
+        }
+
+        return Ok(Arc::new(BinaryExpr::new(left, operator, right)) as _);
     }
 
     if let Some(col_expr) = physical_expr.as_any().downcast_ref::<expressions::Column>() {
@@ -53,6 +60,11 @@ impl TryFrom<DFOperator> for Operator {
             DFOperator::GtEq => Ok(Operator::Gte),
             DFOperator::And => Ok(Operator::And),
             DFOperator::Or => Ok(Operator::Or),
+            DFOperator::BitwiseAnd => Ok(Operator::BitwiseAnd),
+            DFOperator::BitwiseOr => Ok(Operator::BitwiseOr),
+            DFOperator::BitwiseXor => Ok(Operator::BitwiseXor),
+            DFOperator::BitwiseShiftLeft => Ok(Operator::ShiftLeft),
+            DFOperator::BitwiseShiftRight => Ok(Operator::ShiftRight),
             DFOperator::IsDistinctFrom
             | DFOperator::IsNotDistinctFrom
             | DFOperator::RegexMatch
@@ -63,11 +75,6 @@ impl TryFrom<DFOperator> for Operator {
             | DFOperator::ILikeMatch
             | DFOperator::NotLikeMatch
             | DFOperator::NotILikeMatch
-            | DFOperator::BitwiseAnd
-            | DFOperator::BitwiseOr
-            | DFOperator::BitwiseXor
-            | DFOperator::BitwiseShiftRight
-            | DFOperator::BitwiseShiftLeft
             | DFOperator::StringConcat
             | DFOperator::AtArrow
             | DFOperator::ArrowAt
@@ -0,0 +1,130 @@
+use std::any::Any;
+use std::collections::HashSet;
+use std::fmt::{Debug, Formatter};
+use std::sync::Arc;
+
+use vortex::function::FunctionRegistry;
+use vortex::Array;
+use vortex_dtype::field::Field;
+use vortex_error::{vortex_bail, VortexResult};
+
+use crate::{unbox_any, VortexExpr};
+
+/// Calls a function from a [FunctionRegistry] with the results of its argument expressions.
+#[derive(Clone)]
+pub struct ScalarFunctionExpr {
+    registry: Arc<FunctionRegistry>,
+    name: String,
+    args: Vec<Arc<dyn VortexExpr>>,
+}
+
+impl ScalarFunctionExpr {
+    pub fn try_new(
+        registry: Arc<FunctionRegistry>,
+        name: impl Into<String>,
+        args: Vec<Arc<dyn VortexExpr>>,
+    ) -> VortexResult<Self> {
+        let name = name.into();
+        if !registry.contains(&name) {
+            vortex_bail!("Unknown function {name}");
+        }
+        Ok(Self {
+            registry,
+            name,
+            args,
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn args(&self) -> &[Arc<dyn VortexExpr>] {
+        &self.args
+    }
+}
+
+impl Debug for ScalarFunctionExpr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ScalarFunctionExpr")
+            .field("name", &self.name)
+            .field("args", &self.args)
+            .finish()
+    }
+}
+
+impl VortexExpr for ScalarFunctionExpr {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn evaluate(&self, batch: &Array) -> VortexResult<Array> {
+        let args = self
+            .args
+            .iter()
+            .map(|arg| arg.evaluate(batch))
+            .collect::<VortexResult<Vec<_>>>()?;
+        self.registry.invoke(&self.name, &args)
+    }
+
+    fn collect_references<'a>(&'a self, references: &mut HashSet<&'a Field>) {
+        for arg in &self.args {
+            arg.collect_references(references);
+        }
+    }
+}
+
+impl PartialEq<dyn Any> for ScalarFunctionExpr {
+    fn eq(&self, other: &dyn Any) -> bool {
+        unbox_any(other)
+            .downcast_ref::<Self>()
+            .map(|x| {
+                x.name == self.name
+                    && x.args.len() == self.args.len()
+                    && x.args.iter().zip(&self.args).all(|(l, r)| l.eq(r))
+            })
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use vortex::array::{PrimitiveArray, StructArray};
+    use vortex::function::FunctionRegistry;
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_dtype::field::Field;
+
+    use crate::{Column, Literal, ScalarFunctionExpr, VortexExpr};
+
+    #[test]
+    fn evaluate_registered_function() {
+        let mut registry = FunctionRegistry::new();
+        registry
+            .register_binary("clamp_max", |v: i32, max: i32| v.min(max))
+            .unwrap();
+        let registry = Arc::new(registry);
+
+        let batch = StructArray::from_fields(&[(
+            "values",
+            PrimitiveArray::from(vec![1i32, 10, 5]).into_array(),
+        )])
+        .unwrap()
+        .into_array();
+        let expr = ScalarFunctionExpr::try_new(
+            registry.clone(),
+            "clamp_max",
+            vec![
+                Arc::new(Column::new(Field::from("values"))),
+                Arc::new(Literal::new(4i32.into())),
+            ],
+        )
+        .unwrap();
+
+        let result = expr.evaluate(&batch).unwrap().into_primitive().unwrap();
+        assert_eq!(result.maybe_null_slice::<i32>(), &[1, 4, 4]);
+        assert_eq!(expr.references().len(), 1);
+        assert!(ScalarFunctionExpr::try_new(registry, "missing", vec![]).is_err());
+    }
+}
@@ -3,16 +3,20 @@ use std::collections::HashSet;
 use std::fmt::Debug;
 use std::sync::Arc;
 
+mod between;
 mod binary;
 mod column;
 pub mod datafusion;
+mod function;
 mod identity;
 mod literal;
 mod operators;
 mod select;
 
+pub use between::*;
 pub use binary::*;
 pub use column::*;
+pub use function::*;
 pub use identity::*;
 pub use literal::*;
 pub use operators::*;
@@ -14,6 +14,12 @@ pub enum Operator {
     // boolean algebra
     And,
     Or,
+    // bitwise integer operations
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+    ShiftLeft,
+    ShiftRight,
 }
 
 impl Display for Operator {
@@ -27,6 +33,11 @@ impl Display for Operator {
             Operator::Lte => "<=",
             Operator::And => "and",
             Operator::Or => "or",
+            Operator::BitwiseAnd => "&",
+            Operator::BitwiseOr => "|",
+            Operator::BitwiseXor => "^",
+            Operator::ShiftLeft => "<<",
+            Operator::ShiftRight => ">>",
         };
         Display::fmt(display, f)
     }
@@ -41,21 +52,33 @@ impl Operator {
             Operator::Gte => Some(Operator::Lt),
             Operator::Lt => Some(Operator::Gte),
             Operator::Lte => Some(Operator::Gt),
-            Operator::And | Operator::Or => None,
+            Operator::And
+            | Operator::Or
+            | Operator::BitwiseAnd
+            | Operator::BitwiseOr
+            | Operator::BitwiseXor
+            | Operator::ShiftLeft
+            | Operator::ShiftRight => None,
         }
     }
 
     /// Change the sides of the operator, where changing lhs and rhs won't change the result of the operation
-    pub fn swap(self) -> Self {
+    ///
+    /// Returns `None` for operators that have no mirrored counterpart, such as shifts.
+    pub fn swap(self) -> Option<Self> {
         match self {
-            Operator::Eq => Operator::Eq,
-            Operator::NotEq => Operator::NotEq,
-            Operator::Gt => Operator::Lt,
-            Operator::Gte => Operator::Lte,
-            Operator::Lt => Operator::Gt,
-            Operator::Lte => Operator::Gte,
-            Operator::And => Operator::And,
-            Operator::Or => Operator::Or,
+            Operator::Eq => Some(Operator::Eq),
+            Operator::NotEq => Some(Operator::NotEq),
+            Operator::Gt => Some(Operator::Lt),
+            Operator::Gte => Some(Operator::Lte),
+            Operator::Lt => Some(Operator::Gt),
+            Operator::Lte => Some(Operator::Gte),
+            Operator::And => Some(Operator::And),
+            Operator::Or => Some(Operator::Or),
+            Operator::BitwiseAnd => Some(Operator::BitwiseAnd),
+            Operator::BitwiseOr => Some(Operator::BitwiseOr),
+            Operator::BitwiseXor => Some(Operator::BitwiseXor),
+            Operator::ShiftLeft | Operator::ShiftRight => None,
         }
     }
 }
@@ -300,6 +300,14 @@ impl<'a> ArrayStats<'a> {
   pub const VT_NULL_COUNT: flatbuffers::VOffsetT = 18;
   pub const VT_BIT_WIDTH_FREQ: flatbuffers::VOffsetT = 20;
   pub const VT_TRAILING_ZERO_FREQ: flatbuffers::VOffsetT = 22;
+  pub const VT_APPROX_DISTINCT: flatbuffers::VOffsetT = 24;
+  pub const VT_APPROX_QUANTILES: flatbuffers::VOffsetT = 26;
+  pub const VT_SUM: flatbuffers::VOffsetT = 28;
+  pub const VT_NAN_COUNT: flatbuffers::VOffsetT = 30;
+  pub const VT_UNCOMPRESSED_SIZE_IN_BYTES: flatbuffers::VOffsetT = 32;
+  pub const VT_MIN_IS_BOUND: flatbuffers::VOffsetT = 34;
+  pub const VT_MAX_IS_BOUND: flatbuffers::VOffsetT = 36;
+  pub const VT_HISTOGRAM: flatbuffers::VOffsetT = 38;
 
   #[inline]
   pub unsafe fn init_from_table(table: flatbuffers::Table<'a>) -> Self {
@@ -311,13 +319,21 @@ impl<'a> ArrayStats<'a> {
     args: &'args ArrayStatsArgs<'args>
   ) -> flatbuffers::WIPOffset<ArrayStats<'bldr>> {
     let mut builder = ArrayStatsBuilder::new(_fbb);
+    if let Some(x) = args.uncompressed_size_in_bytes { builder.add_uncompressed_size_in_bytes(x); }
+    if let Some(x) = args.nan_count { builder.add_nan_count(x); }
     if let Some(x) = args.null_count { builder.add_null_count(x); }
     if let Some(x) = args.true_count { builder.add_true_count(x); }
     if let Some(x) = args.run_count { builder.add_run_count(x); }
+    if let Some(x) = args.histogram { builder.add_histogram(x); }
+    if let Some(x) = args.sum { builder.add_sum(x); }
+    if let Some(x) = args.approx_quantiles { builder.add_approx_quantiles(x); }
+    if let Some(x) = args.approx_distinct { builder.add_approx_distinct(x); }
     if let Some(x) = args.trailing_zero_freq { builder.add_trailing_zero_freq(x); }
     if let Some(x) = args.bit_width_freq { builder.add_bit_width_freq(x); }
     if let Some(x) = args.max { builder.add_max(x); }
     if let Some(x) = args.min { builder.add_min(x); }
+    if let Some(x) = args.max_is_bound { builder.add_max_is_bound(x); }
+    if let Some(x) = args.min_is_bound { builder.add_min_is_bound(x); }
     if let Some(x) = args.is_constant { builder.add_is_constant(x); }
     if let Some(x) = args.is_strict_sorted { builder.add_is_strict_sorted(x); }
     if let Some(x) = args.is_sorted { builder.add_is_sorted(x); }
@@ -395,6 +411,62 @@ impl<'a> ArrayStats<'a> {
     // which contains a valid value in this slot
     unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u64>>>(ArrayStats::VT_TRAILING_ZERO_FREQ, None)}
   }
+  #[inline]
+  pub fn approx_distinct(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ArrayStats::VT_APPROX_DISTINCT, None)}
+  }
+  #[inline]
+  pub fn approx_quantiles(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ArrayStats::VT_APPROX_QUANTILES, None)}
+  }
+  #[inline]
+  pub fn sum(&self) -> Option<ScalarValue<'a>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<ScalarValue>>(ArrayStats::VT_SUM, None)}
+  }
+  #[inline]
+  pub fn nan_count(&self) -> Option<u64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(ArrayStats::VT_NAN_COUNT, None)}
+  }
+  #[inline]
+  pub fn uncompressed_size_in_bytes(&self) -> Option<u64> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<u64>(ArrayStats::VT_UNCOMPRESSED_SIZE_IN_BYTES, None)}
+  }
+  #[inline]
+  pub fn min_is_bound(&self) -> Option<bool> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ArrayStats::VT_MIN_IS_BOUND, None)}
+  }
+  #[inline]
+  pub fn max_is_bound(&self) -> Option<bool> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<bool>(ArrayStats::VT_MAX_IS_BOUND, None)}
+  }
+  #[inline]
+  pub fn histogram(&self) -> Option<flatbuffers::Vector<'a, u8>> {
+    // Safety:
+    // Created from valid Table for this object
+    // which contains a valid value in this slot
+    unsafe { self._tab.get::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'a, u8>>>(ArrayStats::VT_HISTOGRAM, None)}
+  }
 }
 
 impl flatbuffers::Verifiable for ArrayStats<'_> {
@@ -414,6 +486,14 @@ impl flatbuffers::Verifiable for ArrayStats<'_> {
      .visit_field::<u64>("null_count", Self::VT_NULL_COUNT, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u64>>>("bit_width_freq", Self::VT_BIT_WIDTH_FREQ, false)?
      .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u64>>>("trailing_zero_freq", Self::VT_TRAILING_ZERO_FREQ, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("approx_distinct", Self::VT_APPROX_DISTINCT, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("approx_quantiles", Self::VT_APPROX_QUANTILES, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<ScalarValue>>("sum", Self::VT_SUM, false)?
+     .visit_field::<u64>("nan_count", Self::VT_NAN_COUNT, false)?
+     .visit_field::<u64>("uncompressed_size_in_bytes", Self::VT_UNCOMPRESSED_SIZE_IN_BYTES, false)?
+     .visit_field::<bool>("min_is_bound", Self::VT_MIN_IS_BOUND, false)?
+     .visit_field::<bool>("max_is_bound", Self::VT_MAX_IS_BOUND, false)?
+     .visit_field::<flatbuffers::ForwardsUOffset<flatbuffers::Vector<'_, u8>>>("histogram", Self::VT_HISTOGRAM, false)?
      .finish();
     Ok(())
   }
@@ -429,6 +509,14 @@ pub struct ArrayStatsArgs<'a> {
     pub null_count: Option<u64>,
     pub bit_width_freq: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u64>>>,
     pub trailing_zero_freq: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u64>>>,
+    pub approx_distinct: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub approx_quantiles: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
+    pub sum: Option<flatbuffers::WIPOffset<ScalarValue<'a>>>,
+    pub nan_count: Option<u64>,
+    pub uncompressed_size_in_bytes: Option<u64>,
+    pub min_is_bound: Option<bool>,
+    pub max_is_bound: Option<bool>,
+    pub histogram: Option<flatbuffers::WIPOffset<flatbuffers::Vector<'a, u8>>>,
 }
 impl<'a> Default for ArrayStatsArgs<'a> {
   #[inline]
@@ -444,6 +532,14 @@ impl<'a> Default for ArrayStatsArgs<'a> {
       null_count: None,
       bit_width_freq: None,
       trailing_zero_freq: None,
+      approx_distinct: None,
+      approx_quantiles: None,
+      sum: None,
+      nan_count: None,
+      uncompressed_size_in_bytes: None,
+      min_is_bound: None,
+      max_is_bound: None,
+      histogram: None,
     }
   }
 }
@@ -494,6 +590,38 @@ impl<'a: 'b, 'b, A: flatbuffers::Allocator + 'a> ArrayStatsBuilder<'a, 'b, A> {
     self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ArrayStats::VT_TRAILING_ZERO_FREQ, trailing_zero_freq);
   }
   #[inline]
+  pub fn add_approx_distinct(&mut self, approx_distinct: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ArrayStats::VT_APPROX_DISTINCT, approx_distinct);
+  }
+  #[inline]
+  pub fn add_approx_quantiles(&mut self, approx_quantiles: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ArrayStats::VT_APPROX_QUANTILES, approx_quantiles);
+  }
+  #[inline]
+  pub fn add_sum(&mut self, sum: flatbuffers::WIPOffset<ScalarValue<'b >>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<ScalarValue>>(ArrayStats::VT_SUM, sum);
+  }
+  #[inline]
+  pub fn add_nan_count(&mut self, nan_count: u64) {
+    self.fbb_.push_slot_always::<u64>(ArrayStats::VT_NAN_COUNT, nan_count);
+  }
+  #[inline]
+  pub fn add_uncompressed_size_in_bytes(&mut self, uncompressed_size_in_bytes: u64) {
+    self.fbb_.push_slot_always::<u64>(ArrayStats::VT_UNCOMPRESSED_SIZE_IN_BYTES, uncompressed_size_in_bytes);
+  }
+  #[inline]
+  pub fn add_min_is_bound(&mut self, min_is_bound: bool) {
+    self.fbb_.push_slot_always::<bool>(ArrayStats::VT_MIN_IS_BOUND, min_is_bound);
+  }
+  #[inline]
+  pub fn add_max_is_bound(&mut self, max_is_bound: bool) {
+    self.fbb_.push_slot_always::<bool>(ArrayStats::VT_MAX_IS_BOUND, max_is_bound);
+  }
+  #[inline]
+  pub fn add_histogram(&mut self, histogram: flatbuffers::WIPOffset<flatbuffers::Vector<'b , u8>>) {
+    self.fbb_.push_slot_always::<flatbuffers::WIPOffset<_>>(ArrayStats::VT_HISTOGRAM, histogram);
+  }
+  #[inline]
   pub fn new(_fbb: &'b mut flatbuffers::FlatBufferBuilder<'a, A>) -> ArrayStatsBuilder<'a, 'b, A> {
     let start = _fbb.start_table();
     ArrayStatsBuilder {
@@ -521,6 +649,14 @@ impl core::fmt::Debug for ArrayStats<'_> {
       ds.field("null_count", &self.null_count());
       ds.field("bit_width_freq", &self.bit_width_freq());
       ds.field("trailing_zero_freq", &self.trailing_zero_freq());
+      ds.field("approx_distinct", &self.approx_distinct());
+      ds.field("approx_quantiles", &self.approx_quantiles());
+      ds.field("sum", &self.sum());
+      ds.field("nan_count", &self.nan_count());
+      ds.field("uncompressed_size_in_bytes", &self.uncompressed_size_in_bytes());
+      ds.field("min_is_bound", &self.min_is_bound());
+      ds.field("max_is_bound", &self.max_is_bound());
+      ds.field("histogram", &self.histogram());
       ds.finish()
   }
 }
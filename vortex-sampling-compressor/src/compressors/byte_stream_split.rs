@@ -0,0 +1,115 @@
+use std::collections::HashSet;
+
+use vortex::array::PrimitiveArray;
+use vortex::encoding::EncodingRef;
+use vortex::{Array, ArrayDType, ArrayDef, IntoArray};
+use vortex_byte_stream_split::{
+    byte_stream_split_encode, ByteStreamSplit, ByteStreamSplitArray, ByteStreamSplitEncoding,
+};
+use vortex_dtype::PType;
+use vortex_error::VortexResult;
+
+use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+use crate::{constants, SamplingCompressor};
+
+#[derive(Debug)]
+pub struct ByteStreamSplitCompressor;
+
+impl EncodingCompressor for ByteStreamSplitCompressor {
+    fn id(&self) -> &str {
+        ByteStreamSplit::ID.as_ref()
+    }
+
+    fn cost(&self) -> u8 {
+        constants::BYTE_STREAM_SPLIT_COST
+    }
+
+    fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
+        // Only support primitive arrays
+        let parray = PrimitiveArray::try_from(array).ok()?;
+
+        // Only supports f32 and f64
+        if !matches!(parray.ptype(), PType::F32 | PType::F64) {
+            return None;
+        }
+
+        Some(self)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &Array,
+        like: Option<CompressionTree<'a>>,
+        ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let parray = array.as_primitive();
+        let streams = byte_stream_split_encode(&parray)?;
+
+        // Each byte position is compressed on its own.
+        let compressed_streams = streams
+            .into_iter()
+            .enumerate()
+            .map(|(k, stream)| {
+                ctx.named(&format!("stream_{k}"))
+                    .compress(&stream.into_array(), like.as_ref().and_then(|l| l.child(k)))
+            })
+            .collect::<VortexResult<Vec<_>>>()?;
+        let (streams, paths): (Vec<_>, Vec<_>) = compressed_streams
+            .into_iter()
+            .map(|c| (c.array, c.path))
+            .unzip();
+
+        Ok(CompressedArray::new(
+            ByteStreamSplitArray::try_new(
+                array.dtype().clone(),
+                streams,
+                ctx.compress_validity(parray.validity())?,
+            )?
+            .into_array(),
+            Some(CompressionTree::new(self, paths)),
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([&ByteStreamSplitEncoding as EncodingRef])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex::array::PrimitiveArray;
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_byte_stream_split::ByteStreamSplitArray;
+
+    use crate::compressors::byte_stream_split::ByteStreamSplitCompressor;
+    use crate::compressors::EncodingCompressor as _;
+    use crate::SamplingCompressor;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_byte_stream_split_compressor() {
+        // Values in [1, 2) with noisy mantissas share their sign and exponent bytes.
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let values = (0..10_000)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                1.0 + (state >> 11) as f64 / (1u64 << 53) as f64
+            })
+            .collect::<Vec<_>>();
+        let array = PrimitiveArray::from(values.clone()).into_array();
+        assert!(ByteStreamSplitCompressor.can_compress(&array).is_some());
+
+        let compressed = ByteStreamSplitCompressor
+            .compress(&array, None, SamplingCompressor::default())
+            .unwrap();
+        assert!(compressed.array.nbytes() < array.nbytes());
+        let split = ByteStreamSplitArray::try_from(compressed.array).unwrap();
+        assert!(split.stream(7).nbytes() < 100);
+        assert_eq!(
+            split.into_primitive().unwrap().maybe_null_slice::<f64>(),
+            values.as_slice()
+        );
+    }
+}
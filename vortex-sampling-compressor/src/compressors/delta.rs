@@ -2,6 +2,7 @@ use std::collections::HashSet;
 
 use vortex::array::PrimitiveArray;
 use vortex::encoding::EncodingRef;
+use vortex::stats::ArrayStatistics;
 use vortex::{Array, ArrayDef, IntoArray};
 use vortex_error::VortexResult;
 use vortex_fastlanes::{delta_compress, Delta, DeltaArray, DeltaEncoding};
@@ -30,6 +31,12 @@ impl EncodingCompressor for DeltaCompressor {
             return None;
         }
 
+        // Deltas are only small when the values are monotonic, e.g. sorted IDs or timestamps,
+        // and otherwise wrap around to large values that bit-pack poorly.
+        if !parray.statistics().compute_is_sorted().unwrap_or(false) {
+            return None;
+        }
+
         Some(self)
     }
 
@@ -51,6 +58,7 @@ impl EncodingCompressor for DeltaCompressor {
             .compress(bases.as_ref(), like.as_ref().and_then(|l| l.child(0)))?;
         let deltas = ctx
             .named("deltas")
+            .excluding(self)
             .compress(deltas.as_ref(), like.as_ref().and_then(|l| l.child(1)))?;
 
         Ok(CompressedArray::new(
@@ -64,3 +72,31 @@ impl EncodingCompressor for DeltaCompressor {
         HashSet::from([&DeltaEncoding as EncodingRef])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use vortex::array::PrimitiveArray;
+    use vortex::IntoArray;
+    use vortex_fastlanes::DeltaArray;
+
+    use crate::compressors::delta::DeltaCompressor;
+    use crate::compressors::EncodingCompressor as _;
+    use crate::SamplingCompressor;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_delta_compressor() {
+        let array =
+            PrimitiveArray::from((0..5000u64).map(|i| (1 << 40) + i * 3).collect::<Vec<_>>())
+                .into_array();
+        assert!(DeltaCompressor.can_compress(&array).is_some());
+        let compressed = DeltaCompressor
+            .compress(&array, None, SamplingCompressor::default())
+            .unwrap();
+        assert!(DeltaArray::try_from(compressed.array.clone()).is_ok());
+        assert!(compressed.array.nbytes() < array.nbytes() / 8);
+
+        let unsorted = PrimitiveArray::from(vec![5u32, 1, 4]).into_array();
+        assert!(DeltaCompressor.can_compress(&unsorted).is_none());
+    }
+}
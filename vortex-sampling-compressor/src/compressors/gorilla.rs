@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+use vortex::array::PrimitiveArray;
+use vortex::encoding::EncodingRef;
+use vortex::{Array, ArrayDType, ArrayDef, IntoArray};
+use vortex_dtype::PType;
+use vortex_error::VortexResult;
+use vortex_gorilla::{gorilla_encode, Gorilla, GorillaArray, GorillaEncoding};
+
+use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+use crate::{constants, SamplingCompressor};
+
+#[derive(Debug)]
+pub struct GorillaCompressor;
+
+impl EncodingCompressor for GorillaCompressor {
+    fn id(&self) -> &str {
+        Gorilla::ID.as_ref()
+    }
+
+    fn cost(&self) -> u8 {
+        constants::GORILLA_COST
+    }
+
+    fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
+        // Only support primitive arrays
+        let parray = PrimitiveArray::try_from(array).ok()?;
+
+        // Only supports f32 and f64
+        if !matches!(parray.ptype(), PType::F32 | PType::F64) {
+            return None;
+        }
+
+        Some(self)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &Array,
+        _like: Option<CompressionTree<'a>>,
+        ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let parray = array.as_primitive();
+        let (bits, block_offsets) = gorilla_encode(&parray)?;
+
+        // The bit stream is already dense, so it is stored as is.
+        Ok(CompressedArray::new(
+            GorillaArray::try_new(
+                array.dtype().clone(),
+                bits.into_array(),
+                block_offsets.into_array(),
+                ctx.compress_validity(parray.validity())?,
+                0,
+                array.len(),
+            )?
+            .into_array(),
+            Some(CompressionTree::flat(self)),
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([&GorillaEncoding as EncodingRef])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex::array::PrimitiveArray;
+    use vortex::{ArrayDef, IntoArray, IntoArrayVariant};
+    use vortex_gorilla::Gorilla;
+
+    use crate::compressors::gorilla::GorillaCompressor;
+    use crate::compressors::EncodingCompressor as _;
+    use crate::SamplingCompressor;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_gorilla_compressor() {
+        // A slowly drifting reading with full precision noise, which ALP cannot represent.
+        let values = (0..10_000)
+            .map(|i| 21.5 + (i / 50) as f64 * 0.001 + if i % 50 == 0 { 1.0 / 3.0 } else { 0.0 })
+            .collect::<Vec<_>>();
+        let array = PrimitiveArray::from(values.clone()).into_array();
+        assert!(GorillaCompressor.can_compress(&array).is_some());
+
+        let compressed = GorillaCompressor
+            .compress(&array, None, SamplingCompressor::default())
+            .unwrap();
+        assert!(compressed.array.nbytes() < array.nbytes() / 4);
+        assert_eq!(
+            compressed
+                .array
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<f64>(),
+            values.as_slice()
+        );
+
+        // The sampling compressor prefers it to ALP on such data.
+        let chosen = SamplingCompressor::default()
+            .compress(&array, None)
+            .unwrap();
+        assert_eq!(chosen.array.encoding().id(), Gorilla::ID);
+    }
+}
@@ -13,6 +13,7 @@ use crate::SamplingCompressor;
 pub mod alp;
 pub mod alp_rd;
 pub mod bitpacked;
+pub mod byte_stream_split;
 pub mod chunked;
 pub mod constant;
 pub mod date_time_parts;
@@ -20,9 +21,12 @@ pub mod delta;
 pub mod dict;
 pub mod r#for;
 pub mod fsst;
+pub mod gorilla;
+pub mod rle;
 pub mod roaring_bool;
 pub mod roaring_int;
 pub mod runend;
+pub mod sequence;
 pub mod sparse;
 pub mod struct_;
 pub mod zigzag;
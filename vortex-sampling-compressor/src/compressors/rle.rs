@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use vortex::array::Primitive;
+use vortex::encoding::EncodingRef;
+use vortex::stats::ArrayStatistics;
+use vortex::{Array, ArrayDef, IntoArray};
+use vortex_error::VortexResult;
+use vortex_fastlanes::{rle_encode, RLEArray, RLEEncoding, RLE};
+
+use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+use crate::{constants, SamplingCompressor};
+
+pub const DEFAULT_RLE_COMPRESSOR: RLECompressor = RLECompressor { rle_threshold: 2.0 };
+
+#[derive(Debug, Clone, Copy)]
+pub struct RLECompressor {
+    rle_threshold: f32,
+}
+
+impl EncodingCompressor for RLECompressor {
+    fn id(&self) -> &str {
+        RLE::ID.as_ref()
+    }
+
+    fn cost(&self) -> u8 {
+        constants::RLE_COST
+    }
+
+    fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
+        if array.encoding().id() != Primitive::ID {
+            return None;
+        }
+
+        let avg_run_length = array.len() as f32
+            / array
+                .statistics()
+                .compute_run_count()
+                .unwrap_or(array.len()) as f32;
+        if avg_run_length < self.rle_threshold {
+            return None;
+        }
+
+        Some(self)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &Array,
+        like: Option<CompressionTree<'a>>,
+        ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let primitive_array = array.as_primitive();
+
+        let (values, indices, values_idx_offsets) = rle_encode(&primitive_array)?;
+        let compressed_values = ctx
+            .named("values")
+            .excluding(self)
+            .compress(&values.into_array(), like.as_ref().and_then(|l| l.child(0)))?;
+        let compressed_indices = ctx.auxiliary("indices").compress(
+            &indices.into_array(),
+            like.as_ref().and_then(|l| l.child(1)),
+        )?;
+        let compressed_offsets = ctx.auxiliary("values_idx_offsets").compress(
+            &values_idx_offsets.into_array(),
+            like.as_ref().and_then(|l| l.child(2)),
+        )?;
+
+        Ok(CompressedArray::new(
+            RLEArray::try_new(
+                compressed_values.array,
+                compressed_indices.array,
+                compressed_offsets.array,
+                ctx.compress_validity(primitive_array.validity())?,
+                0,
+                array.len(),
+            )
+            .map(|a| a.into_array())?,
+            Some(CompressionTree::new(
+                self,
+                vec![
+                    compressed_values.path,
+                    compressed_indices.path,
+                    compressed_offsets.path,
+                ],
+            )),
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([&RLEEncoding as EncodingRef])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex::array::PrimitiveArray;
+    use vortex::{IntoArray, IntoArrayVariant};
+    use vortex_fastlanes::RLEArray;
+
+    use crate::compressors::rle::DEFAULT_RLE_COMPRESSOR;
+    use crate::compressors::EncodingCompressor as _;
+    use crate::SamplingCompressor;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_rle_compressor() {
+        let values = (0..5000i64).map(|i| i / 64).collect::<Vec<_>>();
+        let array = PrimitiveArray::from(values.clone()).into_array();
+        assert!(DEFAULT_RLE_COMPRESSOR.can_compress(&array).is_some());
+        let compressed = DEFAULT_RLE_COMPRESSOR
+            .compress(&array, None, SamplingCompressor::default())
+            .unwrap();
+        assert!(compressed.array.nbytes() < array.nbytes() / 8);
+
+        let rle = RLEArray::try_from(compressed.array).unwrap();
+        assert_eq!(
+            rle.into_primitive().unwrap().maybe_null_slice::<i64>(),
+            values.as_slice()
+        );
+
+        let unique = PrimitiveArray::from((0..5000i64).collect::<Vec<_>>()).into_array();
+        assert!(DEFAULT_RLE_COMPRESSOR.can_compress(&unique).is_none());
+    }
+}
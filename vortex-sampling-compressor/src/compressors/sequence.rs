@@ -0,0 +1,102 @@
+use std::collections::HashSet;
+
+use vortex::array::PrimitiveArray;
+use vortex::encoding::EncodingRef;
+use vortex::{Array, ArrayDef, IntoArray};
+use vortex_error::{vortex_err, VortexResult};
+use vortex_sequence::{sequence_encode, Sequence, SequenceEncoding};
+
+use crate::compressors::{CompressedArray, CompressionTree, EncodingCompressor};
+use crate::{constants, SamplingCompressor};
+
+#[derive(Debug)]
+pub struct SequenceCompressor;
+
+impl EncodingCompressor for SequenceCompressor {
+    fn id(&self) -> &str {
+        Sequence::ID.as_ref()
+    }
+
+    fn cost(&self) -> u8 {
+        constants::SEQUENCE_COST
+    }
+
+    fn can_compress(&self, array: &Array) -> Option<&dyn EncodingCompressor> {
+        // Only support primitive arrays
+        let parray = PrimitiveArray::try_from(array).ok()?;
+
+        // Constant arrays are left to the constant compressor.
+        let sequence = sequence_encode(&parray).ok()??;
+        (sequence.step() != 0).then_some(self as &dyn EncodingCompressor)
+    }
+
+    fn compress<'a>(
+        &'a self,
+        array: &Array,
+        _like: Option<CompressionTree<'a>>,
+        _ctx: SamplingCompressor<'a>,
+    ) -> VortexResult<CompressedArray<'a>> {
+        let sequence = sequence_encode(&PrimitiveArray::try_from(array)?)?
+            .ok_or_else(|| vortex_err!("Array is not an arithmetic progression: {}", array))?;
+        Ok(CompressedArray::new(
+            sequence.into_array(),
+            Some(CompressionTree::flat(self)),
+        ))
+    }
+
+    fn used_encodings(&self) -> HashSet<EncodingRef> {
+        HashSet::from([&SequenceEncoding as EncodingRef])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex::array::{ChunkedArray, PrimitiveArray};
+    use vortex::{ArrayDType, ArrayDef, IntoArray, IntoArrayVariant};
+    use vortex_sequence::Sequence;
+
+    use crate::compressors::sequence::SequenceCompressor;
+    use crate::compressors::EncodingCompressor as _;
+    use crate::SamplingCompressor;
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_sequence_compressor() {
+        let values = (0..100_000u64).map(|i| 1_000 + 2 * i).collect::<Vec<_>>();
+        let array = PrimitiveArray::from(values.clone()).into_array();
+        assert!(SequenceCompressor.can_compress(&array).is_some());
+
+        // Sampled slices of a sequence are not a sequence, so it is detected on the whole array.
+        let compressed = SamplingCompressor::default()
+            .compress(&array, None)
+            .unwrap();
+        assert_eq!(compressed.array.encoding().id(), Sequence::ID);
+        assert_eq!(
+            compressed
+                .array
+                .into_primitive()
+                .unwrap()
+                .maybe_null_slice::<u64>(),
+            values.as_slice()
+        );
+
+        // Chunks are compressed one by one, each into its own sequence.
+        let chunked =
+            ChunkedArray::try_new(vec![array.clone(), array.clone()], array.dtype().clone())
+                .unwrap()
+                .into_array();
+        let compressed = SamplingCompressor::default()
+            .compress(&chunked, None)
+            .unwrap();
+        assert!(ChunkedArray::try_from(compressed.array)
+            .unwrap()
+            .chunks()
+            .all(|chunk| chunk.encoding().id() == Sequence::ID));
+
+        let mut values = values;
+        values[500] += 1;
+        assert!(SequenceCompressor
+            .can_compress(&PrimitiveArray::from(values).into_array())
+            .is_none());
+    }
+}
@@ -17,23 +17,31 @@ use vortex::encoding::EncodingRef;
 use vortex::validity::Validity;
 use vortex::{Array, ArrayDType, ArrayDef, Context, IntoCanonical};
 use vortex_alp::{ALPEncoding, ALPRDEncoding};
+use vortex_byte_stream_split::ByteStreamSplitEncoding;
 use vortex_bytebool::ByteBoolEncoding;
 use vortex_datetime_parts::DateTimePartsEncoding;
 use vortex_dict::DictEncoding;
 use vortex_error::{VortexExpect as _, VortexResult};
-use vortex_fastlanes::{BitPackedEncoding, DeltaEncoding, FoREncoding};
+use vortex_fastlanes::{BitPackedEncoding, DeltaEncoding, FoREncoding, RLEEncoding};
 use vortex_fsst::FSSTEncoding;
+use vortex_gorilla::GorillaEncoding;
 use vortex_roaring::{RoaringBoolEncoding, RoaringIntEncoding};
 use vortex_runend::RunEndEncoding;
 use vortex_runend_bool::RunEndBoolEncoding;
+use vortex_sequence::SequenceEncoding;
 use vortex_zigzag::ZigZagEncoding;
 
 use crate::compressors::alp::ALPCompressor;
+use crate::compressors::byte_stream_split::ByteStreamSplitCompressor;
 use crate::compressors::constant::ConstantCompressor;
 use crate::compressors::date_time_parts::DateTimePartsCompressor;
+use crate::compressors::delta::DeltaCompressor;
 use crate::compressors::dict::DictCompressor;
+use crate::compressors::gorilla::GorillaCompressor;
 use crate::compressors::r#for::FoRCompressor;
+use crate::compressors::rle::DEFAULT_RLE_COMPRESSOR;
 use crate::compressors::runend::DEFAULT_RUN_END_COMPRESSOR;
+use crate::compressors::sequence::SequenceCompressor;
 use crate::compressors::sparse::SparseCompressor;
 use crate::compressors::zigzag::ZigZagCompressor;
 use crate::compressors::{CompressedArray, CompressionTree, CompressorRef, EncodingCompressor};
@@ -46,17 +54,20 @@ mod constants;
 mod sampling;
 
 lazy_static! {
-    pub static ref DEFAULT_COMPRESSORS: [CompressorRef<'static>; 9] = [
+    pub static ref DEFAULT_COMPRESSORS: [CompressorRef<'static>; 13] = [
         &ALPCompressor as CompressorRef,
         &BITPACK_WITH_PATCHES,
+        &ByteStreamSplitCompressor,
         &DateTimePartsCompressor,
         &DEFAULT_RUN_END_COMPRESSOR,
-        // &DeltaCompressor,
+        &DeltaCompressor,
         &DictCompressor,
         &FoRCompressor,
         &FSSTCompressor,
+        &GorillaCompressor,
         // &RoaringBoolCompressor,
         // &RoaringIntCompressor,
+        &SequenceCompressor,
         &SparseCompressor,
         &ZigZagCompressor,
     ];
@@ -64,9 +75,9 @@ lazy_static! {
     pub static ref FASTEST_COMPRESSORS: [CompressorRef<'static>; 7] = [
         &BITPACK_WITH_PATCHES,
         &DateTimePartsCompressor,
-        &DEFAULT_RUN_END_COMPRESSOR, // replace with FastLanes RLE
         &DictCompressor, // replace with FastLanes Dictionary
         &FoRCompressor,
+        &DEFAULT_RLE_COMPRESSOR,
         &SparseCompressor,
         &ZigZagCompressor,
     ];
@@ -74,16 +85,20 @@ lazy_static! {
     pub static ref ALL_COMPRESSORS_CONTEXT: Arc<Context> = Arc::new(Context::default().with_encodings([
         &ALPEncoding as EncodingRef,
         &ByteBoolEncoding,
+        &ByteStreamSplitEncoding,
         &DateTimePartsEncoding,
         &DictEncoding,
         &BitPackedEncoding,
         &DeltaEncoding,
         &FoREncoding,
+        &RLEEncoding,
         &FSSTEncoding,
+        &GorillaEncoding,
         &RoaringBoolEncoding,
         &RoaringIntEncoding,
         &RunEndEncoding,
         &RunEndBoolEncoding,
+        &SequenceEncoding,
         &ZigZagEncoding,
         &ALPRDEncoding,
     ]));
@@ -261,6 +276,10 @@ impl<'a> SamplingCompressor<'a> {
         Ok(compressed)
     }
 
+    fn is_enabled(&self, compressor: CompressorRef<'a>) -> bool {
+        self.compressors.contains(&compressor) && !self.disabled_compressors.contains(&compressor)
+    }
+
     pub fn compress_validity(&self, validity: Validity) -> VortexResult<Validity> {
         match validity {
             Validity::Array(a) => Ok(Validity::Array(self.compress(&a, None)?.into_array())),
@@ -288,10 +307,19 @@ impl<'a> SamplingCompressor<'a> {
             return cc.compress(array, None, self.clone());
         }
 
+        // The stratified sample of an arithmetic progression is not one, so it must be detected
+        // on the whole array and is never sampled as a candidate below.
+        if self.is_enabled(&SequenceCompressor) {
+            if let Some(cc) = SequenceCompressor.can_compress(array) {
+                return cc.compress(array, None, self.for_compressor(cc));
+            }
+        }
+
         let (mut candidates, too_deep) = self
             .compressors
             .iter()
             .filter(|&encoding| !self.disabled_compressors.contains(encoding))
+            .filter(|&encoding| encoding.id() != SequenceCompressor.id())
             .filter(|&encoding| encoding.can_compress(array).is_some())
             .partition::<Vec<&dyn EncodingCompressor>, _>(|&encoding| {
                 self.depth + encoding.cost() <= self.options.max_cost
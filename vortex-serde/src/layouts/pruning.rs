@@ -7,7 +7,7 @@ use std::sync::Arc;
 use ahash::{HashMap, HashMapExt};
 use vortex::stats::Stat;
 use vortex_dtype::field::Field;
-use vortex_dtype::Nullability;
+use vortex_dtype::{DType, Nullability};
 use vortex_expr::{BinaryExpr, Column, Literal, Operator, VortexExpr};
 use vortex_scalar::Scalar;
 
@@ -56,13 +56,14 @@ fn convert_to_pruning_expression(
         };
 
         if let Some(col) = bexp.rhs().as_any().downcast_ref::<Column>() {
-            return PruningPredicateRewriter::try_new(
-                col.field().clone(),
-                bexp.op().swap(),
-                bexp.lhs(),
-            )
-            .and_then(PruningPredicateRewriter::rewrite)
-            .unwrap_or_else(|| (fallback, HashMap::new()));
+            return bexp
+                .op()
+                .swap()
+                .and_then(|op| {
+                    PruningPredicateRewriter::try_new(col.field().clone(), op, bexp.lhs())
+                })
+                .and_then(PruningPredicateRewriter::rewrite)
+                .unwrap_or_else(|| (fallback, HashMap::new()));
         };
     }
 
@@ -90,6 +91,11 @@ impl<'a> PruningPredicateRewriter<'a> {
             return None;
         }
 
+        // Stats can only stand in for the columns of the other side if it preserves their order.
+        if has_bitwise_operator(other_exp) {
+            return None;
+        }
+
         Some(Self {
             column,
             operator,
@@ -128,6 +134,9 @@ impl<'a> PruningPredicateRewriter<'a> {
                     Arc::new(BinaryExpr::new(replaced_min, Operator::Gt, max_col)),
                 )))
             }
+            // Utf8 and binary min and max may be truncated bounds, which can be equal to a value
+            // that the column doesn't contain.
+            Operator::NotEq if is_varbin_literal(self.other_exp) => None,
             Operator::NotEq => {
                 let min_col = Arc::new(Column::new(self.add_stat_reference(Stat::Min)));
                 let max_col = Arc::new(Column::new(self.add_stat_reference(Stat::Max)));
@@ -179,6 +188,30 @@ impl<'a> PruningPredicateRewriter<'a> {
     }
 }
 
+fn is_varbin_literal(expr: &Arc<dyn VortexExpr>) -> bool {
+    expr.as_any()
+        .downcast_ref::<Literal>()
+        .is_some_and(|lit| matches!(lit.value().dtype(), DType::Utf8(_) | DType::Binary(_)))
+}
+
+/// Whether the expression contains a bitwise or shift operator, which are not monotonic in their
+/// operands, e.g. `max(b) & 3` is not an upper bound of `b & 3`.
+fn has_bitwise_operator(expr: &Arc<dyn VortexExpr>) -> bool {
+    expr.as_any()
+        .downcast_ref::<BinaryExpr>()
+        .is_some_and(|bexp| {
+            matches!(
+                bexp.op(),
+                Operator::BitwiseAnd
+                    | Operator::BitwiseOr
+                    | Operator::BitwiseXor
+                    | Operator::ShiftLeft
+                    | Operator::ShiftRight
+            ) || has_bitwise_operator(bexp.lhs())
+                || has_bitwise_operator(bexp.rhs())
+        })
+}
+
 fn replace_column_with_stat(
     expr: &Arc<dyn VortexExpr>,
     stat: Stat,
@@ -393,6 +426,47 @@ mod tests {
         assert_eq!(*converted, *expected_expr.as_any());
     }
 
+    #[test]
+    pub fn pruning_bitwise_column() {
+        // `a > (b & 3)` can hold in a chunk with `max(a) <= max(b) & 3`, e.g. a = 2 and b = 6.
+        for op in [
+            Operator::BitwiseAnd,
+            Operator::BitwiseOr,
+            Operator::BitwiseXor,
+            Operator::ShiftLeft,
+            Operator::ShiftRight,
+        ] {
+            let gt_expr = Arc::new(BinaryExpr::new(
+                Arc::new(Column::new(Field::from("a"))),
+                Operator::Gt,
+                Arc::new(BinaryExpr::new(
+                    Arc::new(Column::new(Field::from("b"))),
+                    op,
+                    Arc::new(Literal::new(3.into())),
+                )),
+            )) as _;
+
+            let (converted, refs) = convert_to_pruning_expression(&gt_expr);
+            assert!(refs.is_empty());
+            let expected_expr: Arc<dyn VortexExpr> = Arc::new(Literal::new(true.into()));
+            assert_eq!(*converted, *expected_expr.as_any());
+        }
+    }
+
+    #[test]
+    pub fn pruning_not_equals_string_value() {
+        let not_eq_expr = Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("a"))),
+            Operator::NotEq,
+            Arc::new(Literal::new("hello".into())),
+        )) as _;
+
+        let (converted, refs) = convert_to_pruning_expression(&not_eq_expr);
+        assert!(refs.is_empty());
+        let expected_expr: Arc<dyn VortexExpr> = Arc::new(Literal::new(true.into()));
+        assert_eq!(*converted, *expected_expr.as_any());
+    }
+
     #[test]
     pub fn pruning_lt_column() {
         let column = Field::from("a");
@@ -0,0 +1,134 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use arrow_buffer::BooleanBuffer;
+use vortex::compute::unary::scalar_at;
+use vortex::compute::FilterMask;
+use vortex::stats::BloomFilter;
+use vortex::variants::StructArrayTrait;
+use vortex::{Array, IntoArrayVariant};
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, VortexResult};
+use vortex_scalar::Scalar;
+
+/// Row ranges of a file that a scan skips without reading, because no row in them can pass the
+/// row filter.
+#[derive(Debug, Clone)]
+pub struct SkippedRows {
+    ranges: Arc<[Range<u64>]>,
+    row_count: u64,
+}
+
+impl SkippedRows {
+    pub fn new(mut ranges: Vec<Range<u64>>, row_count: u64) -> Self {
+        ranges.sort_by_key(|r| r.start);
+        let mut merged: Vec<Range<u64>> = Vec::with_capacity(ranges.len());
+        for range in ranges.into_iter().filter(|r| !r.is_empty()) {
+            match merged.last_mut() {
+                Some(last) if last.end >= range.start => last.end = last.end.max(range.end),
+                _ => merged.push(range),
+            }
+        }
+        Self {
+            ranges: merged.into(),
+            row_count,
+        }
+    }
+
+    /// The number of rows in the file.
+    pub fn row_count(&self) -> u64 {
+        self.row_count
+    }
+
+    /// The parts of `range` that are read.
+    pub fn kept(&self, range: Range<u64>) -> Vec<Range<u64>> {
+        let mut kept = Vec::new();
+        let mut start = range.start;
+        for skipped in self.ranges.iter() {
+            if skipped.end <= start {
+                continue;
+            }
+            if skipped.start >= range.end {
+                break;
+            }
+            if skipped.start > start {
+                kept.push(start..skipped.start);
+            }
+            start = skipped.end;
+        }
+        if start < range.end {
+            kept.push(start..range.end);
+        }
+        kept
+    }
+
+    /// Restrict a mask over every row of the file to the rows that are read.
+    pub fn compact(&self, mask: &FilterMask) -> VortexResult<FilterMask> {
+        if mask.len() as u64 != self.row_count {
+            vortex_bail!(
+                "Mask of length {} does not cover the {} rows of the file",
+                mask.len(),
+                self.row_count
+            );
+        }
+        let buffer = mask.boolean_buffer();
+        Ok(FilterMask::from(BooleanBuffer::from_iter(
+            self.kept(0..self.row_count)
+                .into_iter()
+                .flat_map(|r| r.map(|i| buffer.value(i as usize))),
+        )))
+    }
+}
+
+/// The row ranges of the chunks whose bloom filters rule out every one of `values`.
+///
+/// `metadata` is a column's chunk metadata table, and chunks without a bloom filter are kept.
+pub fn bloom_skipped_ranges(
+    metadata: Array,
+    row_count: u64,
+    dtype: &DType,
+    values: &[Scalar],
+) -> VortexResult<Vec<Range<u64>>> {
+    let metadata = metadata.into_struct()?;
+    let Some(blooms) = metadata.field_by_name("bloom_filter") else {
+        return Ok(Vec::new());
+    };
+    let row_offsets = metadata
+        .field_by_name("row_offset")
+        .ok_or_else(|| vortex_err!("Chunk metadata is missing row offsets"))?
+        .into_primitive()?;
+    let row_offsets = row_offsets.maybe_null_slice::<u64>();
+
+    let mut skipped = Vec::new();
+    for (chunk, &begin) in row_offsets.iter().enumerate() {
+        let bloom = scalar_at(&blooms, chunk)?;
+        if bloom.is_null() {
+            continue;
+        }
+        let bloom = BloomFilter::try_from(&bloom)?;
+        if !values.iter().any(|v| bloom.might_contain_scalar(v, dtype)) {
+            let end = row_offsets.get(chunk + 1).copied().unwrap_or(row_count);
+            skipped.push(begin..end);
+        }
+    }
+    Ok(skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use vortex::compute::FilterMask;
+
+    use crate::layouts::read::bloom::SkippedRows;
+
+    #[test]
+    fn kept_and_compact() {
+        let skipped = SkippedRows::new(vec![6..8, 2..4, 3..5], 10);
+        assert_eq!(skipped.kept(0..10), vec![0..2, 5..6, 8..10]);
+        assert_eq!(skipped.kept(3..7), vec![5..6]);
+        assert!(skipped.kept(2..5).is_empty());
+
+        let mask = FilterMask::from_indices(10, [1, 2, 5, 9]);
+        assert_eq!(skipped.compact(&mask).unwrap().indices(), &[1, 2, 4]);
+        assert!(skipped.compact(&FilterMask::from_indices(9, [1])).is_err());
+    }
+}
@@ -1,16 +1,22 @@
 use std::sync::{Arc, RwLock};
 
-use vortex::{Array, ArrayDType};
-use vortex_error::VortexResult;
+use bytes::BytesMut;
+use vortex::array::ChunkedArray;
+use vortex::compute::FilterMask;
+use vortex::{Array, ArrayDType, IntoArray};
+use vortex_dtype::field::Field;
+use vortex_dtype::DType;
+use vortex_error::{vortex_bail, vortex_err, vortex_panic, VortexResult};
 use vortex_schema::projection::Projection;
 
 use crate::io::VortexReadAt;
+use crate::layouts::read::bloom::{bloom_skipped_ranges, SkippedRows};
 use crate::layouts::read::cache::{LayoutMessageCache, LazyDeserializedDType, RelativeLayoutCache};
 use crate::layouts::read::context::LayoutDeserializer;
 use crate::layouts::read::filtering::RowFilter;
-use crate::layouts::read::footer::LayoutDescriptorReader;
+use crate::layouts::read::footer::{LayoutDescriptor, LayoutDescriptorReader};
 use crate::layouts::read::stream::LayoutBatchStream;
-use crate::layouts::read::{Scan, DEFAULT_BATCH_SIZE};
+use crate::layouts::read::{LayoutReader, ReadResult, Scan, DEFAULT_BATCH_SIZE};
 
 pub struct LayoutReaderBuilder<R> {
     reader: R,
@@ -18,6 +24,7 @@ pub struct LayoutReaderBuilder<R> {
     projection: Option<Projection>,
     size: Option<u64>,
     indices: Option<Array>,
+    mask: Option<FilterMask>,
     row_filter: Option<RowFilter>,
     batch_size: Option<usize>,
 }
@@ -31,6 +38,7 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
             row_filter: None,
             size: None,
             indices: None,
+            mask: None,
             batch_size: None,
         }
     }
@@ -45,8 +53,13 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         self
     }
 
+    /// Only read the rows at the given indices.
+    ///
+    /// Unlike [take](vortex::compute::take), the rows are returned in file order and each row at
+    /// most once: the indices are sorted and deduplicated, so callers that need the rows in the
+    /// order of their indices have to take them from the result again. The indices are applied
+    /// together with any mask and row filter.
     pub fn with_indices(mut self, array: Array) -> Self {
-        // TODO(#441): Allow providing boolean masks
         assert!(
             array.dtype().is_int(),
             "Mask arrays have to be integer arrays"
@@ -55,6 +68,14 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         self
     }
 
+    /// Only read the rows selected by the mask, which must cover every row of the file.
+    ///
+    /// The mask is applied together with any row filter.
+    pub fn with_mask(mut self, mask: FilterMask) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
     pub fn with_row_filter(mut self, row_filter: RowFilter) -> Self {
         self.row_filter = Some(row_filter);
         self
@@ -75,6 +96,48 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
             footer.dtype_bytes()?,
             Projection::All,
         ));
+        let message_cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
+
+        let skipped_rows = match &self.row_filter {
+            Some(row_filter) => {
+                self.skipped_rows(
+                    &footer,
+                    row_filter,
+                    batch_size,
+                    &footer_dtype,
+                    &message_cache,
+                )
+                .await?
+            }
+            None => None,
+        };
+        let row_count = footer.row_count()? as usize;
+        let indices_mask = self
+            .indices
+            .as_ref()
+            .map(|indices| FilterMask::try_from_indices_array(row_count, indices))
+            .transpose()?;
+        if let Some(mask) = &self.mask {
+            if mask.len() != row_count {
+                vortex_bail!(
+                    "Mask of length {} does not cover the {} rows of the file",
+                    mask.len(),
+                    row_count
+                );
+            }
+        }
+        let mask = match (self.mask, indices_mask) {
+            (Some(mask), Some(indices_mask)) => Some(FilterMask::from(
+                mask.boolean_buffer() & indices_mask.boolean_buffer(),
+            )),
+            (mask, indices_mask) => mask.or(indices_mask),
+        };
+        // The stream only sees the rows that are read, so the mask has to skip the same rows.
+        let mask = match (mask, &skipped_rows) {
+            (Some(mask), Some(skipped)) => Some(skipped.compact(&mask)?),
+            (mask, _) => mask,
+        };
+
         let read_projection = self.projection.unwrap_or_default();
 
         let filter_projection = self
@@ -92,11 +155,10 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
             filter: self.row_filter.clone(),
             batch_size,
             projection: read_projection,
-            indices: self.indices,
+            mask,
+            skipped_rows: skipped_rows.clone(),
         };
 
-        let message_cache = Arc::new(RwLock::new(LayoutMessageCache::default()));
-
         let data_reader = footer.layout(
             scan.clone(),
             RelativeLayoutCache::new(message_cache.clone(), footer_dtype.clone()),
@@ -109,7 +171,8 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
                         filter: self.row_filter,
                         batch_size,
                         projection,
-                        indices: None,
+                        mask: None,
+                        skipped_rows,
                     },
                     RelativeLayoutCache::new(message_cache.clone(), footer_dtype),
                 )
@@ -126,6 +189,97 @@ impl<R: VortexReadAt> LayoutReaderBuilder<R> {
         ))
     }
 
+    /// Rows in chunks whose bloom filters show that they cannot pass the row filter.
+    async fn skipped_rows(
+        &self,
+        footer: &LayoutDescriptor,
+        row_filter: &RowFilter,
+        batch_size: usize,
+        footer_dtype: &Arc<LazyDeserializedDType>,
+        message_cache: &Arc<RwLock<LayoutMessageCache>>,
+    ) -> VortexResult<Option<SkippedRows>> {
+        let lookups = row_filter.point_lookups();
+        if lookups.is_empty() {
+            return Ok(None);
+        }
+        let DType::Struct(struct_dtype, _) = footer.dtype()? else {
+            return Ok(None);
+        };
+        let row_count = footer.row_count()?;
+
+        let mut ranges = Vec::new();
+        for (field, values) in lookups {
+            let column = match &field {
+                Field::Name(name) => struct_dtype.find_name(name),
+                Field::Index(idx) => Some(*idx),
+            };
+            let Some((column, dtype)) =
+                column.and_then(|c| Some((c, struct_dtype.dtypes().get(c)?)))
+            else {
+                continue;
+            };
+            let scan = Scan {
+                mask: None,
+                projection: Projection::All,
+                filter: None,
+                batch_size,
+                skipped_rows: None,
+            };
+            // Read through the same message ids as the column's chunked layout, which needs the
+            // metadata table again to find the rows of its chunks.
+            let chunked_cache =
+                RelativeLayoutCache::new(message_cache.clone(), footer_dtype.clone())
+                    .relative(column as u16, footer_dtype.clone());
+            let Some(layout) = footer.chunk_metadata_layout(
+                column,
+                scan,
+                chunked_cache.relative(0, footer_dtype.clone()),
+            )?
+            else {
+                continue;
+            };
+            let metadata = self.read_layout(layout, message_cache).await?;
+            ranges.extend(bloom_skipped_ranges(metadata, row_count, dtype, &values)?);
+        }
+
+        Ok((!ranges.is_empty()).then(|| SkippedRows::new(ranges, row_count)))
+    }
+
+    /// Read every batch of a layout, fetching the messages it asks for.
+    async fn read_layout(
+        &self,
+        mut layout: Box<dyn LayoutReader>,
+        cache: &RwLock<LayoutMessageCache>,
+    ) -> VortexResult<Array> {
+        let mut batches = Vec::new();
+        while let Some(rr) = layout.read_next()? {
+            match rr {
+                ReadResult::ReadMore(messages) => {
+                    for (id, range) in messages {
+                        let mut buf = BytesMut::with_capacity(range.len());
+                        unsafe { buf.set_len(range.len()) }
+                        let buf = self.reader.read_at_into(range.begin, buf).await?;
+                        cache
+                            .write()
+                            .unwrap_or_else(|poison| {
+                                vortex_panic!("Failed to write to message cache: {poison}")
+                            })
+                            .set(id, buf.freeze());
+                    }
+                }
+                ReadResult::Batch(a) => batches.push(a),
+            }
+        }
+        if batches.len() == 1 {
+            return Ok(batches.remove(0));
+        }
+        let dtype = batches
+            .first()
+            .map(|b| b.dtype().clone())
+            .ok_or_else(|| vortex_err!("Layout has no batches"))?;
+        Ok(ChunkedArray::try_new(batches, dtype)?.into_array())
+    }
+
     async fn size(&self) -> u64 {
         match self.size {
             Some(s) => s,
@@ -8,7 +8,9 @@ use vortex_error::{vortex_err, VortexResult};
 use vortex_flatbuffers::footer as fb;
 
 use crate::layouts::read::cache::RelativeLayoutCache;
-use crate::layouts::read::layouts::{ChunkedLayoutSpec, ColumnLayoutSpec, FlatLayoutSpec};
+use crate::layouts::read::layouts::{
+    ChunkedLayoutSpec, ColumnLayoutSpec, FlatLayoutSpec, InlineDTypeLayoutSpec,
+};
 use crate::layouts::read::{LayoutReader, Scan};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
@@ -57,6 +59,7 @@ impl Default for LayoutContext {
                 &ColumnLayoutSpec as LayoutSpecRef,
                 &ChunkedLayoutSpec,
                 &FlatLayoutSpec,
+                &InlineDTypeLayoutSpec,
             ]
             .into_iter()
             .map(|l| (l.id(), l))
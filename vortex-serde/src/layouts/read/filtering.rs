@@ -8,7 +8,10 @@ use vortex::stats::ArrayStatistics;
 use vortex::{Array, IntoArray, IntoArrayVariant};
 use vortex_dtype::field::Field;
 use vortex_error::{VortexExpect, VortexResult};
-use vortex_expr::{split_conjunction, VortexExpr};
+use vortex_expr::{
+    fuse_between, split_conjunction, BinaryExpr, Column, Literal, Operator, VortexExpr,
+};
+use vortex_scalar::Scalar;
 
 use crate::layouts::null_as_false;
 
@@ -19,7 +22,7 @@ pub struct RowFilter {
 
 impl RowFilter {
     pub fn new(expr: Arc<dyn VortexExpr>) -> Self {
-        let conjunction = split_conjunction(&expr);
+        let conjunction = fuse_between(split_conjunction(&expr));
         Self { conjunction }
     }
 
@@ -51,4 +54,38 @@ impl RowFilter {
 
         set
     }
+
+    /// The conjuncts that only match rows where a column equals one of a list of values, either a
+    /// single equality or a disjunction of equalities on the same column as an IN-list would be.
+    pub fn point_lookups(&self) -> Vec<(Field, Vec<Scalar>)> {
+        self.conjunction.iter().filter_map(point_lookup).collect()
+    }
+}
+
+fn point_lookup(expr: &Arc<dyn VortexExpr>) -> Option<(Field, Vec<Scalar>)> {
+    let binary = expr.as_any().downcast_ref::<BinaryExpr>()?;
+    match binary.op() {
+        Operator::Eq => {
+            let (column, literal) = match (
+                binary.lhs().as_any().downcast_ref::<Column>(),
+                binary.rhs().as_any().downcast_ref::<Literal>(),
+            ) {
+                (Some(column), Some(literal)) => (column, literal),
+                _ => (
+                    binary.rhs().as_any().downcast_ref::<Column>()?,
+                    binary.lhs().as_any().downcast_ref::<Literal>()?,
+                ),
+            };
+            Some((column.field().clone(), vec![literal.value().clone()]))
+        }
+        Operator::Or => {
+            let (field, mut values) = point_lookup(binary.lhs())?;
+            let (other_field, other_values) = point_lookup(binary.rhs())?;
+            (field == other_field).then(|| {
+                values.extend(other_values);
+                (field, values)
+            })
+        }
+        _ => None,
+    }
 }
@@ -10,7 +10,9 @@ use crate::io::VortexReadAt;
 use crate::layouts::read::cache::RelativeLayoutCache;
 use crate::layouts::read::context::LayoutDeserializer;
 use crate::layouts::read::{LayoutReader, Scan, INITIAL_READ_SIZE};
-use crate::layouts::{EOF_SIZE, FOOTER_POSTSCRIPT_SIZE, MAGIC_BYTES, VERSION};
+use crate::layouts::{
+    CHUNKED_LAYOUT_ID, COLUMN_LAYOUT_ID, EOF_SIZE, FOOTER_POSTSCRIPT_SIZE, MAGIC_BYTES, VERSION,
+};
 use crate::FLATBUFFER_SIZE_LENGTH;
 
 /// Wrapper around serialized file footer. Provides handle on file schema and
@@ -66,16 +68,19 @@ impl LayoutDescriptor {
         (self.schema_offset - self.initial_read_offset) as usize
     }
 
+    fn footer_bytes(&self) -> Bytes {
+        let start_offset = self.initial_read_layout_offset();
+        let end_offset = self.initial_read.len() - FOOTER_POSTSCRIPT_SIZE - EOF_SIZE;
+        self.initial_read
+            .slice(start_offset + FLATBUFFER_SIZE_LENGTH..end_offset)
+    }
+
     pub fn layout(
         &self,
         scan: Scan,
         message_cache: RelativeLayoutCache,
     ) -> VortexResult<Box<dyn LayoutReader>> {
-        let start_offset = self.initial_read_layout_offset();
-        let end_offset = self.initial_read.len() - FOOTER_POSTSCRIPT_SIZE - EOF_SIZE;
-        let footer_bytes = self
-            .initial_read
-            .slice(start_offset + FLATBUFFER_SIZE_LENGTH..end_offset);
+        let footer_bytes = self.footer_bytes();
         let fb_footer = root::<footer::Footer>(&footer_bytes)?;
 
         let fb_layout = fb_footer
@@ -86,6 +91,51 @@ impl LayoutDescriptor {
             .read_layout(footer_bytes, loc, scan, message_cache)
     }
 
+    /// The layout of the chunk metadata table of a top-level column, if the column is chunked.
+    pub fn chunk_metadata_layout(
+        &self,
+        column: usize,
+        scan: Scan,
+        message_cache: RelativeLayoutCache,
+    ) -> VortexResult<Option<Box<dyn LayoutReader>>> {
+        let footer_bytes = self.footer_bytes();
+        let fb_footer = root::<footer::Footer>(&footer_bytes)?;
+        let fb_layout = fb_footer
+            .layout()
+            .ok_or_else(|| vortex_err!("Footer must contain a layout"))?;
+        if fb_layout.encoding() != COLUMN_LAYOUT_ID.0 {
+            return Ok(None);
+        }
+        let Some(chunked) = fb_layout
+            .children()
+            .filter(|c| column < c.len())
+            .map(|c| c.get(column))
+        else {
+            return Ok(None);
+        };
+        let has_metadata = chunked
+            .metadata()
+            .is_some_and(|b| b.bytes().first().is_some_and(|&b| b != 0));
+        if chunked.encoding() != CHUNKED_LAYOUT_ID.0 || !has_metadata {
+            return Ok(None);
+        }
+        let Some(metadata) = chunked
+            .children()
+            .filter(|c| !c.is_empty())
+            .map(|c| c.get(0))
+        else {
+            return Ok(None);
+        };
+        let loc = metadata._tab.loc();
+        self.layout_serde
+            .read_layout(footer_bytes.clone(), loc, scan, message_cache)
+            .map(Some)
+    }
+
+    pub fn row_count(&self) -> VortexResult<u64> {
+        Ok(root::<footer::Footer>(&self.footer_bytes())?.row_count())
+    }
+
     pub fn dtype_bytes(&self) -> VortexResult<Bytes> {
         let start_offset = self.initial_read_schema_offset();
         let end_offset = self.initial_read_layout_offset();
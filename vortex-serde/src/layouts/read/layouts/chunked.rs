@@ -1,6 +1,11 @@
 use std::collections::VecDeque;
+use std::ops::Range;
 
 use bytes::Bytes;
+use vortex::array::ChunkedArray;
+use vortex::compute::slice;
+use vortex::variants::StructArrayTrait;
+use vortex::{Array, ArrayDType, IntoArray, IntoArrayVariant};
 use vortex_error::{vortex_err, VortexResult};
 use vortex_flatbuffers::footer;
 
@@ -40,6 +45,9 @@ impl LayoutSpec for ChunkedLayoutSpec {
 ///
 /// First child in the list is the metadata table
 /// Subsequent children are consecutive chunks of this layout
+///
+/// When the scan skips rows, the metadata table is read first to find the chunks that are skipped
+/// entirely, which are never read.
 #[derive(Debug)]
 pub struct ChunkedLayout {
     fb_bytes: Bytes,
@@ -47,6 +55,8 @@ pub struct ChunkedLayout {
     scan: Scan,
     layout_builder: LayoutDeserializer,
     message_cache: RelativeLayoutCache,
+    metadata_reader: Option<Box<dyn LayoutReader>>,
+    metadata: Vec<Array>,
     reader: Option<BufferedReader>,
 }
 
@@ -64,6 +74,8 @@ impl ChunkedLayout {
             scan,
             layout_builder,
             message_cache,
+            metadata_reader: None,
+            metadata: Vec::new(),
             reader: None,
         }
     }
@@ -81,33 +93,164 @@ impl ChunkedLayout {
             .map(|b| b.bytes()[0] != 0)
             .unwrap_or(false)
     }
+
+    /// Read the metadata table, returning the messages that still have to be fetched.
+    fn read_metadata(&mut self) -> VortexResult<Option<ReadResult>> {
+        let mut reader = match self.metadata_reader.take() {
+            Some(reader) => reader,
+            None => {
+                let metadata = self
+                    .flatbuffer()
+                    .children()
+                    .ok_or_else(|| vortex_err!("Missing children"))?
+                    .get(0);
+                self.layout_builder.read_layout(
+                    self.fb_bytes.clone(),
+                    metadata._tab.loc(),
+                    self.scan.clone(),
+                    self.message_cache
+                        .relative(0, self.message_cache.dtype().clone()),
+                )?
+            }
+        };
+        while let Some(rr) = reader.read_next()? {
+            match rr {
+                read_more @ ReadResult::ReadMore(..) => {
+                    self.metadata_reader = Some(reader);
+                    return Ok(Some(read_more));
+                }
+                ReadResult::Batch(a) => self.metadata.push(a),
+            }
+        }
+        Ok(None)
+    }
+
+    /// The first row of each chunk, from the metadata table.
+    fn chunk_offsets(&self) -> VortexResult<Vec<u64>> {
+        let mut offsets = Vec::new();
+        for metadata in &self.metadata {
+            let row_offsets = metadata
+                .clone()
+                .into_struct()?
+                .field_by_name("row_offset")
+                .ok_or_else(|| vortex_err!("Chunk metadata is missing row offsets"))?
+                .into_primitive()?;
+            offsets.extend_from_slice(row_offsets.maybe_null_slice::<u64>());
+        }
+        Ok(offsets)
+    }
 }
 
 impl LayoutReader for ChunkedLayout {
     fn read_next(&mut self) -> VortexResult<Option<ReadResult>> {
         if let Some(cr) = &mut self.reader {
-            cr.read()
-        } else {
-            let children = self
-                .flatbuffer()
-                .children()
-                .ok_or_else(|| vortex_err!("Missing children"))?
+            return cr.read();
+        }
+
+        let skipped = self
+            .scan
+            .skipped_rows
+            .clone()
+            .filter(|_| self.has_metadata());
+        let chunk_offsets = match skipped {
+            Some(_) => {
+                if let Some(read_more) = self.read_metadata()? {
+                    return Ok(Some(read_more));
+                }
+                self.chunk_offsets()?
+            }
+            None => Vec::new(),
+        };
+
+        let mut children = VecDeque::new();
+        for (i, c) in self
+            .flatbuffer()
+            .children()
+            .ok_or_else(|| vortex_err!("Missing children"))?
+            .iter()
+            .enumerate()
+            // Skip over the metadata table of this layout
+            .skip(if self.has_metadata() { 1 } else { 0 })
+        {
+            let layout = || {
+                self.layout_builder.read_layout(
+                    self.fb_bytes.clone(),
+                    c._tab.loc(),
+                    self.scan.clone(),
+                    self.message_cache
+                        .relative(i as u16, self.message_cache.dtype().clone()),
+                )
+            };
+            let Some(skipped) = &skipped else {
+                children.push_back(layout()?);
+                continue;
+            };
+
+            let begin = chunk_offsets
+                .get(i - 1)
+                .copied()
+                .ok_or_else(|| vortex_err!("Chunk metadata is missing chunk {}", i - 1))?;
+            let end = chunk_offsets
+                .get(i)
+                .copied()
+                .unwrap_or_else(|| skipped.row_count());
+            let kept = skipped.kept(begin..end);
+            if kept.is_empty() {
+                continue;
+            }
+            if kept.len() == 1 && kept[0] == (begin..end) {
+                children.push_back(layout()?);
+            } else {
+                children.push_back(Box::new(PartiallySkippedChunk {
+                    chunk: layout()?,
+                    offset: begin,
+                    kept,
+                }));
+            }
+        }
+        self.reader = Some(BufferedReader::new(children, self.scan.batch_size));
+        self.read_next()
+    }
+}
+
+/// A chunk of which only some rows are read, yielding the rows within `kept`.
+#[derive(Debug)]
+struct PartiallySkippedChunk {
+    chunk: Box<dyn LayoutReader>,
+    /// The file row of the next row that the chunk yields.
+    offset: u64,
+    kept: Vec<Range<u64>>,
+}
+
+impl LayoutReader for PartiallySkippedChunk {
+    fn read_next(&mut self) -> VortexResult<Option<ReadResult>> {
+        while let Some(rr) = self.chunk.read_next()? {
+            let batch = match rr {
+                read_more @ ReadResult::ReadMore(..) => return Ok(Some(read_more)),
+                ReadResult::Batch(batch) => batch,
+            };
+            let begin = self.offset;
+            let end = begin + batch.len() as u64;
+            self.offset = end;
+
+            let mut parts = self
+                .kept
                 .iter()
-                .enumerate()
-                // Skip over the metadata table of this layout
-                .skip(if self.has_metadata() { 1 } else { 0 })
-                .map(|(i, c)| {
-                    self.layout_builder.read_layout(
-                        self.fb_bytes.clone(),
-                        c._tab.loc(),
-                        self.scan.clone(),
-                        self.message_cache
-                            .relative(i as u16, self.message_cache.dtype().clone()),
-                    )
-                })
-                .collect::<VortexResult<VecDeque<_>>>()?;
-            self.reader = Some(BufferedReader::new(children, self.scan.batch_size));
-            self.read_next()
+                .map(|r| r.start.max(begin)..r.end.min(end))
+                .filter(|r| !r.is_empty())
+                .map(|r| slice(&batch, (r.start - begin) as usize, (r.end - begin) as usize))
+                .collect::<VortexResult<Vec<_>>>()?;
+            match parts.len() {
+                0 => continue,
+                1 => return Ok(parts.pop().map(ReadResult::Batch)),
+                _ => {
+                    let dtype = batch.dtype().clone();
+                    return Ok(Some(ReadResult::Batch(
+                        ChunkedArray::try_new(parts, dtype)?.into_array(),
+                    )));
+                }
+            }
         }
+        Ok(None)
     }
 }
@@ -2,11 +2,13 @@ use std::fmt::Debug;
 
 use arrow_buffer::BooleanBuffer;
 use vortex::array::BoolArray;
+use vortex::compute::FilterMask;
 use vortex::validity::Validity;
 use vortex::{Array, IntoArray as _, IntoArrayVariant as _};
 use vortex_error::VortexResult;
 
 mod batch;
+mod bloom;
 mod buffered;
 mod builder;
 mod cache;
@@ -17,6 +19,7 @@ mod layouts;
 mod recordbatchreader;
 mod stream;
 
+pub use bloom::SkippedRows;
 pub use builder::LayoutReaderBuilder;
 pub use cache::LayoutMessageCache;
 pub use context::*;
@@ -36,10 +39,11 @@ pub const DEFAULT_BATCH_SIZE: usize = 65536;
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub struct Scan {
-    indices: Option<Array>,
+    mask: Option<FilterMask>,
     projection: Projection,
     filter: Option<RowFilter>,
     batch_size: usize,
+    skipped_rows: Option<SkippedRows>,
 }
 
 /// Unique identifier for a message within a layout
@@ -7,11 +7,12 @@ use futures::Stream;
 use futures_util::future::BoxFuture;
 use futures_util::{stream, FutureExt, StreamExt, TryStreamExt};
 use vortex::array::ChunkedArray;
-use vortex::compute::filter;
-use vortex::stats::ArrayStatistics;
+use vortex::compute::{filter, FilterMask};
 use vortex::Array;
 use vortex_dtype::DType;
-use vortex_error::{vortex_err, vortex_panic, VortexError, VortexExpect, VortexResult};
+use vortex_error::{
+    vortex_bail, vortex_err, vortex_panic, VortexError, VortexExpect, VortexResult,
+};
 use vortex_schema::Schema;
 
 use crate::io::VortexReadAt;
@@ -27,7 +28,8 @@ pub struct LayoutBatchStream<R> {
     messages_cache: Arc<RwLock<LayoutMessageCache>>,
     state: StreamingState<R>,
     dtype: DType,
-    cached_mask: Option<Array>,
+    cached_mask: Option<FilterMask>,
+    row_offset: usize,
 }
 
 impl<R: VortexReadAt> LayoutBatchStream<R> {
@@ -54,6 +56,7 @@ impl<R: VortexReadAt> LayoutBatchStream<R> {
             dtype,
             state,
             cached_mask: None,
+            row_offset: 0,
         }
     }
 
@@ -61,6 +64,26 @@ impl<R: VortexReadAt> LayoutBatchStream<R> {
         Schema::new(self.dtype.clone())
     }
 
+    /// The selection for the next `len` rows, combining the row filter result with the scan mask.
+    fn batch_mask(&mut self, len: usize) -> VortexResult<Option<FilterMask>> {
+        let offset = self.row_offset;
+        self.row_offset += len;
+
+        let row_filter_mask = self.cached_mask.take();
+        let Some(scan_mask) = self.scan.mask.as_ref() else {
+            return Ok(row_filter_mask);
+        };
+        if self.row_offset > scan_mask.len() {
+            vortex_bail!(OutOfBounds: self.row_offset, 0, scan_mask.len());
+        }
+
+        let scan_mask = scan_mask.slice(offset, len);
+        Ok(Some(match row_filter_mask {
+            Some(m) => FilterMask::from(m.boolean_buffer() & scan_mask.boolean_buffer()),
+            None => scan_mask,
+        }))
+    }
+
     fn store_messages(&self, messages: Vec<(MessageId, Bytes)>) {
         let mut write_cache_guard = self
             .messages_cache
@@ -129,7 +152,7 @@ impl<R: VortexReadAt + Unpin + Send + 'static> Stream for LayoutBatchStream<R> {
                                     .as_ref()
                                     .vortex_expect("Cant filter without filter")
                                     .evaluate(&a)?;
-                                self.cached_mask = Some(mask);
+                                self.cached_mask = Some(FilterMask::try_from(mask)?);
                                 self.state = StreamingState::Init;
                             }
                         }
@@ -139,15 +162,7 @@ impl<R: VortexReadAt + Unpin + Send + 'static> Stream for LayoutBatchStream<R> {
                 }
                 StreamingState::Decoding(arr) => {
                     let mut batch = arr.clone();
-
-                    if let Some(mask) = self.cached_mask.take() {
-                        if mask.statistics().compute_true_count().unwrap_or_default() == 0 {
-                            self.state = StreamingState::Init;
-                            continue;
-                        }
-
-                        batch = filter(batch, mask)?;
-                    }
+                    let mask = self.batch_mask(batch.len())?;
 
                     let goto_state = if self.filter_reader.is_some() {
                         StreamingState::FilterInit
@@ -155,6 +170,15 @@ impl<R: VortexReadAt + Unpin + Send + 'static> Stream for LayoutBatchStream<R> {
                         StreamingState::Init
                     };
                     self.state = goto_state;
+
+                    if let Some(mask) = mask {
+                        if mask.true_count() == 0 {
+                            continue;
+                        }
+
+                        batch = filter(batch, &mask)?;
+                    }
+
                     return Poll::Ready(Some(Ok(batch)));
                 }
                 StreamingState::Reading(f) => match ready!(f.poll_unpin(cx)) {
@@ -1,18 +1,24 @@
 #![allow(clippy::panic)]
 
-use std::iter;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::{io, iter};
 
+use bytes::BytesMut;
 use futures::StreamExt;
 use vortex::accessor::ArrayAccessor;
 use vortex::array::{ChunkedArray, PrimitiveArray, StructArray, VarBinArray};
+use vortex::compute::FilterMask;
+use vortex::stats::{ArrayStatistics, Stat, StatsOptions};
 use vortex::validity::Validity;
 use vortex::variants::StructArrayTrait;
 use vortex::{ArrayDType, IntoArray, IntoArrayVariant};
 use vortex_dtype::field::Field;
 use vortex_dtype::{DType, Nullability, PType, StructDType};
-use vortex_expr::{BinaryExpr, Column, Literal, Operator};
+use vortex_error::VortexResult;
+use vortex_expr::{BinaryExpr, Column, Literal, Operator, VortexExpr};
 
+use crate::io::VortexReadAt;
 use crate::layouts::write::LayoutWriter;
 use crate::layouts::{LayoutDeserializer, LayoutReaderBuilder, Projection, RowFilter};
 
@@ -487,3 +493,302 @@ async fn filter_and() {
         vec![25, 31]
     );
 }
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn read_with_mask() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let read_numbers = |row_filter: Option<RowFilter>| {
+        let written = written.clone();
+        async move {
+            let mut builder = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+                .with_mask(FilterMask::from_indices(8, [0, 2, 5, 7]));
+            if let Some(row_filter) = row_filter {
+                builder = builder.with_row_filter(row_filter);
+            }
+            let mut stream = builder.build().await.unwrap();
+
+            let mut numbers = Vec::new();
+            while let Some(array) = stream.next().await {
+                let field = array
+                    .unwrap()
+                    .with_dyn(|a| a.as_struct_array_unchecked().field(0))
+                    .unwrap();
+                numbers
+                    .extend_from_slice(field.into_primitive().unwrap().maybe_null_slice::<u32>());
+            }
+            numbers
+        }
+    };
+
+    assert_eq!(read_numbers(None).await, vec![1, 3, 6, 8]);
+    assert_eq!(
+        read_numbers(Some(RowFilter::new(Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::Gt,
+            Arc::new(Literal::new(2u32.into())),
+        )))))
+        .await,
+        vec![3, 6, 8]
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn read_with_indices() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new());
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = writer.finalize().await.unwrap();
+
+    let read_numbers = |indices: Vec<u16>, mask: Option<FilterMask>| {
+        let written = written.clone();
+        async move {
+            let mut builder = LayoutReaderBuilder::new(written, LayoutDeserializer::default())
+                .with_indices(PrimitiveArray::from(indices).into_array());
+            if let Some(mask) = mask {
+                builder = builder.with_mask(mask);
+            }
+            let mut stream = builder.build().await?;
+
+            let mut numbers = Vec::new();
+            while let Some(array) = stream.next().await {
+                let field = array?
+                    .with_dyn(|a| a.as_struct_array_unchecked().field(0))
+                    .unwrap();
+                numbers.extend_from_slice(field.into_primitive()?.maybe_null_slice::<u32>());
+            }
+            VortexResult::Ok(numbers)
+        }
+    };
+
+    assert_eq!(
+        read_numbers(vec![6, 1, 3, 1], None).await.unwrap(),
+        vec![2, 4, 7]
+    );
+    assert_eq!(
+        read_numbers(
+            vec![6, 1, 3],
+            Some(FilterMask::from_indices(8, [0, 1, 2, 3]))
+        )
+        .await
+        .unwrap(),
+        vec![2, 4]
+    );
+    assert!(read_numbers(vec![8], None).await.is_err());
+}
+
+/// A reader that counts the bytes read from it.
+#[derive(Clone)]
+struct CountingReader {
+    data: Arc<Vec<u8>>,
+    bytes_read: Arc<AtomicU64>,
+}
+
+impl VortexReadAt for CountingReader {
+    async fn read_at_into(&self, pos: u64, buffer: BytesMut) -> io::Result<BytesMut> {
+        self.bytes_read
+            .fetch_add(buffer.len() as u64, Ordering::Relaxed);
+        self.data.read_at_into(pos, buffer).await
+    }
+
+    async fn size(&self) -> u64 {
+        self.data.len() as u64
+    }
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn bloom_filter_skips_chunks() {
+    let numbers = ChunkedArray::from_iter([
+        PrimitiveArray::from(vec![1u32, 2, 3, 4]).into_array(),
+        PrimitiveArray::from(vec![5u32, 6, 7, 8]).into_array(),
+        PrimitiveArray::from(vec![9u32, 10, 11, 12]).into_array(),
+    ])
+    .into_array();
+    // Chunked differently from the numbers, so that its chunks are only partly skipped.
+    let strings = ChunkedArray::from_iter([
+        VarBinArray::from(vec!["a", "b", "c", "d", "e", "f"]).into_array(),
+        VarBinArray::from(vec!["g", "h", "i", "j", "k", "l"]).into_array(),
+    ])
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers), ("strings", strings)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new()).with_bloom_filters(true);
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = Arc::new(writer.finalize().await.unwrap());
+
+    let numbers_eq = |value: u32| -> Arc<dyn VortexExpr> {
+        Arc::new(BinaryExpr::new(
+            Arc::new(Column::new(Field::from("numbers"))),
+            Operator::Eq,
+            Arc::new(Literal::new(value.into())),
+        ))
+    };
+    let read = |expr: Arc<dyn VortexExpr>, mask: Option<FilterMask>| {
+        let reader = CountingReader {
+            data: written.clone(),
+            bytes_read: Arc::new(AtomicU64::new(0)),
+        };
+        async move {
+            let mut builder =
+                LayoutReaderBuilder::new(reader.clone(), LayoutDeserializer::default())
+                    .with_row_filter(RowFilter::new(expr));
+            if let Some(mask) = mask {
+                builder = builder.with_mask(mask);
+            }
+            let mut stream = builder.build().await.unwrap();
+
+            let mut numbers = Vec::new();
+            let mut strings = Vec::new();
+            while let Some(array) = stream.next().await {
+                let array = array.unwrap();
+                let field = |i| {
+                    array
+                        .with_dyn(|a| a.as_struct_array_unchecked().field(i))
+                        .unwrap()
+                };
+                numbers.extend_from_slice(
+                    field(0).into_primitive().unwrap().maybe_null_slice::<u32>(),
+                );
+                field(1)
+                    .into_varbinview()
+                    .unwrap()
+                    .with_iterator(|iter| {
+                        strings.extend(
+                            iter.flatten()
+                                .map(|s| String::from_utf8_lossy(s).into_owned()),
+                        )
+                    })
+                    .unwrap();
+            }
+            (numbers, strings, reader.bytes_read.load(Ordering::Relaxed))
+        }
+    };
+
+    let (numbers, strings, _) = read(numbers_eq(6), None).await;
+    assert_eq!(numbers, vec![6]);
+    assert_eq!(strings, vec!["f"]);
+
+    let in_list = Arc::new(BinaryExpr::new(numbers_eq(2), Operator::Or, numbers_eq(11)));
+    let (numbers, strings, _) = read(in_list, None).await;
+    assert_eq!(numbers, vec![2, 11]);
+    assert_eq!(strings, vec!["b", "k"]);
+
+    let in_list = Arc::new(BinaryExpr::new(numbers_eq(6), Operator::Or, numbers_eq(7)));
+    let (numbers, strings, _) = read(in_list, Some(FilterMask::from_indices(12, [1, 5, 10]))).await;
+    assert_eq!(numbers, vec![6]);
+    assert_eq!(strings, vec!["f"]);
+
+    // Nothing can match, so no chunk is read.
+    let (numbers, ..) = read(numbers_eq(42), None).await;
+    assert!(numbers.is_empty());
+
+    // A mask that doesn't cover the file is rejected rather than compacted past its end.
+    assert!(
+        LayoutReaderBuilder::new(written.clone(), LayoutDeserializer::default())
+            .with_row_filter(RowFilter::new(numbers_eq(11)))
+            .with_mask(FilterMask::from_indices(6, [1]))
+            .build()
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn bloom_filter_skips_reads() {
+    let numbers = ChunkedArray::from_iter((0..8u32).map(|c| {
+        PrimitiveArray::from((c * 1000..(c + 1) * 1000).collect::<Vec<_>>()).into_array()
+    }))
+    .into_array();
+    let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+    let mut writer = LayoutWriter::new(Vec::new()).with_bloom_filters(true);
+    writer = writer.write_array_columns(st.into_array()).await.unwrap();
+    let written = Arc::new(writer.finalize().await.unwrap());
+
+    let bytes_read = |op: Operator, value: u32| {
+        let reader = CountingReader {
+            data: written.clone(),
+            bytes_read: Arc::new(AtomicU64::new(0)),
+        };
+        async move {
+            let mut stream =
+                LayoutReaderBuilder::new(reader.clone(), LayoutDeserializer::default())
+                    .with_row_filter(RowFilter::new(Arc::new(BinaryExpr::new(
+                        Arc::new(Column::new(Field::from("numbers"))),
+                        op,
+                        Arc::new(Literal::new(value.into())),
+                    ))))
+                    .build()
+                    .await
+                    .unwrap();
+            let mut rows = 0;
+            while let Some(array) = stream.next().await {
+                rows += array.unwrap().len();
+            }
+            assert_eq!(rows, 1);
+            reader.bytes_read.load(Ordering::Relaxed)
+        }
+    };
+
+    // Bloom filters only help point lookups, a range predicate reads every chunk.
+    let point_lookup = bytes_read(Operator::Eq, 4321).await;
+    let range = bytes_read(Operator::Lte, 0).await;
+    assert!(point_lookup < range, "{point_lookup} >= {range}");
+}
+
+#[tokio::test]
+#[cfg_attr(miri, ignore)]
+async fn write_histograms() {
+    let write = |stats_options: StatsOptions| async move {
+        let numbers = ChunkedArray::from_iter([
+            PrimitiveArray::from((0..100u32).collect::<Vec<_>>()).into_array(),
+            PrimitiveArray::from((100..200u32).collect::<Vec<_>>()).into_array(),
+        ])
+        .into_array();
+        let st = StructArray::from_fields(&[("numbers", numbers)]).unwrap();
+        let writer = LayoutWriter::new(Vec::new())
+            .with_stats_options(stats_options)
+            .write_array_columns(st.into_array())
+            .await
+            .unwrap();
+        let mut stream = LayoutReaderBuilder::new(
+            writer.finalize().await.unwrap(),
+            LayoutDeserializer::default(),
+        )
+        .with_batch_size(100)
+        .build()
+        .await
+        .unwrap();
+        let mut histograms = Vec::new();
+        while let Some(array) = stream.next().await {
+            let numbers = array
+                .unwrap()
+                .with_dyn(|a| a.as_struct_array_unchecked().field(0))
+                .unwrap();
+            histograms.push(numbers.statistics().get(Stat::Histogram).is_some());
+        }
+        histograms
+    };
+
+    assert_eq!(write(StatsOptions::default()).await, vec![false, false]);
+    let stats_options = StatsOptions {
+        write_histograms: true,
+        ..StatsOptions::default()
+    };
+    assert_eq!(write(stats_options).await, vec![true, true]);
+}
@@ -3,12 +3,14 @@ use std::{io, mem};
 
 use flatbuffers::FlatBufferBuilder;
 use futures::{Stream, TryStreamExt};
-use vortex::array::{ChunkedArray, StructArray};
+use vortex::array::{ChunkedArray, StructArray, VarBinArray};
+use vortex::stats::{ArrayStatistics, Stat, StatsOptions};
 use vortex::stream::ArrayStream;
 use vortex::validity::Validity;
 use vortex::{Array, ArrayDType, IntoArray};
 use vortex_buffer::io_buf::IoBuf;
-use vortex_dtype::DType;
+use vortex_buffer::Buffer;
+use vortex_dtype::{DType, Nullability};
 use vortex_error::{vortex_bail, vortex_err, VortexExpect, VortexResult};
 use vortex_flatbuffers::WriteFlatBuffer;
 
@@ -25,6 +27,8 @@ pub struct LayoutWriter<W> {
     row_count: u64,
     dtype: Option<DType>,
     column_chunks: Vec<BatchOffsets>,
+    bloom_filters: bool,
+    stats_options: StatsOptions,
 }
 
 impl<W: VortexWrite> LayoutWriter<W> {
@@ -34,9 +38,26 @@ impl<W: VortexWrite> LayoutWriter<W> {
             dtype: None,
             column_chunks: Vec::new(),
             row_count: 0,
+            bloom_filters: false,
+            stats_options: StatsOptions::default(),
         }
     }
 
+    /// Store a bloom filter of every chunk of primitive, utf8 and binary columns in the column's
+    /// metadata table, letting readers skip chunks that cannot match an equality predicate.
+    pub fn with_bloom_filters(mut self, bloom_filters: bool) -> Self {
+        self.bloom_filters = bloom_filters;
+        self
+    }
+
+    /// The options of the statistics computed for the written chunks, which are stored with them.
+    /// A histogram of every numeric chunk is only computed if
+    /// [write_histograms](StatsOptions::write_histograms) is set.
+    pub fn with_stats_options(mut self, stats_options: StatsOptions) -> Self {
+        self.stats_options = stats_options;
+        self
+    }
+
     pub async fn write_array_columns(self, array: Array) -> VortexResult<Self> {
         if let Ok(chunked) = ChunkedArray::try_from(&array) {
             self.write_array_columns_stream(chunked.array_stream())
@@ -87,6 +108,7 @@ impl<W: VortexWrite> LayoutWriter<W> {
     {
         let mut row_offsets: Vec<u64> = Vec::new();
         let mut byte_offsets = vec![self.msgs.tell()];
+        let mut bloom_filters = Vec::new();
 
         let mut n_rows_written = match self.column_chunks.get(column_idx) {
             None => {
@@ -102,6 +124,15 @@ impl<W: VortexWrite> LayoutWriter<W> {
         while let Some(chunk) = stream.try_next().await? {
             n_rows_written += chunk.len() as u64;
             row_offsets.push(n_rows_written);
+            if self.bloom_filters {
+                bloom_filters.push(bloom_filter(&chunk));
+            }
+            if self.stats_options.write_histograms && matches!(chunk.dtype(), DType::Primitive(..))
+            {
+                chunk
+                    .statistics()
+                    .compute_with_options(Stat::Histogram, &self.stats_options);
+            }
             self.msgs.write_batch(chunk).await?;
             byte_offsets.push(self.msgs.tell());
         }
@@ -109,9 +140,13 @@ impl<W: VortexWrite> LayoutWriter<W> {
         if let Some(batches) = self.column_chunks.get_mut(column_idx) {
             batches.row_offsets.extend(row_offsets);
             batches.batch_byte_offsets.push(byte_offsets);
+            batches.bloom_filters.extend(bloom_filters);
         } else {
-            self.column_chunks
-                .push(BatchOffsets::new(row_offsets, vec![byte_offsets]));
+            self.column_chunks.push(BatchOffsets::new(
+                row_offsets,
+                vec![byte_offsets],
+                bloom_filters,
+            ));
         }
 
         Ok(())
@@ -135,12 +170,23 @@ impl<W: VortexWrite> LayoutWriter<W> {
 
             assert_eq!(chunks.len(), chunk.row_offsets.len());
 
-            let metadata_array = StructArray::try_new(
-                ["row_offset".into()].into(),
-                vec![chunk.row_offsets.into_array()],
-                len,
-                Validity::NonNullable,
-            )?;
+            let mut names = vec!["row_offset".into()];
+            let mut fields = vec![chunk.row_offsets.into_array()];
+            if chunk.bloom_filters.iter().any(Option::is_some) {
+                names.push("bloom_filter".into());
+                fields.push(
+                    VarBinArray::from_iter(
+                        chunk
+                            .bloom_filters
+                            .iter()
+                            .map(|b| b.as_ref().map(Buffer::as_slice)),
+                        DType::Binary(Nullability::Nullable),
+                    )
+                    .into_array(),
+                );
+            }
+            let metadata_array =
+                StructArray::try_new(names.into(), fields, len, Validity::NonNullable)?;
 
             let dtype_begin = self.msgs.tell();
             self.msgs.write_dtype(metadata_array.dtype()).await?;
@@ -200,17 +246,34 @@ async fn write_fb_raw<W: VortexWrite, F: WriteFlatBuffer>(mut writer: W, fb: F)
     Ok(writer)
 }
 
+/// The bloom filter of a chunk, if the writer was asked for them and the chunk's type supports one.
+fn bloom_filter(chunk: &Array) -> Option<Buffer> {
+    if !matches!(
+        chunk.dtype(),
+        DType::Primitive(..) | DType::Utf8(_) | DType::Binary(_)
+    ) {
+        return None;
+    }
+    chunk.statistics().compute_as::<Buffer>(Stat::BloomFilter)
+}
+
 #[derive(Clone, Debug)]
 pub struct BatchOffsets {
     pub row_offsets: Vec<u64>,
     pub batch_byte_offsets: Vec<Vec<u64>>,
+    pub bloom_filters: Vec<Option<Buffer>>,
 }
 
 impl BatchOffsets {
-    pub fn new(row_offsets: Vec<u64>, batch_byte_offsets: Vec<Vec<u64>>) -> Self {
+    pub fn new(
+        row_offsets: Vec<u64>,
+        batch_byte_offsets: Vec<Vec<u64>>,
+        bloom_filters: Vec<Option<Buffer>>,
+    ) -> Self {
         Self {
             row_offsets,
             batch_byte_offsets,
+            bloom_filters,
         }
     }
 }
@@ -23,10 +23,12 @@ mod test {
     use futures_util::io::Cursor;
     use futures_util::{pin_mut, StreamExt, TryStreamExt};
     use itertools::Itertools;
-    use vortex::array::{ChunkedArray, PrimitiveArray, PrimitiveEncoding};
+    use vortex::array::{ChunkedArray, PrimitiveArray, PrimitiveEncoding, VarBinArray};
     use vortex::encoding::ArrayEncoding;
+    use vortex::stats::{ArrayStatistics, Stat};
     use vortex::stream::ArrayStreamExt;
     use vortex::{ArrayDType, Context, IntoArray};
+    use vortex_buffer::BufferString;
     use vortex_error::VortexResult;
 
     use crate::io::FuturesAdapter;
@@ -114,4 +116,108 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_write_read_sketches() -> VortexResult<()> {
+        let data = PrimitiveArray::from((0i32..10_000).map(|i| i % 100).collect_vec());
+        assert!(data.statistics().compute(Stat::ApproxDistinct).is_some());
+        assert!(data.statistics().compute(Stat::ApproxQuantiles).is_some());
+        assert!(data.statistics().compute(Stat::Histogram).is_some());
+        let buffer = write_ipc(data);
+
+        let ctx = Arc::new(Context::default());
+        let chunked = block_on(async {
+            StreamArrayReader::try_new(FuturesAdapter(Cursor::new(buffer)), ctx)
+                .await?
+                .load_dtype()
+                .await?
+                .into_array_stream()
+                .collect_chunked()
+                .await
+        })?;
+        let chunk = chunked.chunk(0)?;
+        assert!(chunk.statistics().get(Stat::ApproxDistinct).is_some());
+        assert!(chunk.statistics().get(Stat::ApproxQuantiles).is_some());
+        assert!(chunk.statistics().get(Stat::Histogram).is_some());
+
+        let stats = chunked.statistics();
+        let distinct = stats
+            .compute_approx_distinct_count()
+            .expect("Expected a distinct count");
+        assert!(distinct.abs_diff(100) <= 2, "{distinct}");
+        let median = stats
+            .compute_approx_quantile(0.5)
+            .expect("Expected quantiles");
+        assert!((median - 50.0).abs() < 2.0, "{median}");
+        let below = stats
+            .compute_histogram()
+            .and_then(|h| h.fraction_lt(25.0))
+            .expect("Expected a histogram");
+        assert!((below - 0.25).abs() < 0.02, "{below}");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_write_read_sum_stats() -> VortexResult<()> {
+        let data = PrimitiveArray::from(vec![1.5f64, f64::NAN, -4.0, 2.0]);
+        let size = data.as_ref().nbytes();
+        data.statistics().compute(Stat::Sum);
+        data.statistics().compute(Stat::UncompressedSizeInBytes);
+        let buffer = write_ipc(data);
+
+        let ctx = Arc::new(Context::default());
+        let chunked = block_on(async {
+            StreamArrayReader::try_new(FuturesAdapter(Cursor::new(buffer)), ctx)
+                .await?
+                .load_dtype()
+                .await?
+                .into_array_stream()
+                .collect_chunked()
+                .await
+        })?;
+        let chunk = chunked.chunk(0)?;
+        let stats = chunk.statistics();
+        assert!(stats.get_as::<f64>(Stat::Sum).is_some_and(f64::is_nan));
+        assert_eq!(stats.get_as::<usize>(Stat::NaNCount), Some(1));
+        assert_eq!(
+            stats.get_as::<usize>(Stat::UncompressedSizeInBytes),
+            Some(size)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_write_read_string_bounds() -> VortexResult<()> {
+        let long = "x".repeat(1_000);
+        let data = VarBinArray::from(vec![long.as_str(), "a"]);
+        data.statistics().compute(Stat::Min);
+        data.statistics().compute(Stat::Max);
+        let buffer = write_ipc(data);
+
+        let ctx = Arc::new(Context::default());
+        let chunked = block_on(async {
+            StreamArrayReader::try_new(FuturesAdapter(Cursor::new(buffer)), ctx)
+                .await?
+                .load_dtype()
+                .await?
+                .into_array_stream()
+                .collect_chunked()
+                .await
+        })?;
+        let chunk = chunked.chunk(0)?;
+        let stats = chunk.statistics();
+        assert!(stats.is_exact(Stat::Min));
+        assert!(!stats.is_exact(Stat::Max));
+        let max = stats
+            .get_as::<BufferString>(Stat::Max)
+            .expect("Expected a max bound");
+        assert_eq!(max.as_str(), format!("{}y", "x".repeat(63)));
+
+        Ok(())
+    }
 }